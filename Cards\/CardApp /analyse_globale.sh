@@ -137,7 +137,7 @@ executer_analyse_rust() {
         
         if [ $? -eq 0 ]; then
             afficher_progression "Exécution de l'analyse de performance Rust..."
-            ./target/release/swift_performance_analyzer --path "$DIR_COURANT" --output json --report-path "$DIR_RESULTATS/rust_analysis_$DATE_HEURE.json"
+            ../target/release/swift_performance_analyzer --path "$DIR_COURANT" --output json --report-path "$DIR_RESULTATS/rust_analysis_$DATE_HEURE.json"
             
             # Retourner au répertoire courant
             cd "$DIR_COURANT"
@@ -0,0 +1,614 @@
+use crate::analyzers::compute_metrics;
+use crate::cache::{self, content_hash, AnalysisCache, CacheSession};
+use crate::call_graph::CallGraph;
+use crate::clone_detector::{self, CloneDetector};
+use crate::core_data_model::CoreDataModel;
+use crate::dead_code_analyzer::DeadCodeAnalyzer;
+use crate::main_thread_analyzer::MainThreadAnalyzer;
+use crate::models::{
+    AnalysisConfig, AnalysisResult, CacheStats, CloneGroup, FileIssue, HotspotFile, PerformanceReport, ProjectStats, Severity,
+};
+use crate::project_index::{IndexedFile, ProjectIndex};
+use crate::rules::{Rule, RuleContext, RuleRegistry, SourceFile};
+use crate::suppression::Suppressions;
+use crate::swift_source::{self, enclosing_declaration};
+use rayon::iter::Either;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Nombre de fichiers conservés dans les points chauds du rapport
+const HOTSPOT_COUNT: usize = 10;
+
+/// Rappel invoqué pour chaque fichier dès la fin de son analyse (depuis les threads d'analyse)
+pub type FileCallback = Box<dyn Fn(&AnalysisResult) + Send + Sync>;
+
+/// Source à analyser : fichier sur disque ou contenu en mémoire
+enum Source {
+    File(PathBuf),
+    Memory(PathBuf, String),
+}
+
+/// Analyseur de projet utilisable depuis d'autres outils
+///
+/// Se construit à partir d'une configuration, puis des sources, règles et
+/// rappels à enregistrer. Les règles intégrées (`RuleRegistry::builtin`) sont
+/// actives par défaut :
+///
+/// ```no_run
+/// use std::path::Path;
+/// use swift_analyzer_core::analyzer::Analyzer;
+/// use swift_analyzer_core::discovery::collect_source_files;
+/// use swift_analyzer_core::models::AnalysisConfig;
+///
+/// let files = collect_source_files(Path::new("CardApp"), &["swift".to_string()]);
+/// let report = Analyzer::new(AnalysisConfig::default())
+///     .with_files(files)
+///     .on_file_analyzed(|result| println!("{} : {} problèmes", result.file_path.display(), result.issues.len()))
+///     .run();
+/// println!("Score de santé : {:.1}", report.project_stats.health_score);
+/// ```
+pub struct Analyzer {
+    config: AnalysisConfig,
+    sources: Vec<Source>,
+    context_files: Vec<PathBuf>,
+    cache: Option<AnalysisCache>,
+    registry: RuleRegistry,
+    core_data_model: Arc<CoreDataModel>,
+    on_file: Option<FileCallback>,
+}
+
+impl Analyzer {
+    pub fn new(config: AnalysisConfig) -> Self {
+        Analyzer {
+            config,
+            sources: Vec::new(),
+            context_files: Vec::new(),
+            cache: None,
+            registry: RuleRegistry::builtin(),
+            core_data_model: Arc::new(CoreDataModel::default()),
+            on_file: None,
+        }
+    }
+
+    /// Ajoute des fichiers à lire sur le disque
+    pub fn with_files(mut self, files: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.sources.extend(files.into_iter().map(Source::File));
+        self
+    }
+
+    /// Ajoute des fichiers lus uniquement pour les analyses à l'échelle du projet (appels, références, clones)
+    ///
+    /// Ils ne sont ni analysés ni comptés dans le rapport : seuls les résultats
+    /// concernant les sources analysées sont conservés.
+    pub fn with_context_files(mut self, files: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.context_files.extend(files);
+        self
+    }
+
+    /// Ajoute un source en mémoire ; `path` sert uniquement à identifier le fichier dans le rapport
+    pub fn with_source(mut self, path: impl Into<PathBuf>, content: impl Into<String>) -> Self {
+        self.sources.push(Source::Memory(path.into(), content.into()));
+        self
+    }
+
+    /// Enregistre une règle supplémentaire, exécutée après celles du registre
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.registry.register(rule);
+        self
+    }
+
+    /// Remplace le registre des règles (règles activées ou désactivées, `RuleRegistry::new()` pour aucune règle intégrée)
+    pub fn with_registry(mut self, registry: RuleRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Associe le modèle CoreData du projet (vérification des index des requêtes)
+    pub fn with_core_data_model(mut self, model: Arc<CoreDataModel>) -> Self {
+        self.core_data_model = model;
+        self
+    }
+
+    /// Réutilise les résultats des fichiers inchangés depuis une analyse précédente de même configuration
+    ///
+    /// Les statistiques d'utilisation sont renseignées dans `PerformanceReport::cache`.
+    pub fn with_cache(mut self, cache: AnalysisCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Reçoit le résultat de chaque fichier dès qu'il est disponible, avant la fin de l'analyse
+    pub fn on_file_analyzed(mut self, callback: impl Fn(&AnalysisResult) + Send + Sync + 'static) -> Self {
+        self.on_file = Some(Box::new(callback));
+        self
+    }
+
+    /// Analyse toutes les sources en parallèle et construit le rapport
+    pub fn run(&self) -> PerformanceReport {
+        let context = RuleContext {
+            config: self.config.clone(),
+            core_data_model: self.core_data_model.clone(),
+        };
+
+        let (loaded, read_errors): (Vec<(PathBuf, String)>, Vec<String>) = self
+            .sources
+            .par_iter()
+            .map(|source| match source {
+                Source::File(path) => fs::read_to_string(path)
+                    .map(|content| (path.clone(), content))
+                    .map_err(|e| format!("Erreur lors de la lecture du fichier {}: {}", path.display(), e)),
+                Source::Memory(path, content) => Ok((path.clone(), content.clone())),
+            })
+            .partition_map(|loaded| match loaded {
+                Ok(source) => Either::Left(source),
+                Err(e) => Either::Right(e),
+            });
+
+        let mut cache = self.cache.as_ref().map(|cache| cache.open(&self.cache_key()));
+        let mut cache_stats = CacheStats::default();
+        let hashes: Vec<String> = match &cache {
+            Some(_) => loaded.par_iter().map(|(_, content)| content_hash(content)).collect(),
+            None => Vec::new(),
+        };
+
+        let results: Vec<(AnalysisResult, bool)> = loaded
+            .par_iter()
+            .enumerate()
+            .map(|(i, (path, content))| {
+                let cached = cache.as_ref().and_then(|cache| cache.result(&hashes[i], path));
+                let hit = cached.is_some();
+                let result = cached.unwrap_or_else(|| self.analyze_one(path, content, &context));
+                if let Some(callback) = &self.on_file {
+                    callback(&result);
+                }
+                (result, hit)
+            })
+            .collect();
+
+        let mut report = PerformanceReport {
+            project_root: None,
+            files_analyzed: Vec::new(),
+            issue_count_by_type: HashMap::new(),
+            issue_count_by_severity: HashMap::new(),
+            hotspots: Vec::new(),
+            churn_hotspots: Vec::new(),
+            project_stats: ProjectStats {
+                total_files: self.sources.len(),
+                problematic_files: 0,
+                total_lines: 0,
+                health_score: 100.0,
+                duplication_percentage: 0.0,
+            },
+            main_thread_estimates: Vec::new(),
+            dead_code: Vec::new(),
+            clones: Vec::new(),
+            baseline: None,
+            ownership: None,
+            cache: None,
+            quality_gate: None,
+            warnings: read_errors,
+        };
+        report.warnings.extend(self.core_data_model.read_errors().iter().cloned());
+        for (i, (result, hit)) in results.into_iter().enumerate() {
+            if let Some(cache) = &mut cache {
+                cache.see(&hashes[i], &result.file_path);
+                if hit {
+                    cache_stats.hits += 1;
+                } else {
+                    cache_stats.misses += 1;
+                    cache.store_result(&hashes[i], &result);
+                }
+            }
+            record_result(&mut report, result);
+        }
+
+        // Analyses à l'échelle du projet (appels entre fichiers)
+        let project_rules = [MainThreadAnalyzer::RULE, DeadCodeAnalyzer::RULE, CloneDetector::RULE];
+        if project_rules.iter().any(|rule| self.registry.is_enabled(rule.id)) {
+            let analyzed: HashSet<PathBuf> = loaded.iter().map(|(path, _)| path.clone()).collect();
+            let context: Vec<(PathBuf, String)> = self
+                .context_files
+                .par_iter()
+                .filter(|path| !analyzed.contains(*path))
+                .filter_map(|path| Some((path.clone(), fs::read_to_string(path).ok()?)))
+                .collect();
+            let index = match &mut cache {
+                Some(cache) => index_with_cache(cache, &mut cache_stats, loaded, hashes, context),
+                None => ProjectIndex::from_files(
+                    loaded
+                        .into_par_iter()
+                        .chain(context.into_par_iter())
+                        .map(|(path, content)| IndexedFile::new(path, content))
+                        .collect(),
+                ),
+            };
+            self.analyze_project(&index, &mut report);
+            if !self.context_files.is_empty() {
+                retain_analyzed_files(&mut report, &analyzed);
+            }
+        }
+
+        // Finaliser et trier les hotspots
+        report.hotspots.sort_by(|a, b| b.criticality_score.partial_cmp(&a.criticality_score).unwrap());
+        report.hotspots.truncate(HOTSPOT_COUNT);
+
+        calculate_health_score(&mut report);
+        if let Some(cache) = cache {
+            // Un cache non enregistré ne fait que ralentir l'analyse suivante
+            if let Err(e) = cache.save() {
+                report.warnings.push(e);
+            }
+            report.cache = Some(cache_stats);
+        }
+        report
+    }
+
+    /// Configuration effective pour la clé du cache : seuils, règles actives et modèle CoreData
+    fn cache_key(&self) -> String {
+        let mut rules: Vec<&str> =
+            self.registry.metadata().map(|rule| rule.id).filter(|id| self.registry.is_enabled(id)).collect();
+        rules.sort_unstable();
+        cache::configuration_key(&format!(
+            "{:?}\n{}\n{}",
+            self.config,
+            rules.join(","),
+            self.core_data_model.fingerprint()
+        ))
+    }
+
+    /// Métriques puis règles actives du registre sur un fichier
+    fn analyze_one(&self, path: &Path, content: &str, context: &RuleContext) -> AnalysisResult {
+        let mut result = AnalysisResult {
+            file_path: path.to_path_buf(),
+            file_size: content.len() as u64,
+            line_count: content.lines().count(),
+            metrics: compute_metrics(content),
+            issues: Vec::new(),
+            suppressed_issues: Vec::new(),
+            baseline_issues: Vec::new(),
+        };
+
+        let file = SourceFile::new(path, content);
+        for rule in self.registry.rules_for(path) {
+            let rule_id = rule.metadata().id;
+            result.issues.extend(
+                rule.check(&file, context)
+                    .into_iter()
+                    .filter(|issue| is_reported(issue.severity, self.config.min_severity))
+                    .map(|issue| FileIssue {
+                        rule_id: Some(rule_id.to_string()),
+                        ..issue
+                    }),
+            );
+        }
+
+        if !result.issues.is_empty() {
+            let lines = swift_source::sanitize_lines(content);
+            let declarations = swift_source::find_type_declarations(&lines);
+            let functions = swift_source::find_functions(&lines, &declarations);
+            for issue in &mut result.issues {
+                issue.declaration = enclosing_declaration(&declarations, &functions, issue.line.saturating_sub(1));
+            }
+        }
+
+        let suppressions = Suppressions::parse(content);
+        if !suppressions.is_empty() {
+            let (suppressed, issues) = result.issues.into_iter().partition(|issue| suppressions.is_suppressed(issue));
+            result.issues = issues;
+            result.suppressed_issues = suppressed;
+        }
+
+        result
+    }
+
+    fn analyze_project(&self, index: &ProjectIndex, report: &mut PerformanceReport) {
+        if self.registry.is_enabled(MainThreadAnalyzer::RULE.id) {
+            let call_graph = CallGraph::build(index);
+            let main_thread_analyzer = MainThreadAnalyzer::new(self.config.clone());
+            report.main_thread_estimates = main_thread_analyzer.analyze(index, &call_graph);
+            let main_thread_issues = main_thread_analyzer.issues(&report.main_thread_estimates);
+            merge_project_issues(report, index, main_thread_issues, MainThreadAnalyzer::RULE.id, self.config.min_severity);
+        }
+
+        if self.registry.is_enabled(DeadCodeAnalyzer::RULE.id) {
+            let dead_code_analyzer = DeadCodeAnalyzer::new();
+            report.dead_code = dead_code_analyzer.analyze(index);
+            let dead_code_issues = dead_code_analyzer.issues(&report.dead_code);
+            merge_project_issues(report, index, dead_code_issues, DeadCodeAnalyzer::RULE.id, self.config.min_severity);
+        }
+
+        if self.registry.is_enabled(CloneDetector::RULE.id) {
+            report.clones = CloneDetector::new(self.config.clone()).analyze(index);
+            report.project_stats.duplication_percentage =
+                clone_detector::duplication_percentage(&report.clones, report.project_stats.total_lines);
+        }
+    }
+}
+
+/// Index du projet à partir des faits en cache ; les fichiers absents du cache sont indexés puis enregistrés
+fn index_with_cache(
+    cache: &mut CacheSession,
+    stats: &mut CacheStats,
+    loaded: Vec<(PathBuf, String)>,
+    hashes: Vec<String>,
+    context: Vec<(PathBuf, String)>,
+) -> ProjectIndex {
+    let context_hashes: Vec<String> = context.par_iter().map(|(_, content)| content_hash(content)).collect();
+    let sources: Vec<(PathBuf, String, String)> = loaded
+        .into_iter()
+        .zip(hashes)
+        .chain(context.into_iter().zip(context_hashes))
+        .map(|((path, content), hash)| (path, content, hash))
+        .collect();
+
+    let files: Vec<(IndexedFile, String, bool)> = sources
+        .into_par_iter()
+        .map(|(path, content, hash)| match cache.indexed_file(&hash, path.clone(), content.clone()) {
+            Some(file) => (file, hash, true),
+            None => (IndexedFile::new(path, content), hash, false),
+        })
+        .collect();
+    for (file, hash, hit) in &files {
+        cache.see(hash, &file.path);
+        if *hit {
+            stats.index_hits += 1;
+        } else {
+            stats.index_misses += 1;
+            cache.store_index(hash, file);
+        }
+    }
+    ProjectIndex::from_files(files.into_iter().map(|(file, _, _)| file).collect())
+}
+
+/// Écarte les résultats du projet qui ne concernent que des fichiers de contexte
+fn retain_analyzed_files(report: &mut PerformanceReport, analyzed: &HashSet<PathBuf>) {
+    report.main_thread_estimates.retain(|estimate| analyzed.contains(&estimate.file_path));
+    report.dead_code.retain(|finding| analyzed.contains(&finding.file_path));
+    report.clones.retain(|group| group.locations.iter().any(|location| analyzed.contains(&location.file_path)));
+
+    // Duplication mesurée sur les seules lignes des fichiers analysés
+    let analyzed_locations: Vec<CloneGroup> = report
+        .clones
+        .iter()
+        .map(|group| CloneGroup {
+            clone_type: group.clone_type,
+            token_count: group.token_count,
+            locations: group.locations.iter().filter(|l| analyzed.contains(&l.file_path)).cloned().collect(),
+        })
+        .collect();
+    report.project_stats.duplication_percentage =
+        clone_detector::duplication_percentage(&analyzed_locations, report.project_stats.total_lines);
+}
+
+fn is_reported(severity: Severity, min_severity: Severity) -> bool {
+    severity as u8 <= min_severity as u8
+}
+
+/// Ajoute le résultat d'un fichier au rapport (compteurs, statistiques, hotspots)
+fn record_result(report: &mut PerformanceReport, result: AnalysisResult) {
+    for issue in &result.issues {
+        *report.issue_count_by_type.entry(issue.issue_type).or_insert(0) += 1;
+        *report.issue_count_by_severity.entry(issue.severity).or_insert(0) += 1;
+    }
+
+    report.project_stats.total_lines += result.line_count;
+    if !result.issues.is_empty() {
+        report.project_stats.problematic_files += 1;
+    }
+
+    let criticality_score = calculate_criticality_score(&result);
+    if criticality_score > 0.0 {
+        report.hotspots.push(HotspotFile {
+            file_path: result.file_path.clone(),
+            issue_count: result.issues.len(),
+            criticality_score,
+        });
+    }
+
+    report.files_analyzed.push(result);
+}
+
+/// Recalcule compteurs, fichiers problématiques et hotspots après filtrage des problèmes
+///
+/// Le score de santé n'est pas modifié : il reste celui du projet entier.
+pub fn recount_issues(report: &mut PerformanceReport) {
+    report.issue_count_by_type.clear();
+    report.issue_count_by_severity.clear();
+    report.hotspots.clear();
+    report.project_stats.problematic_files = 0;
+
+    for result in &report.files_analyzed {
+        for issue in &result.issues {
+            *report.issue_count_by_type.entry(issue.issue_type).or_insert(0) += 1;
+            *report.issue_count_by_severity.entry(issue.severity).or_insert(0) += 1;
+        }
+        if !result.issues.is_empty() {
+            report.project_stats.problematic_files += 1;
+        }
+
+        let criticality_score = calculate_criticality_score(result);
+        if criticality_score > 0.0 {
+            report.hotspots.push(HotspotFile {
+                file_path: result.file_path.clone(),
+                issue_count: result.issues.len(),
+                criticality_score,
+            });
+        }
+    }
+
+    report.hotspots.sort_by(|a, b| b.criticality_score.partial_cmp(&a.criticality_score).unwrap());
+    report.hotspots.truncate(HOTSPOT_COUNT);
+}
+
+/// Calcule le score de criticité d'un fichier basé sur ses problèmes
+pub(crate) fn calculate_criticality_score(result: &AnalysisResult) -> f64 {
+    let mut score = 0.0;
+
+    for issue in &result.issues {
+        score += match issue.severity {
+            Severity::Critical => 10.0,
+            Severity::High => 5.0,
+            Severity::Medium => 2.0,
+            Severity::Low => 0.5,
+        };
+    }
+
+    // Ajouter un facteur basé sur la complexité
+    score += result.metrics.max_cyclomatic_complexity * 0.5;
+    score += result.metrics.max_nesting_depth as f64 * 0.3;
+
+    // Normaliser par rapport à la taille du fichier
+    if result.line_count > 0 {
+        score *= 1.0 + (result.line_count as f64 / 500.0).min(1.0);
+    }
+
+    score
+}
+
+/// Rattache aux résultats de leurs fichiers les problèmes détectés à l'échelle du projet
+fn merge_project_issues(
+    report: &mut PerformanceReport,
+    project: &ProjectIndex,
+    issues: Vec<(PathBuf, FileIssue)>,
+    rule_id: &str,
+    min_severity: Severity,
+) {
+    for (file_path, mut issue) in issues {
+        issue.rule_id = Some(rule_id.to_string());
+        if !is_reported(issue.severity, min_severity) {
+            continue;
+        }
+        let Some(index) = report.files_analyzed.iter().position(|r| r.file_path == file_path) else {
+            continue;
+        };
+        let indexed = project.file_index(&file_path).map(|i| &project.files[i]);
+        if let Some(file) = indexed.filter(|_| issue.declaration.is_none()) {
+            issue.declaration = enclosing_declaration(&file.declarations, &file.functions, issue.line.saturating_sub(1));
+        }
+        let suppressed = indexed.is_some_and(|file| Suppressions::parse(&file.content).is_suppressed(&issue));
+        if suppressed {
+            report.files_analyzed[index].suppressed_issues.push(issue);
+            continue;
+        }
+
+        *report.issue_count_by_type.entry(issue.issue_type).or_insert(0) += 1;
+        *report.issue_count_by_severity.entry(issue.severity).or_insert(0) += 1;
+        if report.files_analyzed[index].issues.is_empty() {
+            report.project_stats.problematic_files += 1;
+        }
+        report.files_analyzed[index].issues.push(issue);
+
+        // Recalculer le score de criticité du fichier
+        let criticality_score = calculate_criticality_score(&report.files_analyzed[index]);
+        let issue_count = report.files_analyzed[index].issues.len();
+        match report.hotspots.iter_mut().find(|h| h.file_path == file_path) {
+            Some(hotspot) => {
+                hotspot.criticality_score = criticality_score;
+                hotspot.issue_count = issue_count;
+            }
+            None => report.hotspots.push(HotspotFile {
+                file_path,
+                issue_count,
+                criticality_score,
+            }),
+        }
+    }
+}
+
+/// Calcule le score de santé global du projet
+pub(crate) fn calculate_health_score(report: &mut PerformanceReport) {
+    let total_files = report.project_stats.total_files;
+    if total_files == 0 {
+        report.project_stats.health_score = 100.0;
+        return;
+    }
+
+    let problematic_files = report.project_stats.problematic_files;
+    let problem_ratio = problematic_files as f64 / total_files as f64;
+
+    // Calculer le score en fonction du nombre de problèmes de chaque sévérité
+    let mut severity_score = 0.0;
+    let critical_issues = *report.issue_count_by_severity.get(&Severity::Critical).unwrap_or(&0) as f64;
+    let high_issues = *report.issue_count_by_severity.get(&Severity::High).unwrap_or(&0) as f64;
+    let medium_issues = *report.issue_count_by_severity.get(&Severity::Medium).unwrap_or(&0) as f64;
+    let low_issues = *report.issue_count_by_severity.get(&Severity::Low).unwrap_or(&0) as f64;
+
+    // Les problèmes critiques ont plus d'impact
+    severity_score += critical_issues * 5.0;
+    severity_score += high_issues * 2.0;
+    severity_score += medium_issues * 0.5;
+    severity_score += low_issues * 0.1;
+
+    // Normaliser par le nombre total de lignes
+    if report.project_stats.total_lines > 0 {
+        severity_score = severity_score * 1000.0 / report.project_stats.total_lines as f64;
+    }
+
+    // Combiner les scores
+    let health_score = 100.0 - (problem_ratio * 50.0 + severity_score.min(50.0));
+
+    report.project_stats.health_score = health_score.clamp(0.0, 100.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::NoPrintRule;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_custom_rule_on_in_memory_sources() {
+        let analyzed = Arc::new(AtomicUsize::new(0));
+        let counter = analyzed.clone();
+
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(RuleRegistry::new())
+            .with_source("CardView.swift", "struct CardView {\n    func tap() {\n        print(\"tap\")\n    }\n}\n")
+            .with_source("Deck.swift", "struct Deck {}\n")
+            .with_rule(NoPrintRule)
+            .on_file_analyzed(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+            .run();
+
+        assert_eq!(analyzed.load(Ordering::SeqCst), 2);
+        assert_eq!(report.project_stats.total_files, 2);
+        assert_eq!(report.project_stats.total_lines, 6);
+        assert_eq!(report.project_stats.problematic_files, 1);
+        assert_eq!(report.files_analyzed[0].issues.len(), 1);
+        assert_eq!(report.files_analyzed[0].issues[0].line, 3);
+        assert_eq!(report.files_analyzed[0].issues[0].rule_id.as_deref(), Some("cardapp.no-print"));
+        assert_eq!(report.hotspots[0].file_path, PathBuf::from("CardView.swift"));
+    }
+
+    #[test]
+    fn test_min_severity_applies_to_custom_rules() {
+        let config = AnalysisConfig {
+            min_severity: Severity::Medium,
+            ..AnalysisConfig::default()
+        };
+
+        let report = Analyzer::new(config)
+            .with_source("CardView.swift", "func tap() {\n    print(\"tap\")\n}\n")
+            .with_rule(NoPrintRule)
+            .run();
+
+        assert!(report.files_analyzed[0].issues.iter().all(|i| i.severity != Severity::Low));
+        assert_eq!(report.issue_count_by_severity.get(&Severity::Low), None);
+    }
+
+    #[test]
+    fn test_unreadable_file_is_reported_as_warning() {
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_files(vec![PathBuf::from("/inexistant/CardView.swift")])
+            .with_source("Deck.swift", "struct Deck {}\n")
+            .run();
+
+        assert_eq!(report.files_analyzed.len(), 1);
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("/inexistant/CardView.swift"));
+    }
+}
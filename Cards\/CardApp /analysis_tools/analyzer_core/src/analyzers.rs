@@ -0,0 +1,957 @@
+use crate::fetch_request_analyzer::FetchRequestAnalyzer;
+use crate::models::{FileIssue, FileMetrics, IssueType, Replacement, Severity};
+use crate::resource_analyzer::ResourceLifecycleAnalyzer;
+use crate::rules::{AnalysisLevel, Rule, RuleCategory, RuleContext, RuleExplanation, RuleMetadata, SourceFile};
+use crate::swift_source;
+use crate::swiftui_analyzer::SwiftUIAnalyzer;
+use regex::Regex;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+
+/// Longueur (en lignes) au-delà de laquelle une fonction est signalée
+const MAX_FUNCTION_LENGTH: usize = 50;
+
+lazy_static! {
+    static ref FUNCTION_REGEX: Regex = Regex::new(r"(?m)^\s*(?:func|var|let)\s+(\w+).*\{$").unwrap();
+    static ref COMPLEXITY_INCREASING: Regex = Regex::new(r"\b(if|while|for|switch|case\s+.*:|guard|&&|\|\|)\b").unwrap();
+    static ref SELF_CAPTURE_REGEX: Regex = Regex::new(r"\{\s*\[\s*(weak\s+self|unowned\s+self|self)?").unwrap();
+    static ref CLOSURE_SELF_REGEX: Regex = Regex::new(r"\{.*\bself\.").unwrap();
+    static ref EXPLICIT_CAPTURE_REGEX: Regex = Regex::new(r"\[\s*(weak|unowned)\s+self\s*\]").unwrap();
+    // Corps de fonction ou de propriété calculée ouvert sur la même ligne : pas une closure
+    static ref DECLARATION_BODY_REGEX: Regex = Regex::new(r"\b(func|var|init|get|set)\b[^=]*\{").unwrap();
+    static ref CORE_DATA_REGEX: Regex = Regex::new(
+        r"\b(NSManagedObjectContext|NSFetchRequest|NSPredicate|NSFetchedResultsController|NSBatchDeleteRequest|NSPersistentContainer)\b"
+    ).unwrap();
+    static ref MAIN_CONTEXT_REGEX: Regex = Regex::new(r"\.viewContext\b|\bmainContext\b").unwrap();
+    static ref SAVE_REGEX: Regex = Regex::new(r"\bcontext\.save\(\)").unwrap();
+    static ref VIEW_CONTEXT_PERFORM_REGEX: Regex = Regex::new(r"\.viewContext\.perform\(").unwrap();
+    // Paramètre du bloc capturé quand il est nommé (`{ context in`)
+    static ref BACKGROUND_VIEW_CONTEXT_REGEX: Regex =
+        Regex::new(r"\.performBackgroundTask\s*\{\s*(?:(\w+)\s+in\b)?[^}]*?\.viewContext\b").unwrap();
+    static ref ASYNC_REGEX: Regex = Regex::new(r"\basync\b|\bawait\b|\bcompletionHandler\b|\b(DispatchQueue|Task|TaskGroup)\b").unwrap();
+    static ref MAIN_QUEUE_ASYNC_REGEX: Regex = Regex::new(r"\bDispatchQueue\.main\b.*\basync\b").unwrap();
+    static ref SHARED_COLLECTION_REGEX: Regex = Regex::new(r"^\s*(?:\w+\s+)*var\s+\w+\s*:\s*(Array|Dictionary|Set)<").unwrap();
+    static ref NONISOLATED_REGEX: Regex = Regex::new(r"\bnonisolated\s+(?:func|var)\s+(\w+)").unwrap();
+    static ref LOOP_FILTER_REGEX: Regex = Regex::new(r"for\s+.*\s+in\s+.*\s*\{\s*if\s+.*\s*\{\s*.*\s*\}").unwrap();
+    static ref COLLECTION_IN_LOOP_REGEX: Regex = Regex::new(
+        r"for\s+.*\s+in\s+.*\s*\{\s*.*\.append\(|for\s+.*\s+in\s+.*\s*\{\s*.*\[\w+\]\s*="
+    ).unwrap();
+    static ref DELEGATE_REGEX: Regex = Regex::new(r"\bvar\s+\w+\s*:.*\bdelegate\b|\bvar\s+delegate\s*:").unwrap();
+    static ref UNSIZED_COLLECTION_REGEX: Regex = Regex::new(r"var\s+\w+\s*:\s*\[.*\]\s*=\s*\[\]").unwrap();
+    static ref APPEND_SELF_REGEX: Regex = Regex::new(r"\.append\(\s*self\s*\)").unwrap();
+    static ref FORCE_UNWRAP_REGEX: Regex = Regex::new(r"(\w+|\)|\])!").unwrap();
+}
+
+/// Fonction de détection d'une règle intégrée
+type Detector = fn(&SourceFile, &RuleContext) -> Vec<FileIssue>;
+
+/// Règle intégrée implémentée par une simple fonction de détection
+struct DetectorRule {
+    metadata: RuleMetadata,
+    detect: Detector,
+}
+
+impl Rule for DetectorRule {
+    fn metadata(&self) -> &RuleMetadata {
+        &self.metadata
+    }
+
+    fn check(&self, file: &SourceFile, context: &RuleContext) -> Vec<FileIssue> {
+        (self.detect)(file, context)
+    }
+}
+
+/// Règles par fichier fournies avec l'analyseur, dans leur ordre d'exécution
+pub fn builtin_rules() -> Vec<Arc<dyn Rule>> {
+    let detectors: [(RuleMetadata, Detector); 19] = [
+        (
+            RuleMetadata::new(
+                "complexity.cyclomatic",
+                RuleCategory::Complexity,
+                Severity::Medium,
+                "Fonction dont la complexité cyclomatique dépasse le seuil configuré",
+            )
+            .with_level(AnalysisLevel::Token)
+            .with_explanation(RuleExplanation {
+                rationale: "Chaque if, guard, boucle, case ou opérateur logique ajoute un chemin d'exécution. Au-delà du seuil (--complexity-threshold, 10 par défaut), la fonction devient difficile à tester exhaustivement et chaque modification risque de casser un cas oublié. La sévérité augmente à seuil + 5 puis au double du seuil.",
+                bad_example: "func grade(_ card: Card, answer: Answer) -> Int {\n    if card.isNew {\n        if answer == .easy { return 4 } else if answer == .good { return 3 }\n    } else if card.lapses > 3 && answer != .again {\n        // … une douzaine de branches supplémentaires\n    }\n    return 0\n}",
+                good_example: "func grade(_ card: Card, answer: Answer) -> Int {\n    card.isNew ? newCardGrade(answer) : reviewGrade(card, answer)\n}",
+                conventions: &["2.1 Principe de Séparation"],
+            }),
+            check_cyclomatic_complexity,
+        ),
+        (
+            RuleMetadata::new(
+                "complexity.function-length",
+                RuleCategory::Complexity,
+                Severity::Low,
+                "Fonction de plus de 50 lignes",
+            )
+            .with_level(AnalysisLevel::Token)
+            .with_explanation(RuleExplanation {
+                rationale: "Une fonction de plus de 50 lignes mélange généralement plusieurs responsabilités (chargement, transformation, mise à jour de l'état). La découper rend chaque étape nommée, testable et réutilisable.",
+                bad_example: "func loadDeck() async {\n    // 80 lignes : requête, tri, calcul des statistiques, mise à jour de l'état\n}",
+                good_example: "func loadDeck() async {\n    let cards = await fetchCards()\n    let stats = computeStatistics(for: cards)\n    updateState { $0.cards = cards; $0.stats = stats }\n}",
+                conventions: &["2.1 Principe de Séparation"],
+            }),
+            check_function_length,
+        ),
+        (
+            RuleMetadata::new(
+                "complexity.nesting",
+                RuleCategory::Complexity,
+                Severity::Medium,
+                "Structure de contrôle imbriquée au-delà du seuil configuré",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Une condition ou une boucle indentée au-delà du seuil (--nesting-threshold, 3 niveaux de 4 espaces par défaut) oblige à garder tout le contexte en tête. Les sorties anticipées avec guard et l'extraction de fonctions aplatissent le code.",
+                bad_example: "for deck in decks {\n    if deck.isActive {\n        for card in deck.cards {\n            if card.isDue {\n                schedule(card)\n            }\n        }\n    }\n}",
+                good_example: "for card in decks.filter(\\.isActive).flatMap(\\.cards) where card.isDue {\n    schedule(card)\n}",
+                conventions: &[],
+            }),
+            check_nesting_depth,
+        ),
+        (
+            RuleMetadata::new(
+                "memory.closure-self-capture",
+                RuleCategory::Memory,
+                Severity::High,
+                "Closure utilisant self sans capture [weak self] ou [unowned self]",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Une closure conservée par un service, un publisher ou une tâche retient self fortement. Si self retient à son tour ce service, aucun des deux n'est libéré : le ViewModel survit à sa vue et continue de recevoir des mises à jour.",
+                bad_example: "service.load { cards in self.cards = cards }",
+                good_example: "service.load { [weak self] cards in\n    guard let self = self else { return }\n    self.cards = cards\n}",
+                conventions: &["5.1 Utilisation de `self` dans les Closures", "5.2 Vérification de `self` après `[weak self]`"],
+            }),
+            check_closure_captures,
+        ),
+        (
+            RuleMetadata::new(
+                "coredata.main-thread-operation",
+                RuleCategory::CoreData,
+                Severity::High,
+                "Fetch, sauvegarde ou suppression sur le contexte principal",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "viewContext est lié à la file principale : un fetch volumineux, une sauvegarde ou une suppression en masse bloque l'interface le temps de l'accès au store SQLite.",
+                bad_example: "let cards = try container.viewContext.fetch(NSFetchRequest<CardEntity>(entityName: \"CardEntity\"))",
+                good_example: "try await container.performBackgroundTask { context in\n    let cards = try context.fetch(NSFetchRequest<CardEntity>(entityName: \"CardEntity\"))\n    // … traitement, puis transmission des identifiants à l'UI\n}",
+                conventions: &["6.3 Synchronisation de l'Interface Utilisateur"],
+            }),
+            check_main_context_operations,
+        ),
+        (
+            RuleMetadata::new(
+                "coredata.unhandled-fetch",
+                RuleCategory::CoreData,
+                Severity::Medium,
+                "Requête CoreData sans try/catch",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Un fetch peut échouer (store corrompu, migration incomplète, prédicat invalide). Sans gestion d'erreur, l'échec est silencieux ou fait planter l'application au lieu de mettre à jour l'état d'erreur du ViewModel.",
+                bad_example: "let request = NSFetchRequest<CardEntity>(entityName: \"CardEntity\"); let cards = context.fetch(request)",
+                good_example: "do {\n    cards = try context.fetch(request)\n} catch {\n    updateState { $0.error = error.localizedDescription }\n    logger.error(\"Échec du chargement : \\(error)\")\n}",
+                conventions: &["7.2 Traitement des Erreurs"],
+            }),
+            check_unhandled_fetch,
+        ),
+        (
+            RuleMetadata::new(
+                "coredata.save-on-main-queue",
+                RuleCategory::CoreData,
+                Severity::High,
+                "context.save() planifié dans DispatchQueue.main.async",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Renvoyer la sauvegarde sur la file principale écrit le store pendant que l'interface attend. Seule la mise à jour de l'UI doit revenir sur la file principale.",
+                bad_example: "DispatchQueue.main.async { try? context.save() }",
+                good_example: "try await backgroundContext.perform {\n    try backgroundContext.save()\n}\nawait MainActor.run { updateState { $0.isSaving = false } }",
+                conventions: &["6.3 Synchronisation de l'Interface Utilisateur"],
+            }),
+            check_save_on_main_queue,
+        ),
+        (
+            RuleMetadata::new(
+                "coredata.unhandled-save",
+                RuleCategory::CoreData,
+                Severity::High,
+                "context.save() sans try",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Une sauvegarde qui échoue (contrainte de validation, conflit de fusion) laisse le contexte dans un état non persisté. L'erreur doit être traitée pour informer l'utilisateur et éviter une perte de données.",
+                bad_example: "context.save()",
+                good_example: "do {\n    try context.save()\n} catch {\n    context.rollback()\n    logger.error(\"Échec de la sauvegarde : \\(error)\")\n}",
+                conventions: &["7.2 Traitement des Erreurs", "7.3 Récupération après Erreur"],
+            }),
+            check_unhandled_save,
+        ),
+        (
+            RuleMetadata::new(
+                "coredata.view-context-perform",
+                RuleCategory::CoreData,
+                Severity::Low,
+                ".perform() sur viewContext, déjà lié au thread principal",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Depuis le code d'interface, déjà sur la file principale, perform() sur viewContext ne fait que différer le bloc et rend l'ordre d'exécution moins lisible.",
+                bad_example: "container.viewContext.perform() { deck.name = newName }",
+                good_example: "@MainActor\nfunc rename(_ deck: DeckEntity, to newName: String) {\n    deck.name = newName\n}",
+                conventions: &["6.3 Synchronisation de l'Interface Utilisateur"],
+            }),
+            check_view_context_perform,
+        ),
+        (
+            RuleMetadata::new(
+                "coredata.background-view-context",
+                RuleCategory::CoreData,
+                Severity::Critical,
+                "viewContext utilisé dans un bloc performBackgroundTask",
+            )
+            .with_level(AnalysisLevel::Token)
+            .with_explanation(RuleExplanation {
+                rationale: "Un contexte CoreData n'est utilisable que sur sa propre file. Accéder à viewContext depuis le thread d'un performBackgroundTask viole ce confinement : corruptions et plantages aléatoires, détectés par -com.apple.CoreData.ConcurrencyDebug 1.",
+                bad_example: "container.performBackgroundTask { context in\n    try? container.viewContext.save()\n}",
+                good_example: "container.performBackgroundTask { context in\n    try? context.save()\n}",
+                conventions: &["6.3 Synchronisation de l'Interface Utilisateur"],
+            }),
+            check_background_view_context,
+        ),
+        (
+            RuleMetadata::new(
+                "concurrency.main-queue-async",
+                RuleCategory::Concurrency,
+                Severity::High,
+                "Travail asynchrone renvoyé sur DispatchQueue.main",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Mélanger DispatchQueue.main et la concurrence structurée contourne les vérifications d'isolation du compilateur : l'état du ViewModel peut être modifié depuis deux contextes sans que Swift le signale.",
+                bad_example: "DispatchQueue.main.async { self.state.isLoading = false }",
+                good_example: "@MainActor\nfunc finishLoading() {\n    updateState { $0.isLoading = false }\n}",
+                conventions: &["6.1 Utilisation de `async/await`", "6.3 Synchronisation de l'Interface Utilisateur"],
+            }),
+            check_main_queue_async,
+        ),
+        (
+            RuleMetadata::new(
+                "concurrency.shared-collection",
+                RuleCategory::Concurrency,
+                Severity::Medium,
+                "Collection mutable déclarée dans un fichier utilisant du code asynchrone",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Array, Dictionary et Set ne sont pas thread-safe : des écritures concurrentes depuis plusieurs tâches corrompent la collection. L'état partagé doit être isolé par un acteur ou par @MainActor.",
+                bad_example: "final class CardCache {\n    var entries: Dictionary<UUID, Card> = [:]\n    func warm() async { entries[card.id] = card }\n}",
+                good_example: "actor CardCache {\n    private var entries: Dictionary<UUID, Card> = [:]\n    func store(_ card: Card) { entries[card.id] = card }\n}",
+                conventions: &["6.1 Utilisation de `async/await`"],
+            }),
+            check_shared_collections,
+        ),
+        (
+            RuleMetadata::new(
+                "concurrency.nonisolated-state",
+                RuleCategory::Concurrency,
+                Severity::High,
+                "Membre nonisolated d'un acteur accédant à l'état isolé",
+            )
+            .with_level(AnalysisLevel::Token)
+            .with_explanation(RuleExplanation {
+                rationale: "Un membre nonisolated s'exécute hors de l'acteur : lire ou écrire l'état isolé depuis ce membre réintroduit exactement la course de données que l'acteur devait empêcher.",
+                bad_example: "actor StudySession {\n    var reviewed = 0\n    nonisolated func progress() -> Int { self.reviewed }\n}",
+                good_example: "actor StudySession {\n    var reviewed = 0\n    func progress() -> Int { reviewed }\n}",
+                conventions: &["6.1 Utilisation de `async/await`"],
+            }),
+            check_nonisolated_members,
+        ),
+        (
+            RuleMetadata::new(
+                "performance.loop-filter",
+                RuleCategory::Performance,
+                Severity::Low,
+                "Boucle avec condition remplaçable par filter/compactMap",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Une boucle qui ne fait que tester une condition exprime une sélection : filter, first(where:) ou une clause where la rendent explicite et évitent les tableaux intermédiaires construits à la main.",
+                bad_example: "for card in cards { if card.isDue { due.append(card) } }",
+                good_example: "let due = cards.filter(\\.isDue)",
+                conventions: &[],
+            }),
+            check_loop_filters,
+        ),
+        (
+            RuleMetadata::new(
+                "performance.collection-in-loop",
+                RuleCategory::Performance,
+                Severity::Medium,
+                "Collection construite élément par élément dans une boucle",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Ajouter les éléments un à un provoque des réallocations successives du tableau. map ou reserveCapacity allouent une seule fois.",
+                bad_example: "for card in cards { ids.append(card.id) }",
+                good_example: "let ids = cards.map(\\.id)",
+                conventions: &[],
+            }),
+            check_collections_in_loops,
+        ),
+        (
+            RuleMetadata::new(
+                "performance.unsized-collection",
+                RuleCategory::Performance,
+                Severity::Low,
+                "Collection initialisée vide sans capacité réservée",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Lorsque la taille finale est connue, réserver la capacité évite les recopies successives pendant le remplissage. Sans importance pour les petites collections.",
+                bad_example: "var results: [Card] = []",
+                good_example: "var results: [Card] = []\nresults.reserveCapacity(deck.cards.count)",
+                conventions: &[],
+            }),
+            check_unsized_collections,
+        ),
+        (
+            RuleMetadata::new(
+                "memory.strong-delegate",
+                RuleCategory::Memory,
+                Severity::High,
+                "Propriété delegate sans weak ni unowned",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Le délégué possède généralement l'objet qui le référence. Une référence forte dans l'autre sens crée un cycle : ni l'un ni l'autre n'est jamais libéré.",
+                bad_example: "var delegate: CardEditorDelegate?",
+                good_example: "weak var delegate: CardEditorDelegate?",
+                conventions: &["5.4 Références Circulaires"],
+            }),
+            check_strong_delegates,
+        ),
+        (
+            RuleMetadata::new(
+                "memory.self-in-collection",
+                RuleCategory::Memory,
+                Severity::High,
+                "self ajouté à une collection (référence forte conservée ailleurs)",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Un objet qui s'inscrit lui-même dans un tableau d'observateurs reste retenu par ce tableau tant qu'il ne s'en retire pas explicitement, ce qui est souvent oublié.",
+                bad_example: "StudyCoordinator.shared.observers.append(self)",
+                good_example: "private let observers = NSHashTable<AnyObject>.weakObjects()\nobservers.add(self)",
+                conventions: &["5.4 Références Circulaires"],
+            }),
+            check_self_in_collections,
+        ),
+        (
+            RuleMetadata::new(
+                "memory.force-unwrap",
+                RuleCategory::Memory,
+                Severity::Low,
+                "Optionnel déballé de force avec !",
+            )
+            .with_explanation(RuleExplanation {
+                rationale: "Un optionnel déballé avec ! arrête l'application dès qu'il vaut nil : objet CoreData supprimé entre-temps, relation non chargée, réponse incomplète. Les types implicitement déballés des déclarations (@IBOutlet), try! et as! ne sont pas signalés.",
+                bad_example: "let deck = decks.first!\ntitleLabel.text = deck.name!",
+                good_example: "guard let deck = decks.first, let name = deck.name else { return }\ntitleLabel.text = name",
+                conventions: &["7.2 Traitement des Erreurs"],
+            }),
+            check_force_unwraps,
+        ),
+    ];
+
+    let mut rules: Vec<Arc<dyn Rule>> = detectors
+        .into_iter()
+        .map(|(metadata, detect)| Arc::new(DetectorRule { metadata, detect }) as Arc<dyn Rule>)
+        .collect();
+    rules.push(Arc::new(ResourceLifecycleAnalyzer::new()));
+    rules.push(Arc::new(SwiftUIAnalyzer::new()));
+    rules.push(Arc::new(FetchRequestAnalyzer::new()));
+    rules
+}
+
+/// Fonction repérée dans le source (lignes indexées à partir de 0)
+struct FunctionSpan<'a> {
+    name: &'a str,
+    start: usize,
+    end: usize,
+    complexity: u32,
+}
+
+/// Repère les fonctions et propriétés à corps en comptant les accolades
+fn function_spans<'a>(content: &'a str, lines: &[&str]) -> Vec<FunctionSpan<'a>> {
+    let mut spans = Vec::new();
+
+    for func_match in FUNCTION_REGEX.captures_iter(content) {
+        let start = content[..func_match.get(0).unwrap().start()].matches('\n').count();
+
+        // Trouver la fin de la fonction en comptant les accolades
+        let mut brace_count: i32 = 0;
+        let mut end = start;
+        let mut func_content = String::new();
+        for (i, line) in lines.iter().enumerate().skip(start) {
+            brace_count += line.chars().filter(|&c| c == '{').count() as i32;
+            brace_count -= line.chars().filter(|&c| c == '}').count() as i32;
+            func_content.push_str(line);
+            func_content.push('\n');
+            end = i;
+
+            if brace_count <= 0 {
+                break;
+            }
+        }
+
+        // Calculer la complexité en comptant les points de décision
+        let complexity = 1 + COMPLEXITY_INCREASING.find_iter(&func_content).count() as u32;
+        spans.push(FunctionSpan {
+            name: func_match.get(1).unwrap().as_str(),
+            start,
+            end,
+            complexity,
+        });
+    }
+
+    spans
+}
+
+/// Métriques d'un fichier, calculées indépendamment des règles actives
+pub fn compute_metrics(content: &str) -> FileMetrics {
+    let lines: Vec<&str> = content.lines().collect();
+    let spans = function_spans(content, &lines);
+    let total_complexity: u32 = spans.iter().map(|span| span.complexity).sum();
+
+    let mut metrics = FileMetrics {
+        functions_count: spans.len(),
+        max_cyclomatic_complexity: spans.iter().map(|span| span.complexity).max().unwrap_or(0) as f64,
+        avg_cyclomatic_complexity: if spans.is_empty() {
+            0.0
+        } else {
+            total_complexity as f64 / spans.len() as f64
+        },
+        ..FileMetrics::default()
+    };
+
+    for line in &lines {
+        // 4 espaces = 1 niveau
+        let indent_level = line.chars().take_while(|c| c.is_whitespace()).count() / 4;
+        metrics.max_nesting_depth = metrics.max_nesting_depth.max(indent_level as u32);
+
+        if SELF_CAPTURE_REGEX.is_match(line) {
+            metrics.self_capture_count += 1;
+        }
+        if CORE_DATA_REGEX.is_match(line) {
+            metrics.core_data_operation_count += 1;
+        }
+        if ASYNC_REGEX.is_match(line) {
+            metrics.async_operation_count += 1;
+            if line.contains("DispatchQueue") || line.contains("Task") || line.contains("Thread") {
+                metrics.concurrency_primitive_count += 1;
+            }
+        }
+    }
+
+    metrics
+}
+
+/// Problème signalé sur une ligne, avec la ligne comme extrait de code
+fn line_issue(
+    issue_type: IssueType,
+    severity: Severity,
+    index: usize,
+    line: &str,
+    message: impl Into<String>,
+    suggestion: impl Into<String>,
+) -> FileIssue {
+    FileIssue {
+        issue_type,
+        severity,
+        line: index + 1,
+        column: None,
+        message: message.into(),
+        suggestion: Some(suggestion.into()),
+        code_snippet: Some(line.to_string()),
+        rule_id: None,
+        declaration: None,
+        ownership: None,
+        fix: None,
+    }
+}
+
+/// Fonctions dont la complexité cyclomatique dépasse le seuil
+fn check_cyclomatic_complexity(file: &SourceFile, context: &RuleContext) -> Vec<FileIssue> {
+    let lines: Vec<&str> = file.lines().collect();
+    let threshold = context.config.cyclomatic_complexity_threshold;
+
+    function_spans(file.content, &lines)
+        .into_iter()
+        .filter(|span| span.complexity > threshold)
+        .map(|span| {
+            let severity = if span.complexity > threshold * 2 {
+                Severity::Critical
+            } else if span.complexity > threshold + 5 {
+                Severity::High
+            } else {
+                Severity::Medium
+            };
+            line_issue(
+                IssueType::HighComplexity,
+                severity,
+                span.start,
+                lines[span.start],
+                format!(
+                    "La fonction '{}' a une complexité cyclomatique de {}, supérieure au seuil de {}",
+                    span.name, span.complexity, threshold
+                ),
+                format!("Refactorisez la fonction '{}' en plus petites fonctions ou méthodes", span.name),
+            )
+        })
+        .collect()
+}
+
+/// Fonctions trop longues
+fn check_function_length(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    let lines: Vec<&str> = file.lines().collect();
+
+    function_spans(file.content, &lines)
+        .into_iter()
+        .filter(|span| span.end - span.start + 1 > MAX_FUNCTION_LENGTH)
+        .map(|span| {
+            line_issue(
+                IssueType::HighComplexity,
+                Severity::Low,
+                span.start,
+                lines[span.start],
+                format!("La fonction '{}' est trop longue ({} lignes)", span.name, span.end - span.start + 1),
+                "Décomposez cette fonction en plusieurs méthodes plus petites",
+            )
+        })
+        .collect()
+}
+
+/// Structures de contrôle trop profondément imbriquées
+fn check_nesting_depth(file: &SourceFile, context: &RuleContext) -> Vec<FileIssue> {
+    let threshold = context.config.nesting_depth_threshold;
+    let mut issues = Vec::new();
+
+    for (i, line) in file.lines().enumerate() {
+        let indent_level = line.chars().take_while(|c| c.is_whitespace()).count() / 4;
+        if indent_level > threshold as usize
+            && (line.contains("if ") || line.contains("for ") || line.contains("while ") || line.contains("switch "))
+        {
+            issues.push(line_issue(
+                IssueType::DeepNesting,
+                Severity::Medium,
+                i,
+                line,
+                format!("Profondeur d'imbrication excessive ({}) détectée, seuil de {}", indent_level, threshold),
+                "Extrayez ce code dans une fonction séparée ou utilisez la programmation fonctionnelle",
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Closures utilisant self sans capture explicite
+fn check_closure_captures(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            CLOSURE_SELF_REGEX.is_match(line)
+                && !EXPLICIT_CAPTURE_REGEX.is_match(line)
+                && !DECLARATION_BODY_REGEX.is_match(line)
+        })
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::UnsafeClosure,
+                Severity::High,
+                i,
+                line,
+                "Utilisation potentielle de 'self' sans capture [weak self] dans une closure",
+                "Utilisez [weak self] ou [unowned self] pour éviter les cycles de rétention",
+            )
+        })
+        .collect()
+}
+
+/// Opérations lourdes sur le contexte principal
+fn check_main_context_operations(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            CORE_DATA_REGEX.is_match(line)
+                && MAIN_CONTEXT_REGEX.is_match(line)
+                && (line.contains("fetch") || line.contains("save") || line.contains("delete"))
+        })
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::CoreDataMainThread,
+                Severity::High,
+                i,
+                line,
+                "Opération CoreData potentiellement lourde sur le thread principal",
+                "Utilisez performBackgroundTask pour les opérations lourdes de CoreData",
+            )
+        })
+        .collect()
+}
+
+/// Requêtes CoreData sans gestion d'erreurs
+fn check_unhandled_fetch(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            CORE_DATA_REGEX.is_match(line) && line.contains("fetch") && !line.contains("try") && !line.contains("catch")
+        })
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::MissingErrorHandling,
+                Severity::Medium,
+                i,
+                line,
+                "Requête CoreData sans gestion d'erreur",
+                "Utilisez try/catch pour gérer les erreurs de fetch CoreData",
+            )
+        })
+        .collect()
+}
+
+/// Sauvegardes planifiées sur la file principale
+fn check_save_on_main_queue(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| SAVE_REGEX.is_match(line) && line.contains("DispatchQueue.main.async"))
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::CoreDataMainThread,
+                Severity::High,
+                i,
+                line,
+                "Sauvegarde CoreData planifiée sur le thread principal",
+                "Sauvegardez depuis un contexte d'arrière-plan et ne renvoyez que la mise à jour de l'UI sur le thread principal",
+            )
+        })
+        .collect()
+}
+
+/// Sauvegardes sans gestion d'erreurs
+fn check_unhandled_save(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            SAVE_REGEX.is_match(line) && !line.contains("DispatchQueue.main.async") && !line.contains("try")
+        })
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::MissingErrorHandling,
+                Severity::High,
+                i,
+                line,
+                "Sauvegarde CoreData sans gestion d'erreur",
+                "Utilisez try/catch autour de context.save()",
+            )
+        })
+        .collect()
+}
+
+/// `.perform()` superflu sur viewContext
+fn check_view_context_perform(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| VIEW_CONTEXT_PERFORM_REGEX.is_match(line))
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::CoreDataMainThread,
+                Severity::Low,
+                i,
+                line,
+                "Usage de .perform() sur viewContext",
+                "viewContext est déjà lié au thread principal, .perform() n'apporte rien depuis l'UI",
+            )
+        })
+        .collect()
+}
+
+/// Le contexte principal ne doit pas être utilisé depuis un bloc d'arrière-plan
+fn check_background_view_context(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    let lines: Vec<&str> = file.lines().collect();
+
+    BACKGROUND_VIEW_CONTEXT_REGEX
+        .captures_iter(file.content)
+        .map(|captures| {
+            let block = captures.get(0).unwrap();
+            let line = file.content[..block.end()].matches('\n').count();
+            let line_start = file.content[..block.end()].rfind('\n').map_or(0, |i| i + 1);
+            // Expression complète du contexte (`container.viewContext`, `self.container.viewContext`…)
+            let expression_start = line_start
+                + file.content[line_start..block.end()]
+                    .trim_end_matches(|c: char| c.is_alphanumeric() || c == '_' || c == '.')
+                    .len();
+            let fix = captures.get(1).filter(|parameter| parameter.as_str() != "_").map(|parameter| Replacement {
+                column: expression_start - line_start,
+                length: block.end() - expression_start,
+                text: parameter.as_str().to_string(),
+            });
+            FileIssue {
+                issue_type: IssueType::CoreDataMainThread,
+                severity: Severity::Critical,
+                line: line + 1,
+                column: Some(expression_start - line_start),
+                message: "Accès à viewContext depuis un bloc performBackgroundTask".to_string(),
+                suggestion: Some("Utilisez le contexte fourni au bloc performBackgroundTask".to_string()),
+                code_snippet: lines.get(line).map(|l| l.to_string()),
+                rule_id: None,
+                declaration: None,
+                ownership: None,
+                fix,
+            }
+        })
+        .collect()
+}
+
+/// Travail asynchrone renvoyé sur la file principale
+fn check_main_queue_async(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| MAIN_QUEUE_ASYNC_REGEX.is_match(line))
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::PotentialDataRace,
+                Severity::High,
+                i,
+                line,
+                "Risque de condition de concurrence avec l'utilisation de DispatchQueue et async",
+                "Utilisez des mécanismes de synchronisation comme les acteurs ou les isolations",
+            )
+        })
+        .collect()
+}
+
+/// Collections mutables déclarées après du code asynchrone
+fn check_shared_collections(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    let mut seen_async = false;
+    let mut issues = Vec::new();
+
+    for (i, line) in file.lines().enumerate() {
+        seen_async |= ASYNC_REGEX.is_match(line);
+
+        let is_weak = line.contains("weak ") || line.contains("unowned ");
+        if seen_async && SHARED_COLLECTION_REGEX.is_match(line) && !is_weak {
+            issues.push(line_issue(
+                IssueType::PotentialDataRace,
+                Severity::Medium,
+                i,
+                line,
+                "Variable mutable potentiellement partagée entre threads",
+                "Utilisez @MainActor, des acteurs, ou des garanties explicites de synchronisation",
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Membres nonisolated d'un acteur qui semblent accéder à l'état isolé
+fn check_nonisolated_members(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    let content = file.content;
+    if !content.contains("actor ") {
+        return Vec::new();
+    }
+    let lines: Vec<&str> = file.lines().collect();
+
+    NONISOLATED_REGEX
+        .captures_iter(content)
+        .filter(|capture| content.contains(&format!("self.{}", &capture[1])))
+        .map(|capture| {
+            let line = content[..capture.get(0).unwrap().start()].matches('\n').count();
+            line_issue(
+                IssueType::PotentialDataRace,
+                Severity::High,
+                line,
+                lines[line],
+                format!("Membre nonisolated '{}' qui semble accéder à l'état partagé", &capture[1]),
+                "Retirez nonisolated ou n'accédez pas à l'état isolé de l'acteur dans ce membre",
+            )
+        })
+        .collect()
+}
+
+/// Boucles qui pourraient utiliser filter/map
+fn check_loop_filters(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| LOOP_FILTER_REGEX.is_match(line))
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::InefficientCollection,
+                Severity::Low,
+                i,
+                line,
+                "Boucle et condition qui pourraient être remplacées par filter/compactMap/etc.",
+                "Utilisez les méthodes fonctionnelles comme filter, map, reduce pour un code plus concis et efficace",
+            )
+        })
+        .collect()
+}
+
+/// Construction de collections dans des boucles
+fn check_collections_in_loops(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| COLLECTION_IN_LOOP_REGEX.is_match(line))
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::InefficientCollection,
+                Severity::Medium,
+                i,
+                line,
+                "Construction de collection inefficace dans une boucle",
+                "Préallouez la collection ou utilisez des méthodes comme map, filter ou reduce",
+            )
+        })
+        .collect()
+}
+
+/// Grandes collections sans allocation initiale
+fn check_unsized_collections(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| UNSIZED_COLLECTION_REGEX.is_match(line))
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::ExcessiveComputation,
+                Severity::Low,
+                i,
+                line,
+                "Collection potentiellement grande sans capacité initiale",
+                "Préallouez une capacité pour les grandes collections si la taille est connue à l'avance",
+            )
+        })
+        .collect()
+}
+
+/// Délégués qui pourraient créer des cycles de rétention
+fn check_strong_delegates(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| DELEGATE_REGEX.is_match(line) && !line.contains("weak ") && !line.contains("unowned "))
+        .map(|(i, line)| {
+            let mut issue = line_issue(
+                IssueType::MemoryLeak,
+                Severity::High,
+                i,
+                line,
+                "Délégué potentiellement fort pouvant causer un cycle de rétention",
+                "Utilisez 'weak var' pour les propriétés de type délégué",
+            );
+            issue.fix = line.find("var ").map(|column| Replacement {
+                column,
+                length: 0,
+                text: "weak ".to_string(),
+            });
+            issue
+        })
+        .collect()
+}
+
+/// Ajout de self à une collection (référence forte conservée ailleurs)
+fn check_self_in_collections(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    file.lines()
+        .enumerate()
+        .filter(|(_, line)| APPEND_SELF_REGEX.is_match(line))
+        .map(|(i, line)| {
+            line_issue(
+                IssueType::MemoryLeak,
+                Severity::High,
+                i,
+                line,
+                "Ajout de self à un tableau ou une collection",
+                "Conservez une référence faible (boîte weak ou NSHashTable.weakObjects())",
+            )
+        })
+        .collect()
+}
+
+/// Optionnels déballés de force (`valeur!`, `appel()!`), hors chaînes, commentaires,
+/// `try!`, `as!`, `!=` et types implicitement déballés (`var label: UILabel!`)
+fn check_force_unwraps(file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+    let raw_lines: Vec<&str> = file.lines().collect();
+
+    swift_source::sanitize_lines(file.content)
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            FORCE_UNWRAP_REGEX.captures_iter(line).any(|cap| {
+                let unwrapped = cap.get(1).unwrap();
+                let before = line[..unwrapped.start()].trim_end();
+                !matches!(unwrapped.as_str(), "try" | "as")
+                    && !line[cap.get(0).unwrap().end()..].starts_with('=')
+                    && !before.ends_with(':')
+                    && !before.ends_with("->")
+            })
+        })
+        .map(|(i, _)| {
+            line_issue(
+                IssueType::MissingErrorHandling,
+                Severity::Low,
+                i,
+                raw_lines[i],
+                "Optionnel déballé de force : arrêt de l'application s'il vaut nil",
+                "Remplacer par 'if let', 'guard let' ou une valeur par défaut avec '??'",
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AnalysisConfig;
+    use std::path::Path;
+
+    fn check_all(content: &str) -> Vec<FileIssue> {
+        let context = RuleContext::new(AnalysisConfig::default());
+        let file = SourceFile::new(Path::new("CardStore.swift"), content);
+        builtin_rules().iter().flat_map(|rule| rule.check(&file, &context)).collect()
+    }
+
+    #[test]
+    fn test_closure_capture_ignores_computed_properties() {
+        let content = "final class CardStore {\n    var count: Int { self.cards.count }\n    func reload() {\n        service.load { cards in self.cards = cards }\n        service.load { [weak self] cards in self?.cards = cards }\n    }\n}\n";
+
+        let lines: Vec<usize> = check_all(content)
+            .iter()
+            .filter(|i| i.issue_type == IssueType::UnsafeClosure)
+            .map(|i| i.line)
+            .collect();
+
+        assert_eq!(lines, vec![4]);
+    }
+
+    #[test]
+    fn test_background_task_using_view_context() {
+        let content = "import CoreData\n\nfunc importCards() {\n    container.performBackgroundTask { context in\n        let card = Card(context: context)\n        try? container.viewContext.save()\n    }\n}\n";
+
+        let issues = check_all(content);
+
+        let issue = issues
+            .iter()
+            .find(|i| i.severity == Severity::Critical)
+            .expect("accès à viewContext depuis un bloc d'arrière-plan non détecté");
+        assert_eq!(issue.issue_type, IssueType::CoreDataMainThread);
+        assert_eq!((issue.line, issue.column), (6, Some(13)));
+        let fix = issue.fix.as_ref().expect("contexte du bloc non proposé");
+        let mut fixed = issue.code_snippet.clone().unwrap();
+        fixed.replace_range(fix.column..fix.column + fix.length, &fix.text);
+        assert_eq!(fixed, "        try? context.save()");
+        assert_eq!(compute_metrics(content).functions_count, 1);
+    }
+
+    #[test]
+    fn test_force_unwraps_outside_declarations_and_literals() {
+        let content = "final class DeckViewController {\n    @IBOutlet weak var titleLabel: UILabel!\n    func show(_ decks: [Deck]) {\n        let deck = decks.first!\n        titleLabel.text = deck.name ?? \"Sans titre!\"\n        let data = try! encoder.encode(deck)\n        let view = sender as! UIButton\n        if deck.count != 0 && !deck.isEmpty { print(deck.cards.last!.question) } // pas de crash!\n    }\n}\n";
+
+        let lines: Vec<usize> = check_all(content)
+            .iter()
+            .filter(|i| i.issue_type == IssueType::MissingErrorHandling && i.severity == Severity::Low)
+            .map(|i| i.line)
+            .collect();
+
+        assert_eq!(lines, vec![4, 8]);
+    }
+}
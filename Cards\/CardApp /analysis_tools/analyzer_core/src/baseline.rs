@@ -0,0 +1,163 @@
+//! Ligne de base : empreintes des problèmes existants, pour ne signaler que les nouveaux
+//!
+//! Les empreintes (voir [`crate::fingerprint`]) ne dépendent pas des numéros de
+//! ligne : un problème déplacé par une modification plus haut dans le fichier
+//! reste « inchangé ».
+
+use std::collections::HashMap;
+use std::fs;
+use std::mem;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::recount_issues;
+use crate::discovery::relative_path;
+use crate::fingerprint::Fingerprinter;
+use crate::models::{BaselineEntry, BaselineSummary, PerformanceReport};
+
+/// Version du format de fichier
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Nom de fichier par défaut de `baseline create`
+pub const DEFAULT_BASELINE_FILE: &str = "swift-analyzer-baseline.json";
+
+/// Contenu d'un fichier de ligne de base
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub findings: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Enregistre tous les problèmes signalés du rapport (hors suppressions)
+    pub fn from_report(report: &PerformanceReport, project_root: &Path) -> Self {
+        let mut fingerprinter = Fingerprinter::new();
+        let mut findings = Vec::new();
+        for result in &report.files_analyzed {
+            let file = relative_path(project_root, &result.file_path);
+            for issue in &result.issues {
+                findings.push(BaselineEntry {
+                    fingerprint: fingerprinter.fingerprint(&file, issue),
+                    rule_id: issue.rule_id.clone().unwrap_or_default(),
+                    file: file.clone(),
+                    declaration: issue.declaration.clone(),
+                    line: issue.line,
+                    message: issue.message.clone(),
+                });
+            }
+        }
+        Baseline {
+            version: BASELINE_VERSION,
+            findings,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Impossible de lire la ligne de base {}: {}", path.display(), e))?;
+        let baseline: Baseline = serde_json::from_str(&content)
+            .map_err(|e| format!("Ligne de base invalide {}: {}", path.display(), e))?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(format!(
+                "Version de ligne de base non prise en charge ({}, attendue {})",
+                baseline.version, BASELINE_VERSION
+            ));
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Erreur lors de la sérialisation de la ligne de base: {}", e))?;
+        fs::write(path, content).map_err(|e| format!("Impossible d'écrire la ligne de base {}: {}", path.display(), e))
+    }
+
+    /// Classe les problèmes du rapport : les nouveaux restent dans `issues`, les
+    /// inchangés passent dans `baseline_issues` et les compteurs sont recalculés
+    pub fn apply(&self, report: &mut PerformanceReport, project_root: &Path) {
+        let mut remaining: HashMap<&str, &BaselineEntry> =
+            self.findings.iter().map(|entry| (entry.fingerprint.as_str(), entry)).collect();
+        let mut fingerprinter = Fingerprinter::new();
+        let mut summary = BaselineSummary::default();
+
+        for result in &mut report.files_analyzed {
+            let file = relative_path(project_root, &result.file_path);
+            for issue in mem::take(&mut result.issues) {
+                if remaining.remove(fingerprinter.fingerprint(&file, &issue).as_str()).is_some() {
+                    summary.unchanged += 1;
+                    result.baseline_issues.push(issue);
+                } else {
+                    summary.new += 1;
+                    result.issues.push(issue);
+                }
+            }
+        }
+
+        summary.fixed = self
+            .findings
+            .iter()
+            .filter(|entry| remaining.contains_key(entry.fingerprint.as_str()))
+            .cloned()
+            .collect();
+        report.baseline = Some(summary);
+        recount_issues(report);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::focused_registry;
+
+    fn analyze(content: &str) -> PerformanceReport {
+        Analyzer::new(AnalysisConfig::default())
+            .with_registry(focused_registry())
+            .with_source("App/Editor.swift", content)
+            .run()
+    }
+
+    #[test]
+    fn test_classifies_new_unchanged_and_fixed() {
+        let before = "\
+class EditorViewController {
+    var delegate: EditorDelegate?
+}
+class ListViewController {
+    var delegate: ListDelegate?
+}
+";
+        let baseline = Baseline::from_report(&analyze(before), Path::new(""));
+        assert_eq!(baseline.findings.len(), 2);
+
+        // Lignes décalées, un problème corrigé, un autre ajouté
+        let after = "\
+import UIKit
+
+class EditorViewController {
+    var delegate: EditorDelegate?
+}
+class ListViewController {
+    weak var delegate: ListDelegate?
+}
+class GridViewController {
+    var delegate: GridDelegate?
+}
+";
+        let mut report = analyze(after);
+        baseline.apply(&mut report, Path::new(""));
+
+        let summary = report.baseline.as_ref().unwrap();
+        assert_eq!((summary.new, summary.unchanged), (1, 1));
+        assert_eq!(summary.fixed.len(), 1);
+        assert_eq!(summary.fixed[0].declaration.as_deref(), Some("ListViewController"));
+
+        let result = &report.files_analyzed[0];
+        assert_eq!(result.issues[0].declaration.as_deref(), Some("GridViewController"));
+        assert_eq!(result.baseline_issues[0].line, 4);
+        assert_eq!(report.project_stats.problematic_files, 1);
+        assert_eq!(report.issue_count_by_severity.values().sum::<usize>(), 1);
+    }
+}
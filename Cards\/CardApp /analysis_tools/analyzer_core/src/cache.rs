@@ -0,0 +1,316 @@
+//! Cache de l'analyse sur disque : résultat de chaque fichier et faits extraits pour l'index du projet
+//!
+//! Les entrées sont indexées par l'empreinte BLAKE3 du contenu des fichiers.
+//! Chaque combinaison version des règles (`RULES_VERSION`) × configuration
+//! effective (seuils, règles actives, modèle CoreData) a son propre fichier de
+//! cache, qui ne conserve que les fichiers rencontrés lors de la dernière analyse.
+//! Les règles à l'échelle du projet sont toujours réévaluées, sur un index
+//! complet reconstruit à partir des faits en cache.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::AnalysisResult;
+use crate::project_index::IndexedFile;
+use crate::rules::RULES_VERSION;
+use crate::swift_source::{FunctionDeclaration, TypeDeclaration};
+
+/// Répertoire du cache par défaut, relatif au répertoire courant
+pub const DEFAULT_CACHE_DIR: &str = ".swift-analyzer-cache";
+
+/// Version du format des fichiers de cache
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Préfixe des fichiers de cache, seuls fichiers supprimés par `clean`
+const CACHE_FILE_PREFIX: &str = "analysis-";
+
+/// Faits d'un fichier nécessaires à l'index du projet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexFacts {
+    lines: Vec<String>,
+    declarations: Vec<TypeDeclaration>,
+    functions: Vec<FunctionDeclaration>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Dernier fichier rencontré avec ce contenu
+    path: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result: Option<AnalysisResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<IndexFacts>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    entries: HashMap<String, CacheEntry>,
+}
+
+/// Cache d'analyse enregistré dans un répertoire, ou conservé en mémoire
+#[derive(Debug, Clone)]
+pub struct AnalysisCache {
+    storage: Storage,
+}
+
+#[derive(Debug, Clone)]
+enum Storage {
+    Directory(PathBuf),
+    /// Entrées de la dernière configuration enregistrée, partagées entre les copies du cache
+    Memory(Arc<Mutex<HashMap<String, HashMap<String, CacheEntry>>>>),
+}
+
+impl AnalysisCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        AnalysisCache {
+            storage: Storage::Directory(dir.into()),
+        }
+    }
+
+    /// Cache limité au processus, pour des analyses successives du même projet (`watch`)
+    pub fn in_memory() -> Self {
+        AnalysisCache {
+            storage: Storage::Memory(Arc::default()),
+        }
+    }
+
+    /// Répertoire du cache, `None` pour un cache en mémoire
+    pub fn dir(&self) -> Option<&Path> {
+        match &self.storage {
+            Storage::Directory(dir) => Some(dir),
+            Storage::Memory(_) => None,
+        }
+    }
+
+    /// Entrées enregistrées pour une configuration (`key`) ; cache absent ou illisible : session vide
+    pub(crate) fn open(&self, key: &str) -> CacheSession {
+        let entries = match &self.storage {
+            Storage::Directory(dir) => fs::read(dir.join(cache_file_name(key)))
+                .ok()
+                .and_then(|content| serde_json::from_slice::<CacheFile>(&content).ok())
+                .filter(|file| file.version == CACHE_FORMAT_VERSION)
+                .map(|file| file.entries)
+                .unwrap_or_default(),
+            Storage::Memory(configurations) => {
+                configurations.lock().unwrap().get(key).cloned().unwrap_or_default()
+            }
+        };
+        CacheSession {
+            storage: self.storage.clone(),
+            key: key.to_string(),
+            entries,
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Supprime les fichiers de cache du répertoire (ou les entrées en mémoire) ; renvoie le nombre de configurations
+    pub fn clean(&self) -> Result<usize, String> {
+        let dir = match &self.storage {
+            Storage::Directory(dir) => dir,
+            Storage::Memory(configurations) => {
+                let mut configurations = configurations.lock().unwrap();
+                let removed = configurations.len();
+                configurations.clear();
+                return Ok(removed);
+            }
+        };
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(format!("Impossible de lire le cache {}: {}", dir.display(), e)),
+        };
+        let mut removed = 0;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_cache_file = path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with(CACHE_FILE_PREFIX));
+            if is_cache_file {
+                fs::remove_file(&path).map_err(|e| format!("Impossible de supprimer {}: {}", path.display(), e))?;
+                removed += 1;
+            }
+        }
+        // Le répertoire n'est supprimé que s'il ne contient plus rien
+        let _ = fs::remove_dir(dir);
+        Ok(removed)
+    }
+}
+
+fn cache_file_name(key: &str) -> String {
+    format!("{}{}.json", CACHE_FILE_PREFIX, key)
+}
+
+/// Empreinte du contenu d'un fichier
+pub(crate) fn content_hash(content: &str) -> String {
+    blake3::hash(content.as_bytes()).to_hex().to_string()
+}
+
+/// Clé de configuration : version des règles et empreinte de la configuration effective
+pub(crate) fn configuration_key(configuration: &str) -> String {
+    let hash = blake3::hash(configuration.as_bytes()).to_hex();
+    format!("rules{}-{}", RULES_VERSION, &hash[..16])
+}
+
+/// Cache ouvert pour une analyse : lectures pendant l'analyse, puis enregistrement des nouvelles entrées
+pub(crate) struct CacheSession {
+    storage: Storage,
+    key: String,
+    entries: HashMap<String, CacheEntry>,
+    /// Empreinte actuelle de chaque fichier rencontré, pour écarter les entrées périmées
+    seen: HashMap<PathBuf, String>,
+}
+
+impl CacheSession {
+    /// Résultat en cache pour ce contenu, rattaché à `path`
+    pub fn result(&self, hash: &str, path: &Path) -> Option<AnalysisResult> {
+        let mut result = self.entries.get(hash)?.result.clone()?;
+        result.file_path = path.to_path_buf();
+        Some(result)
+    }
+
+    /// Fichier indexé à partir des faits en cache pour ce contenu
+    pub fn indexed_file(&self, hash: &str, path: PathBuf, content: String) -> Option<IndexedFile> {
+        let facts = self.entries.get(hash)?.index.clone()?;
+        Some(IndexedFile {
+            path,
+            content,
+            lines: facts.lines,
+            declarations: facts.declarations,
+            functions: facts.functions,
+        })
+    }
+
+    pub fn store_result(&mut self, hash: &str, result: &AnalysisResult) {
+        self.entry(hash, &result.file_path).result = Some(result.clone());
+    }
+
+    pub fn store_index(&mut self, hash: &str, file: &IndexedFile) {
+        self.entry(hash, &file.path).index = Some(IndexFacts {
+            lines: file.lines.clone(),
+            declarations: file.declarations.clone(),
+            functions: file.functions.clone(),
+        });
+    }
+
+    /// Note l'empreinte actuelle d'un fichier rencontré
+    pub fn see(&mut self, hash: &str, path: &Path) {
+        if let Some(entry) = self.entries.get_mut(hash) {
+            entry.path = path.to_path_buf();
+        }
+        self.seen.insert(path.to_path_buf(), hash.to_string());
+    }
+
+    fn entry(&mut self, hash: &str, path: &Path) -> &mut CacheEntry {
+        let entry = self.entries.entry(hash.to_string()).or_default();
+        entry.path = path.to_path_buf();
+        entry
+    }
+
+    /// Enregistre le cache ; seul le contenu actuel des fichiers rencontrés est conservé
+    ///
+    /// Les entrées des anciennes versions d'un fichier, et celles des fichiers supprimés, renommés
+    /// ou hors de l'analyse, sont retirées.
+    pub fn save(self) -> Result<(), String> {
+        let seen = self.seen;
+        let entries = self
+            .entries
+            .into_iter()
+            .filter(|(hash, entry)| seen.get(&entry.path) == Some(hash))
+            .collect();
+        let dir = match self.storage {
+            Storage::Directory(dir) => dir,
+            Storage::Memory(configurations) => {
+                let mut configurations = configurations.lock().unwrap();
+                configurations.clear();
+                configurations.insert(self.key, entries);
+                return Ok(());
+            }
+        };
+        let file = CacheFile {
+            version: CACHE_FORMAT_VERSION,
+            entries,
+        };
+
+        let path = dir.join(cache_file_name(&self.key));
+        let error = |e: &dyn std::fmt::Display| format!("Impossible d'enregistrer le cache {}: {}", path.display(), e);
+        fs::create_dir_all(&dir).map_err(|e| error(&e))?;
+        let content = serde_json::to_vec(&file).map_err(|e| error(&e))?;
+        // Écriture dans un fichier temporaire puis renommage : un cache n'est jamais lu à moitié écrit
+        let temporary = path.with_extension(format!("tmp-{}", std::process::id()));
+        fs::write(&temporary, content).map_err(|e| error(&e))?;
+        fs::rename(&temporary, &path).map_err(|e| error(&e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::{AnalysisConfig, PerformanceReport};
+
+    const UTIL: &str = "class Util {\n    func helper() -> Int {\n        return 1\n    }\n}\n";
+
+    fn analyze(cache: &AnalysisCache, screen: &str) -> PerformanceReport {
+        Analyzer::new(AnalysisConfig::default())
+            .with_cache(cache.clone())
+            .with_source("App/Util.swift", UTIL)
+            .with_source("App/Screen.swift", screen)
+            .run()
+    }
+
+    fn is_dead(report: &PerformanceReport, name: &str) -> bool {
+        report.dead_code.iter().any(|finding| finding.name.contains(name))
+    }
+
+    #[test]
+    fn test_unchanged_files_reused_and_project_rules_see_changes() {
+        let dir = std::env::temp_dir().join(format!("swift-analyzer-cache-{}", std::process::id()));
+        let cache = AnalysisCache::new(&dir);
+        cache.clean().unwrap();
+
+        let calling = "class Screen {\n    var delegate: ScreenDelegate?\n    func show() {\n        print(Util().helper())\n    }\n}\n";
+        let first = analyze(&cache, calling);
+        let second = analyze(&cache, calling);
+        let stats = |report: &PerformanceReport| {
+            let stats = report.cache.unwrap();
+            (stats.hits, stats.misses, stats.index_hits, stats.index_misses)
+        };
+        assert_eq!(stats(&first), (0, 2, 0, 2));
+        assert_eq!(stats(&second), (2, 0, 2, 0));
+        assert_eq!(first.issue_count_by_severity, second.issue_count_by_severity);
+        assert!(!is_dead(&second, "Util"));
+
+        // Util.swift vient du cache, mais Screen.swift ne l'utilise plus
+        let third = analyze(&cache, "class Screen {\n    weak var delegate: ScreenDelegate?\n}\n");
+        assert_eq!(stats(&third), (1, 1, 1, 1));
+        assert!(is_dead(&third, "Util"));
+
+        assert_eq!(cache.clean().unwrap(), 1);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_removed_files_pruned_from_versioned_cache_file() {
+        let dir = std::env::temp_dir().join(format!("swift-analyzer-cache-prune-{}", std::process::id()));
+        let cache = AnalysisCache::new(&dir);
+        cache.clean().unwrap();
+
+        analyze(&cache, "class Screen {}\n");
+        // Screen.swift supprimé
+        Analyzer::new(AnalysisConfig::default()).with_cache(cache.clone()).with_source("App/Util.swift", UTIL).run();
+
+        let files: Vec<PathBuf> = fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+        let content = fs::read(&files[0]).unwrap();
+        cache.clean().unwrap();
+
+        assert_eq!(files.len(), 1);
+        let name = files[0].file_name().unwrap().to_string_lossy().into_owned();
+        assert!(name.starts_with(&format!("{}rules{}-", CACHE_FILE_PREFIX, RULES_VERSION)), "{}", name);
+        let file: CacheFile = serde_json::from_slice(&content).unwrap();
+        let paths: Vec<&Path> = file.entries.values().map(|entry| entry.path.as_path()).collect();
+        assert_eq!(paths, vec![Path::new("App/Util.swift")]);
+    }
+}
@@ -0,0 +1,670 @@
+use crate::project_index::{FunctionId, IndexedFile, ProjectIndex};
+use crate::swift_source::{self, TypeKind};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+
+/// Mots-clés suivis de parenthèses qui ne sont pas des appels
+const NON_CALL_KEYWORDS: &[&str] = &[
+    "if", "guard", "while", "for", "switch", "return", "catch", "case", "func", "init", "super", "self",
+];
+
+lazy_static! {
+    static ref CALL_PATTERN: Regex = Regex::new(
+        r"\b([a-z_]\w*)\s*\("
+    ).unwrap();
+
+    /// `let x: Type`, `var x = Type(...)`, `static let shared = Type()`
+    static ref BINDING_PATTERN: Regex = Regex::new(
+        r"\b(?:let|var)\s+(\w+)\s*(?::\s*(?:any\s+|some\s+)?([A-Z]\w*)|=\s*(?:try\s+|await\s+)*([A-Z]\w*)\s*(?:\(|\.shared\b))"
+    ).unwrap();
+
+    static ref PARAMETER_TYPE_PATTERN: Regex = Regex::new(
+        r"^\s*(?:@\w+\s+)*(?:(?:inout|any|some|borrowing|consuming)\s+)*([A-Z]\w*)"
+    ).unwrap();
+}
+
+/// Manière dont la cible d'un appel a été déterminée
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CallResolution {
+    /// Appel sans receveur vers une méthode du même type ou une fonction globale
+    Implicit,
+    /// Receveur dont le type est connu (propriété, paramètre, variable locale, `self`)
+    Typed,
+    /// Receveur typé par un protocole : une arête par type conforme
+    Witness,
+    /// Receveur inconnu : seule fonction du projet portant ce nom
+    NameOnly,
+}
+
+/// Appel d'une fonction du projet
+#[derive(Debug, Clone)]
+pub struct CallEdge {
+    /// Fonction appelante, `None` pour un appel hors fonction (`body` d'une vue, propriété calculée...)
+    pub caller: Option<FunctionId>,
+    /// Nom qualifié de la portée appelante (`Type.fonction`, `Type.body`)
+    pub scope: String,
+    pub callee: FunctionId,
+    pub file: usize,
+    /// Ligne de l'appel (indexée à partir de 0)
+    pub line: usize,
+    /// Position en octets du nom appelé dans la ligne
+    pub column: usize,
+    pub resolution: CallResolution,
+}
+
+/// Graphe d'appels interprocédural du projet
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    edges: Vec<CallEdge>,
+    by_caller: HashMap<FunctionId, Vec<usize>>,
+    by_callee: HashMap<FunctionId, Vec<usize>>,
+    by_line: HashMap<(usize, usize), Vec<usize>>,
+}
+
+impl CallGraph {
+    /// Construit le graphe en résolvant chaque appel à partir des types déclarés du projet
+    pub fn build(index: &ProjectIndex) -> Self {
+        let types = TypeTable::new(index);
+        let mut graph = CallGraph::default();
+
+        for (file_index, file) in index.files.iter().enumerate() {
+            let view_properties = swift_source::find_view_properties(&file.lines, &file.declarations);
+            let locals: Vec<HashMap<String, String>> =
+                (0..file.functions.len()).map(|f| local_bindings(file, f)).collect();
+
+            for (i, line) in file.lines.iter().enumerate() {
+                let enclosing = file
+                    .functions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| i >= f.line_start && i <= f.line_end)
+                    .min_by_key(|(_, f)| f.line_end - f.line_start)
+                    .map(|(f, _)| f);
+                let owner = swift_source::innermost_type_at(&file.declarations, i).map(|d| d.name.as_str());
+                let scope = match enclosing {
+                    Some(f) => file.functions[f].qualified_name(),
+                    None => match view_properties.iter().find(|p| i >= p.line_start && i <= p.line_end) {
+                        Some(property) => format!("{}.{}", owner.unwrap_or("?"), property.name),
+                        None => owner.map(str::to_string).unwrap_or_else(|| file_label(&file.path)),
+                    },
+                };
+                let context = CallContext {
+                    owner,
+                    locals: enclosing.map(|f| &locals[f]),
+                };
+
+                for cap in CALL_PATTERN.captures_iter(line) {
+                    let name = cap.get(1).unwrap();
+                    if NON_CALL_KEYWORDS.contains(&name.as_str()) || line[..name.start()].trim_end().ends_with("func") {
+                        continue;
+                    }
+
+                    let receiver = receiver_chain(line, name.start());
+                    let (callees, resolution) = types.resolve(name.as_str(), receiver.as_deref(), &context);
+                    for callee in callees {
+                        graph.add(CallEdge {
+                            caller: enclosing.map(|function| FunctionId { file: file_index, function }),
+                            scope: scope.clone(),
+                            callee,
+                            file: file_index,
+                            line: i,
+                            column: name.start(),
+                            resolution,
+                        });
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn add(&mut self, edge: CallEdge) {
+        let position = self.edges.len();
+        if let Some(caller) = edge.caller {
+            self.by_caller.entry(caller).or_default().push(position);
+        }
+        self.by_callee.entry(edge.callee).or_default().push(position);
+        self.by_line.entry((edge.file, edge.line)).or_default().push(position);
+        self.edges.push(edge);
+    }
+
+    pub fn edges(&self) -> &[CallEdge] {
+        &self.edges
+    }
+
+    /// Appels effectués par une fonction
+    pub fn callees(&self, id: FunctionId) -> Vec<&CallEdge> {
+        self.lookup(self.by_caller.get(&id))
+    }
+
+    /// Appels reçus par une fonction
+    pub fn callers(&self, id: FunctionId) -> Vec<&CallEdge> {
+        self.lookup(self.by_callee.get(&id))
+    }
+
+    /// Appels situés sur une ligne donnée d'un fichier
+    pub fn calls_at(&self, file: usize, line: usize) -> Vec<&CallEdge> {
+        self.lookup(self.by_line.get(&(file, line)))
+    }
+
+    fn lookup(&self, positions: Option<&Vec<usize>>) -> Vec<&CallEdge> {
+        positions
+            .map(|p| p.iter().map(|&i| &self.edges[i]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Fonctions atteignables depuis `start` (incluse), dans l'ordre de parcours en largeur
+    pub fn reachable_from(&self, start: FunctionId) -> Vec<FunctionId> {
+        let mut visited = HashSet::from([start]);
+        let mut order = vec![start];
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(current) = queue.pop_front() {
+            for edge in self.callees(current) {
+                if visited.insert(edge.callee) {
+                    order.push(edge.callee);
+                    queue.push_back(edge.callee);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Restreint le graphe aux appels effectués depuis les fonctions atteignables depuis `start`
+    pub fn subgraph_from(&self, start: FunctionId) -> CallGraph {
+        let reachable: HashSet<FunctionId> = self.reachable_from(start).into_iter().collect();
+        let mut graph = CallGraph::default();
+        for edge in &self.edges {
+            if edge.caller.map(|c| reachable.contains(&c)).unwrap_or(false) {
+                graph.add(edge.clone());
+            }
+        }
+        graph
+    }
+
+    /// Export au format Graphviz DOT
+    pub fn to_dot(&self, index: &ProjectIndex) -> String {
+        let export = self.export(index);
+        let mut dot = String::from("digraph callgraph {\n    rankdir=LR;\n    node [shape=box, fontname=\"Helvetica\"];\n");
+
+        for node in &export.nodes {
+            dot.push_str(&format!(
+                "    n{} [label=\"{}\\n{}:{}\"];\n",
+                node.id,
+                escape_dot(&node.name),
+                escape_dot(&file_label(&node.file)),
+                node.line
+            ));
+        }
+        for edge in &export.edges {
+            let style = match edge.resolution {
+                CallResolution::Witness => " [style=dashed]",
+                CallResolution::NameOnly => " [style=dotted]",
+                _ => "",
+            };
+            dot.push_str(&format!("    n{} -> n{}{};\n", edge.caller, edge.callee, style));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Export JSON (nœuds et arêtes)
+    pub fn to_json(&self, index: &ProjectIndex) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.export(index))
+    }
+
+    fn export(&self, index: &ProjectIndex) -> CallGraphExport {
+        let mut export = CallGraphExport::default();
+        let mut ids: HashMap<(usize, String), usize> = HashMap::new();
+
+        let mut node_id = |export: &mut CallGraphExport, file: usize, name: String, line: usize| {
+            *ids.entry((file, name.clone())).or_insert_with(|| {
+                export.nodes.push(CallGraphNode {
+                    id: export.nodes.len(),
+                    name,
+                    file: index.files[file].path.clone(),
+                    line: line + 1,
+                });
+                export.nodes.len() - 1
+            })
+        };
+
+        for edge in &self.edges {
+            let caller = match edge.caller {
+                Some(id) => {
+                    let function = index.function(id);
+                    node_id(&mut export, id.file, function.qualified_name(), function.line_start)
+                }
+                None => node_id(&mut export, edge.file, edge.scope.clone(), edge.line),
+            };
+            let callee_function = index.function(edge.callee);
+            let callee = node_id(
+                &mut export,
+                edge.callee.file,
+                callee_function.qualified_name(),
+                callee_function.line_start,
+            );
+
+            export.edges.push(CallGraphExportEdge {
+                caller,
+                callee,
+                file: index.files[edge.file].path.clone(),
+                line: edge.line + 1,
+                resolution: edge.resolution,
+            });
+        }
+
+        export
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CallGraphExport {
+    nodes: Vec<CallGraphNode>,
+    edges: Vec<CallGraphExportEdge>,
+}
+
+#[derive(Debug, Serialize)]
+struct CallGraphNode {
+    id: usize,
+    name: String,
+    file: PathBuf,
+    line: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct CallGraphExportEdge {
+    caller: usize,
+    callee: usize,
+    file: PathBuf,
+    line: usize,
+    resolution: CallResolution,
+}
+
+/// Contexte de résolution d'un appel : type englobant et variables locales de la fonction
+struct CallContext<'a> {
+    owner: Option<&'a str>,
+    locals: Option<&'a HashMap<String, String>>,
+}
+
+/// Types déclarés du projet, leurs propriétés typées, leurs conformances et leurs méthodes
+struct TypeTable {
+    types: HashSet<String>,
+    protocols: HashSet<String>,
+    supertypes: HashMap<String, Vec<String>>,
+    properties: HashMap<String, HashMap<String, String>>,
+    methods: HashMap<(String, String), Vec<FunctionId>>,
+    globals: HashMap<String, Vec<FunctionId>>,
+    index: HashMap<String, Vec<FunctionId>>,
+}
+
+impl TypeTable {
+    fn new(project: &ProjectIndex) -> Self {
+        let mut table = TypeTable {
+            types: HashSet::new(),
+            protocols: HashSet::new(),
+            supertypes: HashMap::new(),
+            properties: HashMap::new(),
+            methods: HashMap::new(),
+            globals: HashMap::new(),
+            index: HashMap::new(),
+        };
+
+        for (file_index, file) in project.files.iter().enumerate() {
+            for declaration in &file.declarations {
+                match declaration.kind {
+                    TypeKind::Protocol => {
+                        table.protocols.insert(declaration.name.clone());
+                    }
+                    TypeKind::Extension => {}
+                    _ => {
+                        table.types.insert(declaration.name.clone());
+                    }
+                }
+                table
+                    .supertypes
+                    .entry(declaration.name.clone())
+                    .or_default()
+                    .extend(declaration.inherited.iter().cloned());
+            }
+
+            // Propriétés : liaisons situées dans un type mais hors de toute fonction
+            for (i, line) in file.lines.iter().enumerate() {
+                if file.functions.iter().any(|f| i > f.line_start && i <= f.line_end) {
+                    continue;
+                }
+                let Some(owner) = swift_source::innermost_type_at(&file.declarations, i) else {
+                    continue;
+                };
+                for (name, type_name) in bindings(line) {
+                    table
+                        .properties
+                        .entry(owner.name.clone())
+                        .or_default()
+                        .entry(name)
+                        .or_insert(type_name);
+                }
+            }
+
+            for (function_index, function) in file.functions.iter().enumerate() {
+                let id = FunctionId {
+                    file: file_index,
+                    function: function_index,
+                };
+                match &function.owner {
+                    Some(owner) => table
+                        .methods
+                        .entry((owner.clone(), function.name.clone()))
+                        .or_default()
+                        .push(id),
+                    None => table.globals.entry(function.name.clone()).or_default().push(id),
+                }
+                table.index.entry(function.name.clone()).or_default().push(id);
+            }
+        }
+
+        table
+    }
+
+    /// Résout un appel `name(...)` précédé de la chaîne de receveurs `receiver`
+    fn resolve(
+        &self,
+        name: &str,
+        receiver: Option<&[String]>,
+        context: &CallContext,
+    ) -> (Vec<FunctionId>, CallResolution) {
+        match receiver {
+            None => {
+                if let Some(owner) = context.owner {
+                    let methods = self.methods_on(owner, name, &mut HashSet::new());
+                    if !methods.is_empty() {
+                        return (methods, CallResolution::Implicit);
+                    }
+                }
+                if let Some(globals) = self.globals.get(name) {
+                    return (globals.clone(), CallResolution::Implicit);
+                }
+            }
+            Some(chain) => {
+                if let Some(type_name) = self.receiver_type(chain, context) {
+                    let methods = self.methods_on(&type_name, name, &mut HashSet::new());
+                    if !methods.is_empty() {
+                        return (methods, CallResolution::Typed);
+                    }
+                    if self.protocols.contains(&type_name) {
+                        let witnesses: Vec<FunctionId> = self
+                            .conforming_types(&type_name)
+                            .iter()
+                            .flat_map(|t| self.methods_on(t, name, &mut HashSet::new()))
+                            .collect();
+                        if !witnesses.is_empty() {
+                            return (witnesses, CallResolution::Witness);
+                        }
+                    }
+                    // Type connu mais méthode hors projet (Foundation, SwiftUI...)
+                    if self.types.contains(&type_name) || self.protocols.contains(&type_name) {
+                        return (Vec::new(), CallResolution::Typed);
+                    }
+                }
+            }
+        }
+
+        match self.index.get(name).map(Vec::as_slice) {
+            Some([only]) => (vec![*only], CallResolution::NameOnly),
+            _ => (Vec::new(), CallResolution::NameOnly),
+        }
+    }
+
+    /// Méthodes `name` d'un type, de ses extensions et de ses supertypes (implémentations par défaut comprises)
+    fn methods_on(&self, type_name: &str, name: &str, visited: &mut HashSet<String>) -> Vec<FunctionId> {
+        if !visited.insert(type_name.to_string()) {
+            return Vec::new();
+        }
+        if let Some(methods) = self.methods.get(&(type_name.to_string(), name.to_string())) {
+            return methods.clone();
+        }
+        self.supertypes
+            .get(type_name)
+            .map(|supertypes| {
+                supertypes
+                    .iter()
+                    .flat_map(|s| self.methods_on(s, name, visited))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Types concrets conformes à un protocole, directement ou via un protocole qui le raffine
+    fn conforming_types(&self, protocol: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        let mut visited = HashSet::from([protocol.to_string()]);
+        let mut queue = VecDeque::from([protocol.to_string()]);
+
+        while let Some(current) = queue.pop_front() {
+            for (type_name, supertypes) in &self.supertypes {
+                if supertypes.contains(&current) && visited.insert(type_name.clone()) {
+                    if self.protocols.contains(type_name) {
+                        queue.push_back(type_name.clone());
+                    } else {
+                        result.push(type_name.clone());
+                    }
+                }
+            }
+        }
+
+        result.sort();
+        result
+    }
+
+    /// Type du receveur `a.b.c` : premier maillon résolu localement, puis propriétés successives
+    fn receiver_type(&self, chain: &[String], context: &CallContext) -> Option<String> {
+        let (first, rest) = chain.split_first()?;
+
+        let mut current = match first.as_str() {
+            "self" | "Self" => context.owner?.to_string(),
+            name if self.types.contains(name) || self.protocols.contains(name) => name.to_string(),
+            name => context
+                .locals
+                .and_then(|locals| locals.get(name).cloned())
+                .or_else(|| context.owner.and_then(|owner| self.property_type(owner, name, &mut HashSet::new())))?,
+        };
+
+        for property in rest {
+            current = self.property_type(&current, property, &mut HashSet::new())?;
+        }
+
+        Some(current)
+    }
+
+    fn property_type(&self, type_name: &str, property: &str, visited: &mut HashSet<String>) -> Option<String> {
+        if !visited.insert(type_name.to_string()) {
+            return None;
+        }
+        if let Some(found) = self.properties.get(type_name).and_then(|p| p.get(property)) {
+            return Some(found.clone());
+        }
+        self.supertypes
+            .get(type_name)?
+            .iter()
+            .find_map(|s| self.property_type(s, property, visited))
+    }
+}
+
+/// Paramètres et variables locales typés d'une fonction
+fn local_bindings(file: &IndexedFile, function: usize) -> HashMap<String, String> {
+    let declaration = &file.functions[function];
+    let mut locals = HashMap::new();
+
+    // Signature, éventuellement répartie sur plusieurs lignes
+    let signature_end = (declaration.line_start..=declaration.line_end)
+        .find(|&i| file.lines[i].contains('{'))
+        .unwrap_or(declaration.line_end);
+    let signature = file.lines[declaration.line_start..=signature_end].join(" ");
+    if let Some(open) = signature.find('(') {
+        if let Some(close) = swift_source::matching_parenthesis(&signature, open) {
+            for parameter in split_parameters(&signature[open + 1..close]) {
+                let Some((names, type_part)) = parameter.split_once(':') else {
+                    continue;
+                };
+                let Some(internal) = names.split_whitespace().last() else {
+                    continue;
+                };
+                if let Some(cap) = PARAMETER_TYPE_PATTERN.captures(type_part) {
+                    locals.insert(internal.to_string(), cap[1].to_string());
+                }
+            }
+        }
+    }
+
+    for line in &file.lines[declaration.line_start + 1..=declaration.line_end] {
+        locals.extend(bindings(line));
+    }
+
+    locals
+}
+
+/// Découpe une liste de paramètres sur les virgules de premier niveau
+fn split_parameters(parameters: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (i, c) in parameters.char_indices() {
+        match c {
+            '(' | '[' | '<' => depth += 1,
+            ')' | ']' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&parameters[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&parameters[start..]);
+    parts
+}
+
+/// Liaisons `let`/`var` typées d'une ligne : (nom, type)
+fn bindings(line: &str) -> Vec<(String, String)> {
+    BINDING_PATTERN
+        .captures_iter(line)
+        .filter_map(|cap| {
+            let type_name = cap.get(2).or_else(|| cap.get(3))?;
+            Some((cap[1].to_string(), type_name.as_str().to_string()))
+        })
+        .collect()
+}
+
+/// Chaîne des receveurs d'un appel (`self.store.load(` → `["self", "store"]`).
+///
+/// Retourne `None` pour un appel sans receveur et une chaîne vide lorsque le receveur
+/// est une expression (résultat d'appel, indice, membre implicite `.foo(`).
+fn receiver_chain(line: &str, call_start: usize) -> Option<Vec<String>> {
+    let bytes = line.as_bytes();
+    let mut position = call_start;
+    let mut chain = Vec::new();
+
+    loop {
+        let mut cursor = position;
+        while cursor > 0 && bytes[cursor - 1].is_ascii_whitespace() {
+            cursor -= 1;
+        }
+        if cursor == 0 || bytes[cursor - 1] != b'.' {
+            break;
+        }
+        cursor -= 1;
+        while cursor > 0 && matches!(bytes[cursor - 1], b'?' | b'!') {
+            cursor -= 1;
+        }
+        let end = cursor;
+        while cursor > 0 && (bytes[cursor - 1].is_ascii_alphanumeric() || bytes[cursor - 1] == b'_') {
+            cursor -= 1;
+        }
+        if cursor == end {
+            return Some(Vec::new());
+        }
+        chain.insert(0, line[cursor..end].to_string());
+        position = cursor;
+    }
+
+    if chain.is_empty() {
+        None
+    } else {
+        Some(chain)
+    }
+}
+
+fn file_label(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(files: &[(&str, &str)]) -> ProjectIndex {
+        ProjectIndex::from_files(
+            files
+                .iter()
+                .map(|(path, content)| IndexedFile::new(PathBuf::from(path), content.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_resolves_typed_properties_and_protocol_witnesses() {
+        let project = index(&[
+            ("Service.swift", "protocol DeckServiceProtocol {\n    func loadDecks()\n}\nfinal class DeckService: DeckServiceProtocol {\n    func loadDecks() {\n    }\n}\nfinal class MockDeckService: DeckServiceProtocol {\n    func loadDecks() {\n    }\n}"),
+            ("Store.swift", "final class Cache {\n    func loadDecks() {\n    }\n}\nfinal class DeckStore {\n    private let service: DeckServiceProtocol\n    let cache = Cache()\n    func refresh() {\n        service.loadDecks()\n        self.cache.loadDecks()\n    }\n}"),
+        ]);
+        let graph = CallGraph::build(&project);
+
+        let refresh = project.functions_named("refresh")[0];
+        let callees: Vec<(String, CallResolution)> = graph
+            .callees(refresh)
+            .iter()
+            .map(|e| (project.function(e.callee).qualified_name(), e.resolution))
+            .collect();
+
+        assert_eq!(
+            callees,
+            vec![
+                ("DeckService.loadDecks".to_string(), CallResolution::Witness),
+                ("MockDeckService.loadDecks".to_string(), CallResolution::Witness),
+                ("Cache.loadDecks".to_string(), CallResolution::Typed),
+            ]
+        );
+        assert_eq!(graph.reachable_from(refresh).len(), 4);
+    }
+
+    #[test]
+    fn test_parameters_and_locals_are_typed() {
+        let project = index(&[(
+            "Sync.swift",
+            "final class Importer {\n    func run() {\n    }\n}\nfinal class Exporter {\n    func run() {\n    }\n}\nfunc sync(using importer: Importer) {\n    importer.run()\n    let exporter = Exporter()\n    exporter.run()\n}",
+        )]);
+        let graph = CallGraph::build(&project);
+
+        let sync = project.functions_named("sync")[0];
+        let callees: Vec<String> = graph
+            .callees(sync)
+            .iter()
+            .map(|e| project.function(e.callee).qualified_name())
+            .collect();
+
+        assert_eq!(callees, vec!["Importer.run", "Exporter.run"]);
+        assert_eq!(graph.callers(project.functions_named("run")[0]).len(), 1);
+    }
+}
@@ -0,0 +1,190 @@
+//! Points chauds selon l'historique git : fréquence de modification × complexité
+//!
+//! Un fichier complexe rarement modifié coûte peu ; un fichier complexe modifié
+//! à chaque commit concentre le risque. L'historique est lu localement depuis
+//! `HEAD` (commits de fusion exclus, renommages non suivis).
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use git2::{Patch, Repository, Sort};
+
+use crate::models::{AnalysisResult, ChurnHotspot, PerformanceReport};
+
+/// Période récente par défaut, en jours avant le dernier commit
+pub const DEFAULT_RECENT_DAYS: u64 = 90;
+
+/// Options de lecture de l'historique
+#[derive(Debug, Clone, Copy)]
+pub struct ChurnOptions {
+    /// Les lignes modifiées sont comptées sur cette période, mesurée depuis la date du dernier commit
+    pub recent_days: u64,
+    /// Nombre maximal de commits parcourus, des plus récents aux plus anciens (`None` : tout l'historique)
+    pub max_commits: Option<usize>,
+}
+
+impl Default for ChurnOptions {
+    fn default() -> Self {
+        ChurnOptions {
+            recent_days: DEFAULT_RECENT_DAYS,
+            max_commits: None,
+        }
+    }
+}
+
+/// Historique d'un fichier
+#[derive(Debug, Clone, Default)]
+pub struct FileChurn {
+    pub commits: usize,
+    /// Adresses e-mail des auteurs
+    pub authors: HashSet<String>,
+    /// Lignes ajoutées et supprimées pendant la période récente
+    pub recent_churn: usize,
+}
+
+/// Historique des fichiers du dépôt, par chemin absolu dans la copie de travail
+#[derive(Debug, Clone, Default)]
+pub struct ChurnHistory {
+    pub files: HashMap<PathBuf, FileChurn>,
+    /// Commits parcourus
+    pub commits: usize,
+}
+
+impl ChurnHistory {
+    /// Parcourt l'historique du dépôt contenant `repo`
+    pub fn load(repo: &Path, options: ChurnOptions) -> Result<Self, String> {
+        let error = |e: git2::Error| format!("Impossible de lire l'historique git de {}: {}", repo.display(), e.message());
+        let repository = Repository::discover(repo).map_err(error)?;
+        let workdir = repository
+            .workdir()
+            .ok_or_else(|| format!("Le dépôt git de {} n'a pas de copie de travail", repo.display()))?
+            .to_path_buf();
+
+        let mut walk = repository.revwalk().map_err(error)?;
+        walk.set_sorting(Sort::TIME).map_err(error)?;
+        walk.push_head().map_err(error)?;
+
+        let mut files: HashMap<PathBuf, FileChurn> = HashMap::new();
+        let mut commits = 0;
+        let mut recent_since = None;
+        for oid in walk.take(options.max_commits.unwrap_or(usize::MAX)) {
+            let commit = repository.find_commit(oid.map_err(error)?).map_err(error)?;
+            if commit.parent_count() > 1 {
+                continue;
+            }
+            commits += 1;
+            let time = commit.time().seconds();
+            let recent_since = *recent_since.get_or_insert(time - (options.recent_days * 86_400) as i64);
+            let author = commit.author().email().unwrap_or_default().to_lowercase();
+
+            let parent = commit.parent(0).ok().map(|parent| parent.tree()).transpose().map_err(error)?;
+            let tree = commit.tree().map_err(error)?;
+            let diff = repository.diff_tree_to_tree(parent.as_ref(), Some(&tree), None).map_err(error)?;
+            for (index, delta) in diff.deltas().enumerate() {
+                let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+                    continue;
+                };
+                let churn = files.entry(path.to_path_buf()).or_default();
+                churn.commits += 1;
+                churn.authors.insert(author.clone());
+                if time >= recent_since {
+                    if let Some(patch) = Patch::from_diff(&diff, index).map_err(error)? {
+                        let (_, added, deleted) = patch.line_stats().map_err(error)?;
+                        churn.recent_churn += added + deleted;
+                    }
+                }
+            }
+        }
+
+        let files = files
+            .into_iter()
+            .map(|(path, churn)| {
+                let path = workdir.join(path);
+                (fs::canonicalize(&path).unwrap_or(path), churn)
+            })
+            .collect();
+        Ok(ChurnHistory { files, commits })
+    }
+
+    /// Fichiers analysés modifiés dans l'historique, classés par `commits × complexité` décroissant
+    pub fn hotspots(&self, report: &PerformanceReport) -> Vec<ChurnHotspot> {
+        let mut hotspots: Vec<ChurnHotspot> = report
+            .files_analyzed
+            .iter()
+            .filter_map(|result| {
+                let path = fs::canonicalize(&result.file_path).unwrap_or_else(|_| result.file_path.clone());
+                let churn = self.files.get(&path)?;
+                let complexity = total_complexity(result);
+                Some(ChurnHotspot {
+                    file_path: result.file_path.clone(),
+                    commits: churn.commits,
+                    authors: churn.authors.len(),
+                    recent_churn: churn.recent_churn,
+                    complexity,
+                    score: churn.commits as f64 * complexity,
+                })
+            })
+            .collect();
+        hotspots.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| b.commits.cmp(&a.commits)));
+        hotspots
+    }
+}
+
+/// Somme des complexités cyclomatiques des fonctions (moyenne × nombre de fonctions)
+fn total_complexity(result: &AnalysisResult) -> f64 {
+    result.metrics.avg_cyclomatic_complexity * result.metrics.functions_count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use git2::Signature;
+
+    #[test]
+    fn test_hotspots_rank_churn_times_complexity() {
+        let root = std::env::temp_dir().join(format!("swift-analyzer-churn-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let repository = Repository::init(&root).unwrap();
+
+        let branchy = "func pick(_ a: Int) -> Int {\n    if a > 1 { return 1 }\n    if a > 2 { return 2 }\n    return 0\n}\n";
+        let simple = "func one() -> Int {\n    return 1\n}\n";
+        let history = [
+            ("ana@example.com", "Picker.swift", branchy),
+            ("ana@example.com", "One.swift", simple),
+            ("bob@example.com", "One.swift", "func one() -> Int {\n    return 2\n}\n"),
+            ("bob@example.com", "Picker.swift", &branchy.replace("return 0", "return -1")),
+            ("cy@example.com", "One.swift", simple),
+        ];
+        let mut parent: Option<git2::Oid> = None;
+        for (email, file, content) in history {
+            fs::write(root.join(file), content).unwrap();
+            let mut index = repository.index().unwrap();
+            index.add_path(Path::new(file)).unwrap();
+            let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+            let signature = Signature::now("Dev", email).unwrap();
+            let parents: Vec<git2::Commit> = parent.iter().map(|oid| repository.find_commit(*oid).unwrap()).collect();
+            let parents: Vec<&git2::Commit> = parents.iter().collect();
+            parent = Some(repository.commit(Some("HEAD"), &signature, &signature, file, &tree, &parents).unwrap());
+        }
+
+        let churn = ChurnHistory::load(&root, ChurnOptions::default()).unwrap();
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_files([root.join("One.swift"), root.join("Picker.swift")])
+            .run();
+        let hotspots = churn.hotspots(&report);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(churn.commits, 5);
+        let summary: Vec<(String, usize, usize)> = hotspots
+            .iter()
+            .map(|h| (h.file_path.file_name().unwrap().to_string_lossy().into_owned(), h.commits, h.authors))
+            .collect();
+        assert_eq!(summary, [("Picker.swift".to_string(), 2, 2), ("One.swift".to_string(), 3, 3)]);
+        assert!(hotspots[0].score > hotspots[1].score);
+        assert_eq!(hotspots[1].recent_churn, 3 + 2 + 2);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::models::{AnalysisConfig, CloneGroup, CloneLocation, CloneType, Severity};
+use crate::project_index::{IndexedFile, ProjectIndex};
+use crate::rules::{AnalysisLevel, RuleCategory, RuleExplanation, RuleMetadata};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+/// Mots-clés conservés tels quels lors de la normalisation
+const KEYWORDS: &[&str] = &[
+    "func", "var", "let", "if", "else", "for", "in", "while", "repeat", "return", "guard", "switch", "case",
+    "default", "break", "continue", "fallthrough", "defer", "do", "try", "catch", "throw", "throws",
+    "rethrows", "async", "await", "class", "struct", "enum", "protocol", "extension", "actor", "import",
+    "init", "deinit", "self", "Self", "super", "true", "false", "nil", "static", "private", "fileprivate",
+    "internal", "public", "open", "final", "override", "mutating", "lazy", "weak", "unowned", "inout",
+    "where", "as", "is", "some", "any", "typealias", "associatedtype", "subscript", "get", "set",
+    "willSet", "didSet",
+];
+
+/// Base du hachage glissant
+const HASH_BASE: u64 = 1_000_003;
+
+lazy_static! {
+    static ref TOKEN_PATTERN: Regex = Regex::new(
+        r#"[A-Za-z_]\w*|\d[\w.]*|"[^"]*"|\S"#
+    ).unwrap();
+}
+
+/// Jeton d'un fichier : forme normalisée, texte d'origine et ligne
+struct Token {
+    normalized: u32,
+    raw: String,
+    line: usize,
+}
+
+/// Détecteur de code dupliqué (clones de type 1 et 2) sur le flux de jetons normalisé
+pub struct CloneDetector {
+    config: AnalysisConfig,
+}
+
+impl CloneDetector {
+    pub const RULE: RuleMetadata = RuleMetadata::new(
+        "duplication.clones",
+        RuleCategory::Duplication,
+        Severity::Low,
+        "Fragments de code dupliqués (clones de type 1 et 2)",
+    )
+    .with_level(AnalysisLevel::Project)
+    .with_explanation(RuleExplanation {
+        rationale: "Des fragments identiques au renommage près (clones de type 1 et 2) doivent être corrigés à chaque endroit ; l'un d'eux finit par diverger. Au-delà de --clone-min-tokens jetons, factoriser dans une fonction ou un composant partagé.",
+        bad_example: "// DeckView.swift et StudyView.swift\nlet formatter = DateFormatter()\nformatter.dateStyle = .medium\nformatter.timeStyle = .none\nlabel = formatter.string(from: date)",
+        good_example: "// Core/Utilities/DateFormatting.swift\nextension Date {\n    var mediumDate: String { formatted(date: .abbreviated, time: .omitted) }\n}",
+        conventions: &["1.1 Organisation des Fichiers"],
+    });
+
+    pub fn new(config: AnalysisConfig) -> Self {
+        CloneDetector { config }
+    }
+
+    /// Recherche les groupes de fragments d'au moins `clone_min_tokens` jetons, du plus long au plus court
+    pub fn analyze(&self, index: &ProjectIndex) -> Vec<CloneGroup> {
+        let window = self.config.clone_min_tokens.max(2);
+
+        let mut interner: HashMap<String, u32> = HashMap::new();
+        let tokens: Vec<Vec<Token>> = index.files.iter().map(|f| tokenize(f, &mut interner)).collect();
+
+        // Fenêtres de `window` jetons regroupées par hachage, puis par contenu exact
+        let mut by_hash: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+        for (file, file_tokens) in tokens.iter().enumerate() {
+            for (position, hash) in rolling_hashes(file_tokens, window).into_iter().enumerate() {
+                by_hash.entry(hash).or_default().push((file, position));
+            }
+        }
+
+        let normalized = |(file, position): (usize, usize), length: usize| -> Vec<u32> {
+            tokens[file][position..position + length].iter().map(|t| t.normalized).collect()
+        };
+
+        let mut buckets: Vec<Vec<(usize, usize)>> = Vec::new();
+        for members in by_hash.into_values().filter(|m| m.len() > 1) {
+            let mut groups: HashMap<Vec<u32>, Vec<(usize, usize)>> = HashMap::new();
+            for member in members {
+                groups.entry(normalized(member, window)).or_default().push(member);
+            }
+            for mut group in groups.into_values().filter(|g| g.len() > 1) {
+                group.sort_unstable();
+                buckets.push(group);
+            }
+        }
+
+        let mut bucket_at: HashMap<(usize, usize), usize> = HashMap::new();
+        for (bucket, members) in buckets.iter().enumerate() {
+            for &member in members {
+                bucket_at.insert(member, bucket);
+            }
+        }
+
+        // Deux fenêtres consécutives appartiennent au même clone si tous leurs membres avancent d'un jeton
+        let continues = |from: usize, delta: isize| -> Option<usize> {
+            let (file, position) = buckets[from][0];
+            let next = bucket_at.get(&(file, position.checked_add_signed(delta)?))?;
+            let aligned = buckets[*next].len() == buckets[from].len()
+                && buckets[from]
+                    .iter()
+                    .zip(&buckets[*next])
+                    .all(|(&(f, p), &(nf, np))| f == nf && p.checked_add_signed(delta) == Some(np));
+            aligned.then_some(*next)
+        };
+
+        let mut groups = Vec::new();
+        for (start, first) in buckets.iter().enumerate() {
+            if continues(start, -1).is_some() {
+                continue;
+            }
+            let mut length = window;
+            let mut current = start;
+            while let Some(next) = continues(current, 1) {
+                current = next;
+                length += 1;
+            }
+
+            // Occurrences qui se chevauchent dans un même fichier : on ne garde que la première
+            let mut members: Vec<(usize, usize)> = Vec::new();
+            for &(file, position) in first {
+                let overlaps = members.iter().any(|&(f, p)| f == file && position < p + length);
+                if !overlaps {
+                    members.push((file, position));
+                }
+            }
+            if members.len() < 2 {
+                continue;
+            }
+
+            let raw: HashSet<Vec<&str>> = members
+                .iter()
+                .map(|&(file, position)| {
+                    tokens[file][position..position + length].iter().map(|t| t.raw.as_str()).collect()
+                })
+                .collect();
+
+            groups.push(CloneGroup {
+                clone_type: if raw.len() == 1 { CloneType::Type1 } else { CloneType::Type2 },
+                token_count: length,
+                locations: members
+                    .iter()
+                    .map(|&(file, position)| {
+                        let indexed = &index.files[file];
+                        let line_start = tokens[file][position].line;
+                        let line_end = tokens[file][position + length - 1].line;
+                        CloneLocation {
+                            file_path: indexed.path.clone(),
+                            line_start: line_start + 1,
+                            line_end: line_end + 1,
+                            code: indexed
+                                .content
+                                .lines()
+                                .skip(line_start)
+                                .take(line_end - line_start + 1)
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        }
+                    })
+                    .collect(),
+            });
+        }
+
+        groups.sort_by(|a, b| {
+            b.token_count
+                .cmp(&a.token_count)
+                .then_with(|| a.locations[0].file_path.cmp(&b.locations[0].file_path))
+                .then_with(|| a.locations[0].line_start.cmp(&b.locations[0].line_start))
+        });
+        groups
+    }
+}
+
+/// Pourcentage des lignes du projet couvertes par au moins une occurrence de clone
+pub fn duplication_percentage(groups: &[CloneGroup], total_lines: usize) -> f64 {
+    if total_lines == 0 {
+        return 0.0;
+    }
+
+    let mut duplicated: HashSet<(&std::path::Path, usize)> = HashSet::new();
+    for location in groups.iter().flat_map(|g| &g.locations) {
+        for line in location.line_start..=location.line_end {
+            duplicated.insert((location.file_path.as_path(), line));
+        }
+    }
+
+    (duplicated.len() as f64 / total_lines as f64 * 100.0).min(100.0)
+}
+
+/// Découpe un fichier en jetons ; identifiants et littéraux sont abstraits dans la forme normalisée
+fn tokenize(file: &IndexedFile, interner: &mut HashMap<String, u32>) -> Vec<Token> {
+    let raw_lines: Vec<&str> = file.content.lines().collect();
+    let mut tokens = Vec::new();
+
+    for (i, line) in file.lines.iter().enumerate() {
+        for token in TOKEN_PATTERN.find_iter(line) {
+            let text = token.as_str();
+            let normalized = if text.starts_with('"') {
+                "$str"
+            } else if text.starts_with(|c: char| c.is_ascii_digit()) {
+                "$num"
+            } else if text.starts_with(|c: char| c.is_alphabetic() || c == '_') && !KEYWORDS.contains(&text) {
+                "$id"
+            } else {
+                text
+            };
+
+            let next_id = interner.len() as u32;
+            let id = *interner.entry(normalized.to_string()).or_insert(next_id);
+            // Les lignes nettoyées conservent les positions : le texte d'origine est à la même place
+            let raw = raw_lines
+                .get(i)
+                .and_then(|l| l.get(token.start()..token.end()))
+                .unwrap_or(text);
+
+            tokens.push(Token {
+                normalized: id,
+                raw: raw.to_string(),
+                line: i,
+            });
+        }
+    }
+
+    tokens
+}
+
+/// Hachages polynomiaux de toutes les fenêtres de `window` jetons
+fn rolling_hashes(tokens: &[Token], window: usize) -> Vec<u64> {
+    if tokens.len() < window {
+        return Vec::new();
+    }
+
+    let high = (1..window).fold(1u64, |acc, _| acc.wrapping_mul(HASH_BASE));
+    let mut hash = tokens[..window]
+        .iter()
+        .fold(0u64, |acc, t| acc.wrapping_mul(HASH_BASE).wrapping_add(t.normalized as u64 + 1));
+    let mut hashes = vec![hash];
+
+    for i in window..tokens.len() {
+        hash = hash
+            .wrapping_sub((tokens[i - window].normalized as u64 + 1).wrapping_mul(high))
+            .wrapping_mul(HASH_BASE)
+            .wrapping_add(tokens[i].normalized as u64 + 1);
+        hashes.push(hash);
+    }
+
+    hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn detect(files: &[(&str, &str)], min_tokens: usize) -> Vec<CloneGroup> {
+        let index = ProjectIndex::from_files(
+            files
+                .iter()
+                .map(|(path, content)| IndexedFile::new(PathBuf::from(path), content.to_string()))
+                .collect(),
+        );
+        let config = AnalysisConfig {
+            clone_min_tokens: min_tokens,
+            ..AnalysisConfig::default()
+        };
+        CloneDetector::new(config).analyze(&index)
+    }
+
+    #[test]
+    fn test_detect_renamed_clone_across_files() {
+        let legacy = "final class CardService {\n    func load(limit: Int) -> [Card] {\n        let request = Card.fetchRequest()\n        request.fetchLimit = limit\n        return (try? context.fetch(request)) ?? []\n    }\n}";
+        let unified = "final class UnifiedCardService {\n    func loadCards(max: Int) -> [Card] {\n        let fetch = Card.fetchRequest()\n        fetch.fetchLimit = max\n        return (try? context.fetch(fetch)) ?? []\n    }\n}";
+
+        let groups = detect(&[("CardService.swift", legacy), ("UnifiedCardService.swift", unified)], 20);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].clone_type, CloneType::Type2);
+        assert_eq!(groups[0].locations.len(), 2);
+        assert_eq!(groups[0].locations[0].line_start, 1);
+        assert_eq!(groups[0].locations[1].line_end, 7);
+        assert_eq!(duplication_percentage(&groups, 14), 100.0);
+    }
+
+    #[test]
+    fn test_exact_copy_is_type1_and_short_fragments_ignored() {
+        let body = "func reset() {\n    cards.removeAll()\n    isLoading = false\n    error = nil\n}\n";
+        let content = format!("final class A {{\n    var count = 1\n{}    var total = 2\n}}\nstruct B {{\n{}}}\n", body, body);
+
+        let groups = detect(&[("Copy.swift", &content)], 15);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].clone_type, CloneType::Type1);
+        assert!(groups[0].locations.iter().all(|l| l.line_end - l.line_start == 4));
+
+        assert!(detect(&[("Copy.swift", &content)], 40).is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+lazy_static! {
+    static ref ENTITY_PATTERN: Regex = Regex::new(
+        r#"(?s)<entity\s+([^>]*?)/?>(.*?)</entity>"#
+    ).unwrap();
+
+    static ref XML_ATTRIBUTE_PATTERN: Regex = Regex::new(
+        r#"(\w+)="([^"]*)""#
+    ).unwrap();
+
+    static ref ATTRIBUTE_PATTERN: Regex = Regex::new(
+        r#"<attribute\s+([^>]*?)/?>"#
+    ).unwrap();
+
+    static ref RELATIONSHIP_PATTERN: Regex = Regex::new(
+        r#"<relationship\s+([^>]*?)/?>"#
+    ).unwrap();
+
+    static ref FETCH_INDEX_ELEMENT_PATTERN: Regex = Regex::new(
+        r#"<fetchIndexElement\s+([^>]*?)/?>"#
+    ).unwrap();
+}
+
+/// Entité d'un modèle Core Data (`.xcdatamodel`)
+#[derive(Debug, Clone, Default)]
+pub struct EntityModel {
+    pub name: String,
+    pub class_name: Option<String>,
+    pub attributes: HashSet<String>,
+    pub relationships: HashSet<String>,
+    /// Propriétés indexées (`indexed="YES"` ou élément de `fetchIndex`)
+    pub indexed: HashSet<String>,
+}
+
+impl EntityModel {
+    pub fn is_indexed(&self, property: &str) -> bool {
+        self.indexed.contains(property)
+    }
+
+    pub fn has_property(&self, property: &str) -> bool {
+        self.attributes.contains(property) || self.relationships.contains(property)
+    }
+}
+
+/// Modèle Core Data du projet, fusionné à partir de tous les `.xcdatamodel` trouvés
+#[derive(Debug, Clone, Default)]
+pub struct CoreDataModel {
+    entities: HashMap<String, EntityModel>,
+    read_errors: Vec<String>,
+}
+
+impl CoreDataModel {
+    /// Charge tous les fichiers `contents` des `.xcdatamodel` sous `root` ; les fichiers illisibles sont ignorés
+    pub fn load(root: &Path) -> Self {
+        let mut model = CoreDataModel::default();
+
+        for entry in WalkDir::new(root).into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let in_model = path
+                .parent()
+                .and_then(|p| p.extension())
+                .map(|ext| ext == "xcdatamodel")
+                .unwrap_or(false);
+
+            if in_model && path.file_name().map(|n| n == "contents").unwrap_or(false) {
+                match fs::read_to_string(path) {
+                    Ok(xml) => model.add_contents(&xml),
+                    Err(e) => model
+                        .read_errors
+                        .push(format!("Erreur lors de la lecture du modèle {}: {}", path.display(), e)),
+                }
+            }
+        }
+
+        model
+    }
+
+    /// Fichiers du modèle qui n'ont pas pu être lus par `load`
+    pub fn read_errors(&self) -> &[String] {
+        &self.read_errors
+    }
+
+    /// Ajoute les entités d'un fichier `contents` ; les entités homonymes sont fusionnées
+    pub fn add_contents(&mut self, xml: &str) {
+        for cap in ENTITY_PATTERN.captures_iter(xml) {
+            let attributes = xml_attributes(&cap[1]);
+            let Some(name) = attributes.get("name") else {
+                continue;
+            };
+
+            let entity = self.entities.entry(name.clone()).or_insert_with(|| EntityModel {
+                name: name.clone(),
+                ..EntityModel::default()
+            });
+            if let Some(class_name) = attributes.get("representedClassName") {
+                entity.class_name = Some(class_name.trim_start_matches('.').to_string());
+            }
+
+            let body = &cap[2];
+            for attribute in ATTRIBUTE_PATTERN.captures_iter(body) {
+                let attribute = xml_attributes(&attribute[1]);
+                if let Some(property) = attribute.get("name") {
+                    entity.attributes.insert(property.clone());
+                    if attribute.get("indexed").map(|v| v == "YES").unwrap_or(false) {
+                        entity.indexed.insert(property.clone());
+                    }
+                }
+            }
+            for relationship in RELATIONSHIP_PATTERN.captures_iter(body) {
+                if let Some(property) = xml_attributes(&relationship[1]).get("name") {
+                    entity.relationships.insert(property.clone());
+                }
+            }
+            for element in FETCH_INDEX_ELEMENT_PATTERN.captures_iter(body) {
+                if let Some(property) = xml_attributes(&element[1]).get("property") {
+                    entity.indexed.insert(property.clone());
+                }
+            }
+        }
+    }
+
+    /// Recherche une entité par nom d'entité ou par nom de classe Swift
+    pub fn entity(&self, name: &str) -> Option<&EntityModel> {
+        self.entities.get(name).or_else(|| {
+            self.entities
+                .values()
+                .find(|e| e.class_name.as_deref() == Some(name))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Description stable du modèle (entités et propriétés triées), pour la clé du cache d'analyse
+    pub fn fingerprint(&self) -> String {
+        let sorted = |set: &HashSet<String>| {
+            let mut values: Vec<&str> = set.iter().map(String::as_str).collect();
+            values.sort_unstable();
+            values.join(",")
+        };
+        let mut entities: Vec<String> = self
+            .entities
+            .values()
+            .map(|entity| {
+                format!(
+                    "{}:{}:{}:{}:{}",
+                    entity.name,
+                    entity.class_name.as_deref().unwrap_or_default(),
+                    sorted(&entity.attributes),
+                    sorted(&entity.relationships),
+                    sorted(&entity.indexed)
+                )
+            })
+            .collect();
+        entities.sort_unstable();
+        entities.join(";")
+    }
+}
+
+fn xml_attributes(text: &str) -> HashMap<String, String> {
+    XML_ATTRIBUTE_PATTERN
+        .captures_iter(text)
+        .map(|cap| (cap[1].to_string(), cap[2].to_string()))
+        .collect()
+}
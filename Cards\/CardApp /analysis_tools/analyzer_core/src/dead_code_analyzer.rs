@@ -0,0 +1,531 @@
+use crate::models::{Confidence, DeadCodeFinding, DeadCodeKind, FileIssue, IssueType, Severity};
+use crate::project_index::{IndexedFile, ProjectIndex};
+use crate::rules::{AnalysisLevel, RuleCategory, RuleExplanation, RuleMetadata};
+use crate::swift_source::{self, TypeDeclaration, TypeKind};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Noms appelés implicitement par le système ou le langage
+const IMPLICIT_NAMES: &[&str] = &[
+    "init", "deinit", "body", "main", "previews", "id", "hash", "encode", "description", "debugDescription",
+    "makeBody", "makeUIView", "updateUIView", "makeUIViewController", "updateUIViewController",
+    "makeCoordinator", "makeNSView", "updateNSView", "setUp", "tearDown", "setUpWithError",
+    "tearDownWithError", "awakeFromNib", "prepareForReuse", "willSet", "didSet",
+];
+
+/// Attributs signalant un usage par le runtime Objective-C, Interface Builder ou CoreData
+const RUNTIME_ATTRIBUTES: &[&str] = &["@objc", "@IBAction", "@IBOutlet", "@IBInspectable", "@NSManaged", "@main", "@UIApplicationMain"];
+
+/// Protocoles dont les types conformes sont utilisés par le système
+const SYSTEM_ENTRY_PROTOCOLS: &[&str] = &["App", "PreviewProvider", "XCTestCase", "UIApplicationDelegate", "UIWindowSceneDelegate"];
+
+/// Protocoles dont la conformance n'ajoute pas de méthodes appelées par le système
+const MARKER_PROTOCOLS: &[&str] = &[
+    "Identifiable", "Hashable", "Equatable", "Comparable", "Sendable", "ObservableObject", "View",
+    "CaseIterable", "Error", "LocalizedError", "CustomStringConvertible",
+];
+
+/// Protocoles de sérialisation : les propriétés sont lues par la synthèse du compilateur
+const CODABLE_PROTOCOLS: &[&str] = &["Codable", "Decodable", "Encodable", "NSCoding", "NSSecureCoding"];
+
+lazy_static! {
+    static ref IDENTIFIER_PATTERN: Regex = Regex::new(r"\b[A-Za-z_]\w*\b").unwrap();
+
+    static ref PROTOCOL_MEMBER_PATTERN: Regex = Regex::new(
+        r"^\s*(?:@\w+\s+)*(?:(?:static|mutating|nonisolated)\s+)*(?:func\s+(\w+)|var\s+(\w+))"
+    ).unwrap();
+}
+
+/// Déclaration candidate à l'analyse de code mort
+struct Symbol {
+    kind: DeadCodeKind,
+    name: String,
+    qualified_name: String,
+    file: usize,
+    line: usize,
+    confidence: Confidence,
+    /// Déclaration de premier niveau (prise en compte pour les fichiers entièrement inutilisés)
+    top_level: bool,
+}
+
+/// Détecteur de déclarations sans référence à l'échelle du projet
+#[derive(Default)]
+pub struct DeadCodeAnalyzer;
+
+impl DeadCodeAnalyzer {
+    pub const RULE: RuleMetadata = RuleMetadata::new(
+        "deadcode.unused-declaration",
+        RuleCategory::DeadCode,
+        Severity::Low,
+        "Déclaration ou fichier jamais référencé dans le projet",
+    )
+    .with_level(AnalysisLevel::Project)
+    .with_explanation(RuleExplanation {
+        rationale: "Une déclaration jamais référencée ailleurs dans le projet alourdit la compilation et la maintenance. Les usages dynamiques (@objc, sélecteurs, KVC, modèle CoreData) ne sont pas visibles : vérifier le niveau de confiance avant de supprimer.",
+        bad_example: "extension DeckViewModel {\n    func legacyShuffle() { /* plus appelée depuis la refonte */ }\n}",
+        good_example: "// Déclaration supprimée, ou marquée @objc si elle est appelée dynamiquement",
+        conventions: &["1.1 Organisation des Fichiers"],
+    });
+
+    pub fn new() -> Self {
+        DeadCodeAnalyzer
+    }
+
+    /// Recherche les déclarations jamais référencées ailleurs que sur leur propre ligne de déclaration
+    pub fn analyze(&self, index: &ProjectIndex) -> Vec<DeadCodeFinding> {
+        let types = TypeInfo::new(index);
+        let symbols = collect_symbols(index, &types);
+
+        // Occurrences de chaque identifiant, moins celles des déclarations elles-mêmes
+        let mut usages: HashMap<&str, usize> = HashMap::new();
+        for file in &index.files {
+            for line in &file.lines {
+                for identifier in IDENTIFIER_PATTERN.find_iter(line) {
+                    *usages.entry(usage_key(identifier.as_str())).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut declarations: HashMap<&str, usize> = HashMap::new();
+        for symbol in symbols.iter().filter(|s| s.kind != DeadCodeKind::UnimplementedProtocol) {
+            *declarations.entry(usage_key(&symbol.name)).or_insert(0) += 1;
+        }
+        for name in types.extensions.iter().chain(types.implementations.iter()) {
+            *declarations.entry(usage_key(name)).or_insert(0) += 1;
+        }
+        let unused = |name: &str| {
+            let key = usage_key(name);
+            usages.get(key).copied().unwrap_or(0) <= declarations.get(key).copied().unwrap_or(0)
+        };
+
+        let mut findings: Vec<(usize, DeadCodeFinding)> = Vec::new();
+        let mut used_top_level: HashSet<usize> = HashSet::new();
+        let mut top_level_confidence: HashMap<usize, Confidence> = HashMap::new();
+
+        for symbol in &symbols {
+            let is_unused = match symbol.kind {
+                DeadCodeKind::UnimplementedProtocol => !types.conformed.contains(&symbol.name),
+                _ => unused(&symbol.name),
+            };
+            if symbol.top_level {
+                if is_unused {
+                    let confidence = top_level_confidence.entry(symbol.file).or_insert(Confidence::High);
+                    *confidence = (*confidence).max(symbol.confidence);
+                } else if symbol.kind != DeadCodeKind::UnimplementedProtocol {
+                    used_top_level.insert(symbol.file);
+                }
+            }
+            if !is_unused {
+                continue;
+            }
+
+            let reason = match symbol.kind {
+                DeadCodeKind::UnimplementedProtocol => "aucun type conforme".to_string(),
+                _ => "aucune référence dans le projet".to_string(),
+            };
+            findings.push((
+                symbol.file,
+                DeadCodeFinding {
+                    kind: symbol.kind,
+                    name: symbol.qualified_name.clone(),
+                    file_path: index.files[symbol.file].path.clone(),
+                    line: symbol.line + 1,
+                    confidence: symbol.confidence,
+                    reason,
+                },
+            ));
+        }
+
+        // Un fichier dont aucune déclaration n'est utilisée est signalé une seule fois
+        let dead_files: HashSet<usize> = top_level_confidence
+            .keys()
+            .copied()
+            .filter(|file| !used_top_level.contains(file) && !types.files_with_extensions.contains(file))
+            .collect();
+        let mut result: Vec<DeadCodeFinding> = findings
+            .into_iter()
+            .filter(|(file, _)| !dead_files.contains(file))
+            .map(|(_, finding)| finding)
+            .collect();
+        for &file in &dead_files {
+            result.push(DeadCodeFinding {
+                kind: DeadCodeKind::File,
+                name: index.files[file]
+                    .path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                file_path: index.files[file].path.clone(),
+                line: 1,
+                confidence: top_level_confidence[&file],
+                reason: "aucune déclaration du fichier n'est référencée".to_string(),
+            });
+        }
+
+        result.sort_by(|a, b| (&a.file_path, a.line).cmp(&(&b.file_path, b.line)));
+        result
+    }
+
+    /// Convertit les résultats en problèmes rattachés à leur fichier
+    pub fn issues(&self, findings: &[DeadCodeFinding]) -> Vec<(PathBuf, FileIssue)> {
+        findings
+            .iter()
+            .map(|finding| {
+                let subject = match finding.kind {
+                    DeadCodeKind::Function => "Fonction",
+                    DeadCodeKind::Type => "Type",
+                    DeadCodeKind::Property => "Propriété",
+                    DeadCodeKind::ProtocolMember => "Exigence de protocole",
+                    DeadCodeKind::UnimplementedProtocol => "Protocole",
+                    DeadCodeKind::File => "Fichier",
+                };
+                let confidence = match finding.confidence {
+                    Confidence::High => "élevée",
+                    Confidence::Medium => "moyenne",
+                    Confidence::Low => "faible",
+                };
+                let issue = FileIssue {
+                    issue_type: IssueType::UnusedCode,
+                    severity: Severity::Low,
+                    line: finding.line,
+                    column: None,
+                    message: format!("{} '{}' inutilisé : {} (confiance {})", subject, finding.name, finding.reason, confidence),
+                    suggestion: Some(
+                        "Supprimer la déclaration après avoir vérifié les usages dynamiques (sélecteurs, KVC, réflexion, modèle CoreData)".to_string(),
+                    ),
+                    code_snippet: None,
+                    rule_id: None,
+                    declaration: None,
+                    ownership: None,
+                    fix: None,
+                };
+                (finding.file_path.clone(), issue)
+            })
+            .collect()
+    }
+}
+
+/// Relations entre types du projet nécessaires à l'analyse
+struct TypeInfo {
+    /// Supertypes déclarés, extensions comprises
+    supertypes: HashMap<String, Vec<String>>,
+    /// Noms de types et protocoles déclarés dans le projet
+    declared: HashSet<String>,
+    /// Protocoles ayant au moins un type (ou protocole) conforme
+    conformed: HashSet<String>,
+    /// Exigences de protocole (noms de membres)
+    requirements: HashSet<String>,
+    /// Noms des types étendus (une extension n'est pas une référence)
+    extensions: Vec<String>,
+    /// Implémentations d'exigences de protocole (comptées comme déclarations)
+    implementations: Vec<String>,
+    files_with_extensions: HashSet<usize>,
+}
+
+impl TypeInfo {
+    fn new(index: &ProjectIndex) -> Self {
+        let mut info = TypeInfo {
+            supertypes: HashMap::new(),
+            declared: HashSet::new(),
+            conformed: HashSet::new(),
+            requirements: HashSet::new(),
+            extensions: Vec::new(),
+            implementations: Vec::new(),
+            files_with_extensions: HashSet::new(),
+        };
+
+        for (file_index, file) in index.files.iter().enumerate() {
+            for declaration in &file.declarations {
+                let supertypes: Vec<String> = declaration
+                    .inherited
+                    .iter()
+                    .flat_map(|i| i.split('&'))
+                    .map(|i| i.split('<').next().unwrap_or(i).trim().to_string())
+                    .collect();
+                info.conformed.extend(supertypes.iter().cloned());
+                info.supertypes
+                    .entry(declaration.name.clone())
+                    .or_default()
+                    .extend(supertypes);
+
+                if declaration.kind == TypeKind::Extension {
+                    info.extensions.push(declaration.name.clone());
+                    info.files_with_extensions.insert(file_index);
+                } else {
+                    info.declared.insert(declaration.name.clone());
+                }
+            }
+        }
+
+        for file in &index.files {
+            for (_, name, _) in protocol_members(file) {
+                info.requirements.insert(name);
+            }
+        }
+        for file in &index.files {
+            for function in &file.functions {
+                if info.requirements.contains(&function.name) {
+                    info.implementations.push(function.name.clone());
+                }
+            }
+        }
+
+        info
+    }
+
+    fn supertypes_of(&self, name: &str) -> &[String] {
+        self.supertypes.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Le type dépend du runtime Objective-C (`@objc`, sous-classe de `NSObject`, `UIView`...)
+    fn is_objc_visible(&self, declaration: &TypeDeclaration) -> bool {
+        declaration.has_attribute("@objc")
+            || self.supertypes_of(&declaration.name).iter().any(|s| {
+                s.starts_with("NS") || s.starts_with("UI") || s.starts_with("CA") || s.starts_with("AV")
+            })
+    }
+
+    /// Le type est conforme à un protocole externe dont les exigences peuvent être appelées par le système
+    fn has_external_conformance(&self, name: &str) -> bool {
+        self.supertypes_of(name).iter().any(|s| {
+            !self.declared.contains(s) && !MARKER_PROTOCOLS.contains(&s.as_str()) && !CODABLE_PROTOCOLS.contains(&s.as_str())
+        })
+    }
+
+    fn is_codable(&self, name: &str) -> bool {
+        self.supertypes_of(name).iter().any(|s| CODABLE_PROTOCOLS.contains(&s.as_str()))
+    }
+}
+
+/// Exigences déclarées dans les protocoles d'un fichier : (protocole, membre, ligne)
+fn protocol_members(file: &IndexedFile) -> Vec<(String, String, usize)> {
+    let mut members = Vec::new();
+    for declaration in file.declarations.iter().filter(|d| d.kind == TypeKind::Protocol) {
+        for i in declaration.line_start + 1..declaration.line_end {
+            if swift_source::innermost_type_at(&file.declarations, i).map(|d| d.line_start) != Some(declaration.line_start) {
+                continue;
+            }
+            if let Some(cap) = PROTOCOL_MEMBER_PATTERN.captures(&file.lines[i]) {
+                let name = cap.get(1).or_else(|| cap.get(2)).unwrap().as_str().to_string();
+                members.push((declaration.name.clone(), name, i));
+            }
+        }
+    }
+    members
+}
+
+/// Clé de comptage d'un identifiant : `_propriété` (stockage d'un property wrapper) et
+/// `$propriété` (déjà découpé par l'expression régulière) comptent pour `propriété`
+fn usage_key(name: &str) -> &str {
+    name.strip_prefix('_').filter(|n| !n.is_empty()).unwrap_or(name)
+}
+
+fn is_private(modifiers: &[String]) -> bool {
+    modifiers.iter().any(|m| m == "private" || m == "fileprivate")
+}
+
+fn is_public(modifiers: &[String]) -> bool {
+    modifiers.iter().any(|m| m == "public" || m == "open")
+}
+
+fn has_runtime_attribute(attributes: &[String]) -> bool {
+    attributes.iter().any(|a| RUNTIME_ATTRIBUTES.contains(&a.as_str()))
+}
+
+/// Inventaire des déclarations analysées, avec leur niveau de confiance
+fn collect_symbols(index: &ProjectIndex, types: &TypeInfo) -> Vec<Symbol> {
+    let mut symbols = Vec::new();
+
+    for (file_index, file) in index.files.iter().enumerate() {
+        let owner_declaration = |owner: &str| file.declarations.iter().find(|d| d.name == owner);
+        let member_confidence = |owner: Option<&str>, modifiers: &[String]| {
+            let owner_declaration = owner.and_then(owner_declaration);
+            if owner_declaration.map(|d| types.is_objc_visible(d)).unwrap_or(false) {
+                Confidence::Low
+            } else if is_private(modifiers) {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            }
+        };
+
+        for declaration in &file.declarations {
+            if declaration.kind == TypeKind::Extension
+                || is_public(&declaration.modifiers)
+                || has_runtime_attribute(&declaration.attributes)
+                || declaration.name == "CodingKeys"
+                || types.supertypes_of(&declaration.name).iter().any(|s| SYSTEM_ENTRY_PROTOCOLS.contains(&s.as_str()))
+            {
+                continue;
+            }
+
+            let nested = file
+                .declarations
+                .iter()
+                .any(|d| d.line_start < declaration.line_start && d.contains(declaration.line_start));
+            let confidence = if types.is_objc_visible(declaration) {
+                Confidence::Low
+            } else if is_private(&declaration.modifiers) {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+
+            symbols.push(Symbol {
+                kind: if declaration.kind == TypeKind::Protocol {
+                    DeadCodeKind::UnimplementedProtocol
+                } else {
+                    DeadCodeKind::Type
+                },
+                name: declaration.name.clone(),
+                qualified_name: declaration.name.clone(),
+                file: file_index,
+                line: declaration.line_start,
+                confidence: if declaration.kind == TypeKind::Protocol && confidence == Confidence::High {
+                    Confidence::Medium
+                } else {
+                    confidence
+                },
+                top_level: !nested,
+            });
+            if declaration.kind == TypeKind::Protocol {
+                // Un protocole inutilisé comme type est aussi signalé comme type
+                symbols.push(Symbol {
+                    kind: DeadCodeKind::Type,
+                    name: declaration.name.clone(),
+                    qualified_name: declaration.name.clone(),
+                    file: file_index,
+                    line: declaration.line_start,
+                    confidence,
+                    top_level: !nested,
+                });
+            }
+        }
+
+        for function in &file.functions {
+            if function.name == "init"
+                || IMPLICIT_NAMES.contains(&function.name.as_str())
+                || function.name.starts_with("test")
+                || function.has_modifier("override")
+                || is_public(&function.modifiers)
+                || has_runtime_attribute(&function.attributes)
+                || types.requirements.contains(&function.name)
+            {
+                continue;
+            }
+
+            let mut confidence = member_confidence(function.owner.as_deref(), &function.modifiers);
+            if function
+                .owner
+                .as_deref()
+                .map(|owner| types.has_external_conformance(owner))
+                .unwrap_or(false)
+            {
+                // Peut implémenter une exigence d'un protocole système (délégués, `UIViewRepresentable`...)
+                confidence = Confidence::Low;
+            }
+
+            symbols.push(Symbol {
+                kind: DeadCodeKind::Function,
+                name: function.name.clone(),
+                qualified_name: function.qualified_name(),
+                file: file_index,
+                line: function.line_start,
+                confidence,
+                top_level: function.owner.is_none(),
+            });
+        }
+
+        for property in swift_source::find_properties(&file.lines, &file.declarations, &file.functions) {
+            if IMPLICIT_NAMES.contains(&property.name.as_str())
+                || property.has_modifier("override")
+                || is_public(&property.modifiers)
+                || has_runtime_attribute(&property.attributes)
+                || types.requirements.contains(&property.name)
+            {
+                continue;
+            }
+
+            let mut confidence = member_confidence(Some(&property.owner), &property.modifiers);
+            if types.is_codable(&property.owner) || types.has_external_conformance(&property.owner) {
+                confidence = Confidence::Low;
+            }
+
+            symbols.push(Symbol {
+                kind: DeadCodeKind::Property,
+                name: property.name.clone(),
+                qualified_name: format!("{}.{}", property.owner, property.name),
+                file: file_index,
+                line: property.line,
+                confidence,
+                top_level: false,
+            });
+        }
+
+        for (protocol, name, line) in protocol_members(file) {
+            let objc = owner_declaration(&protocol).map(|d| d.has_attribute("@objc")).unwrap_or(false);
+            symbols.push(Symbol {
+                kind: DeadCodeKind::ProtocolMember,
+                qualified_name: format!("{}.{}", protocol, name),
+                name,
+                file: file_index,
+                line,
+                confidence: if objc { Confidence::Low } else { Confidence::Medium },
+                top_level: false,
+            });
+        }
+    }
+
+    symbols
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(files: &[(&str, &str)]) -> Vec<DeadCodeFinding> {
+        let index = ProjectIndex::from_files(
+            files
+                .iter()
+                .map(|(path, content)| IndexedFile::new(PathBuf::from(path), content.to_string()))
+                .collect(),
+        );
+        DeadCodeAnalyzer::new().analyze(&index)
+    }
+
+    #[test]
+    fn test_detect_unused_members_and_protocols() {
+        let findings = analyze(&[
+            ("Store.swift", "final class DeckStore {\n    private var cache: [String] = []\n    @objc func refreshFromNotification() {\n    }\n    private func legacyReload() {\n    }\n    func reload() {\n        print(cache)\n    }\n}"),
+            ("Protocols.swift", "protocol DeckSchedulerProtocolV2 {\n    func schedule()\n}"),
+            ("App.swift", "func start(store: DeckStore) {\n    store.reload()\n}\nlet store = DeckStore()\nstart(store: store)"),
+        ]);
+
+        let names: Vec<(&str, DeadCodeKind, Confidence)> = findings
+            .iter()
+            .map(|f| (f.name.as_str(), f.kind, f.confidence))
+            .collect();
+
+        assert!(names.contains(&("DeckStore.legacyReload", DeadCodeKind::Function, Confidence::High)));
+        assert!(!names.iter().any(|(n, _, _)| n.contains("refreshFromNotification") || n.contains("cache")));
+        assert!(names.contains(&("Protocols.swift", DeadCodeKind::File, Confidence::Medium)));
+    }
+
+    #[test]
+    fn test_protocol_without_conformer_is_reported() {
+        let findings = analyze(&[
+            ("Service.swift", "protocol CardServiceProtocol {\n    func load()\n}\nprotocol ExtendedCardServiceProtocol {\n    func export()\n}\nfinal class CardService: CardServiceProtocol {\n    func load() {\n    }\n}"),
+            ("Use.swift", "func run(service: CardServiceProtocol, extended: ExtendedCardServiceProtocol) {\n    service.load()\n    extended.export()\n    _ = CardService()\n}"),
+        ]);
+
+        let unimplemented: Vec<&str> = findings
+            .iter()
+            .filter(|f| f.kind == DeadCodeKind::UnimplementedProtocol)
+            .map(|f| f.name.as_str())
+            .collect();
+
+        assert_eq!(unimplemented, vec!["ExtendedCardServiceProtocol"]);
+        assert!(!findings.iter().any(|f| f.kind == DeadCodeKind::ProtocolMember));
+    }
+}
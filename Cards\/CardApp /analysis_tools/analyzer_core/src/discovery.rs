@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
+
+/// Répertoires générés ou de gestion de version, jamais analysés
+const IGNORED_DIRECTORIES: &[&str] = &[".git", ".build", "build", "DerivedData", "target"];
+
+/// Collecte récursivement les fichiers portant l'une des extensions données
+pub fn collect_source_files(root: &Path, extensions: &[String]) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !is_ignored_directory(e))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| {
+            e.path()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| extensions.iter().any(|wanted| wanted.trim_start_matches('.') == ext))
+        })
+        .map(|e| e.into_path())
+        .collect();
+
+    files.sort();
+    files
+}
+
+/// Chemin relatif à la racine du projet, séparé par `/` quel que soit le système
+pub fn relative_path(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Le chemin est-il un répertoire ignoré par `collect_source_files` sous `root`, ou contenu dans l'un d'eux ?
+pub fn is_ignored(root: &Path, path: &Path) -> bool {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .any(|component| component.as_os_str().to_str().is_some_and(|name| IGNORED_DIRECTORIES.contains(&name)))
+}
+
+fn is_ignored_directory(entry: &DirEntry) -> bool {
+    entry.file_type().is_dir() && entry.file_name().to_str().is_some_and(|name| IGNORED_DIRECTORIES.contains(&name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_collect_skips_build_directories() {
+        let root = std::env::temp_dir().join(format!("discovery_test_{}", std::process::id()));
+        fs::create_dir_all(root.join("App/Views")).unwrap();
+        fs::create_dir_all(root.join(".build/checkouts")).unwrap();
+        fs::write(root.join("App/Views/CardView.swift"), "struct CardView {}").unwrap();
+        fs::write(root.join("App/README.md"), "").unwrap();
+        fs::write(root.join(".build/checkouts/Dependency.swift"), "").unwrap();
+
+        let files = collect_source_files(&root, &["swift".to_string()]);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(files, vec![root.join("App/Views/CardView.swift")]);
+    }
+}
@@ -0,0 +1,575 @@
+use crate::core_data_model::CoreDataModel;
+use crate::models::{FileIssue, IssueType, Severity};
+use crate::rules::{AnalysisLevel, Rule, RuleCategory, RuleContext, RuleExplanation, RuleMetadata, SourceFile};
+use crate::swift_source::{self, TypeDeclaration, ViewProperty};
+use crate::types::CoreDataIssueType;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::sync::Arc;
+
+lazy_static! {
+    static ref FETCH_REQUEST_WRAPPER_PATTERN: Regex = Regex::new(
+        r"@(Sectioned)?FetchRequest\b"
+    ).unwrap();
+
+    static ref RESULTS_TYPE_PATTERN: Regex = Regex::new(
+        r"^\s*(?:(?:private|fileprivate|internal|public)\s+)?var\s+(\w+)\s*:\s*(?:Sectioned)?FetchedResults\s*<(?:[^,>]+,\s*)?(\w+)\s*>"
+    ).unwrap();
+
+    static ref ENTITY_ARGUMENT_PATTERN: Regex = Regex::new(
+        r"^(\w+)\.entity\s*\(\s*\)"
+    ).unwrap();
+
+    static ref KEY_PATH_SORT_PATTERN: Regex = Regex::new(
+        r"(?:keyPath\s*:\s*|SortDescriptor\s*\(\s*)\\(\w+)?\.(\w+)"
+    ).unwrap();
+
+    static ref STRING_KEY_SORT_PATTERN: Regex = Regex::new(
+        r#"key\s*:\s*"(\w+)""#
+    ).unwrap();
+
+    static ref PREDICATE_FORMAT_PATTERN: Regex = Regex::new(
+        r#"format\s*:\s*"([^"]*)""#
+    ).unwrap();
+
+    static ref PREDICATE_KEY_PATTERN: Regex = Regex::new(
+        r"\b([a-z]\w*)\s*(?:==|=|!=|<=|>=|<|>|\bBEGINSWITH\b|\bCONTAINS\b|\bLIKE\b|\bIN\b|\bMATCHES\b)"
+    ).unwrap();
+
+    static ref FETCH_REQUEST_CREATION_PATTERN: Regex = Regex::new(
+        r"\bNSFetchRequest\s*<[^>]*>\s*\(|\bNSFetchRequest\s*\(|\.fetchRequest\s*\(\s*\)"
+    ).unwrap();
+
+    static ref FETCH_REQUEST_BINDING_PATTERN: Regex = Regex::new(
+        r"\b(?:let|var)\s+(\w+)\s*(?::[^=]+)?=\s*(NSFetchRequest\b|\w+\.fetchRequest\s*\(\s*\))"
+    ).unwrap();
+
+    static ref FETCH_REQUEST_BOUND_PATTERN: Regex = Regex::new(
+        r"\b(\w+)\.(?:fetchLimit|fetchBatchSize)\s*=[^=]"
+    ).unwrap();
+
+    static ref FETCH_RESULTS_PATTERN: Regex = Regex::new(
+        r"\b(?:let|var)\s+(\w+)\s*(?::[^=]+)?=\s*(?:try[?!]?\s+)?[\w.]*\bfetch\(\s*(\w+)\s*\)"
+    ).unwrap();
+
+    static ref FETCH_CALL_PATTERN: Regex = Regex::new(r"\bfetch\(\s*(\w+)\s*\)").unwrap();
+
+    static ref FOR_IN_PATTERN: Regex = Regex::new(r"\bfor\b.+?\bin\b(.+)").unwrap();
+}
+
+/// Déclaration `@FetchRequest` / `@SectionedFetchRequest` analysée
+#[derive(Debug, Clone)]
+pub struct FetchRequestDeclaration {
+    pub sectioned: bool,
+    /// Ligne de l'attribut (indexée à partir de 0)
+    pub line: usize,
+    pub column: usize,
+    pub property: Option<String>,
+    pub entity: Option<String>,
+    /// `None` si l'argument `sortDescriptors` est absent
+    pub sort_descriptors: Option<String>,
+    pub sort_keys: Vec<String>,
+    pub predicate: Option<String>,
+    pub predicate_keys: Vec<String>,
+    pub animation: Option<String>,
+    /// La requête est fournie toute faite via `fetchRequest:`
+    pub uses_fetch_request: bool,
+}
+
+/// Analyseur de l'intégration Core Data dans SwiftUI (`@FetchRequest`, requêtes dans `body`)
+#[derive(Default)]
+pub struct FetchRequestAnalyzer {
+    model: Option<Arc<CoreDataModel>>,
+}
+
+impl FetchRequestAnalyzer {
+    pub const RULE: RuleMetadata = RuleMetadata::new(
+        "coredata.fetch-request",
+        RuleCategory::CoreData,
+        Severity::Medium,
+        "@FetchRequest sans tri, clés non indexées, requêtes exécutées dans body ou sans limite de résultats",
+    )
+    .with_level(AnalysisLevel::Ast)
+    .with_explanation(RuleExplanation {
+        rationale: "@FetchRequest exige des descripteurs de tri à l'exécution, et un tri ou un prédicat sur un attribut non indexé du modèle force un parcours complet de la table. Une requête exécutée dans body est relancée à chaque rendu de la vue, et une NSFetchRequest sans fetchLimit ni fetchBatchSize matérialise d'un coup tous les objets correspondants.",
+        bad_example: "@FetchRequest(entity: CardEntity.entity(), sortDescriptors: [])\nvar cards: FetchedResults<CardEntity>",
+        good_example: "@FetchRequest(sortDescriptors: [SortDescriptor(\\.dueDate)], predicate: NSPredicate(format: \"deck == %@\", deck))\nvar cards: FetchedResults<CardEntity>  // dueDate indexé dans le modèle",
+        conventions: &["2.1 Principe de Séparation"],
+    });
+
+    pub fn new() -> Self {
+        FetchRequestAnalyzer { model: None }
+    }
+
+    /// Utilise le modèle Core Data du projet pour vérifier les index
+    pub fn with_model(mut self, model: Option<Arc<CoreDataModel>>) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Analyser un fichier pour les problèmes d'intégration SwiftUI / Core Data
+    pub fn analyze(&self, content: &str) -> Vec<FileIssue> {
+        self.analyze_with_model(content, self.model.as_deref())
+    }
+
+    fn analyze_with_model(&self, content: &str, model: Option<&CoreDataModel>) -> Vec<FileIssue> {
+        let mut issues = Vec::new();
+
+        let raw_lines: Vec<&str> = content.lines().collect();
+        for declaration in parse_fetch_request_declarations(content) {
+            self.check_declaration(&declaration, model, &raw_lines, &mut issues);
+        }
+
+        let lines = swift_source::sanitize_lines(content);
+        let types = swift_source::find_type_declarations(&lines);
+        let view_properties = swift_source::find_view_properties(&lines, &types);
+        self.analyze_requests_in_body(&lines, &view_properties, &raw_lines, &mut issues);
+        self.analyze_unbounded_requests(&lines, &types, &view_properties, &raw_lines, &mut issues);
+
+        issues.sort_by_key(|i| i.line);
+        issues
+    }
+
+    fn check_declaration(
+        &self,
+        declaration: &FetchRequestDeclaration,
+        model: Option<&CoreDataModel>,
+        raw_lines: &[&str],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        let wrapper = if declaration.sectioned { "@SectionedFetchRequest" } else { "@FetchRequest" };
+        let property = declaration.property.as_deref().unwrap_or("?");
+
+        let missing_sort = match declaration.sort_descriptors.as_deref() {
+            None => !declaration.uses_fetch_request,
+            Some(value) => value.trim_start_matches('[').trim_end_matches(']').trim().is_empty(),
+        };
+        if missing_sort {
+            issues.push(self.issue(
+                CoreDataIssueType::InefficientFetch,
+                Severity::High,
+                declaration,
+                format!(
+                    "{} '{}' sans descripteur de tri : les descripteurs sont requis à l'exécution par le contrôleur de résultats",
+                    wrapper, property
+                ),
+                "Fournir au moins un descripteur dans sortDescriptors, idéalement sur une propriété indexée",
+                raw_lines,
+            ));
+        }
+
+        let entity = match (model, &declaration.entity) {
+            (Some(model), Some(entity)) => model.entity(entity),
+            _ => None,
+        };
+
+        if let Some(entity) = entity {
+            for key in &declaration.sort_keys {
+                if entity.attributes.contains(key) && !entity.is_indexed(key) {
+                    issues.push(self.issue(
+                        CoreDataIssueType::MissingIndex,
+                        Severity::Medium,
+                        declaration,
+                        format!(
+                            "{} '{}' trie sur '{}.{}' qui n'est pas indexé dans le modèle",
+                            wrapper, property, entity.name, key
+                        ),
+                        "Ajouter un fetchIndex sur cette propriété dans le .xcdatamodel",
+                        raw_lines,
+                    ));
+                }
+            }
+            for key in &declaration.predicate_keys {
+                if entity.attributes.contains(key) && !entity.is_indexed(key) {
+                    issues.push(self.issue(
+                        CoreDataIssueType::MissingIndex,
+                        Severity::Low,
+                        declaration,
+                        format!(
+                            "Le prédicat de {} '{}' filtre sur '{}.{}' qui n'est pas indexé dans le modèle",
+                            wrapper, property, entity.name, key
+                        ),
+                        "Ajouter un fetchIndex sur cette propriété si le volume de données est important",
+                        raw_lines,
+                    ));
+                }
+            }
+        }
+
+        if declaration.animation.is_some() && declaration.predicate.is_none() && !declaration.uses_fetch_request {
+            issues.push(self.issue(
+                CoreDataIssueType::InefficientFetch,
+                Severity::Low,
+                declaration,
+                format!(
+                    "{} '{}' animé sans prédicat : chaque changement calcule la différence sur toute l'entité",
+                    wrapper, property
+                ),
+                "Restreindre la requête avec un prédicat ou une fetchRequest limitée (fetchLimit)",
+                raw_lines,
+            ));
+        }
+    }
+
+    /// Requêtes `NSFetchRequest` recréées dans `body` à chaque évaluation de la vue
+    fn analyze_requests_in_body(
+        &self,
+        lines: &[String],
+        view_properties: &[ViewProperty],
+        raw_lines: &[&str],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        for property in view_properties {
+            for (i, line) in lines.iter().enumerate().take(property.line_end + 1).skip(property.line_start) {
+                if let Some(m) = FETCH_REQUEST_CREATION_PATTERN.find(line) {
+                    issues.push(FileIssue {
+                        issue_type: IssueType::from(CoreDataIssueType::InefficientFetch),
+                        severity: Severity::High,
+                        line: i + 1,
+                        column: Some(m.start()),
+                        message: format!(
+                            "NSFetchRequest recréée dans '{}' à chaque évaluation de la vue",
+                            property.name
+                        ),
+                        suggestion: Some("Déclarer la requête avec @FetchRequest ou la déplacer dans le view model".to_string()),
+                        code_snippet: raw_lines.get(i).map(|l| l.to_string()),
+                        rule_id: None,
+                        declaration: None,
+                        ownership: None,
+                        fix: None,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Requêtes manuelles sans `fetchLimit` ni `fetchBatchSize` dans la fonction qui les crée :
+    /// tous les objets correspondants sont chargés en une fois (celles de `body` sont signalées à part).
+    ///
+    /// Une boucle sur les résultats est signalée à la place de la requête, plus sévèrement : chaque
+    /// objet est matérialisé avant la première itération.
+    fn analyze_unbounded_requests(
+        &self,
+        lines: &[String],
+        types: &[TypeDeclaration],
+        view_properties: &[ViewProperty],
+        raw_lines: &[&str],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        let functions = swift_source::find_functions(lines, types);
+
+        for (i, line) in lines.iter().enumerate() {
+            if is_fetch_request_wrapper(line)
+                || view_properties.iter().any(|p| (p.line_start..=p.line_end).contains(&i))
+            {
+                continue;
+            }
+            let Some(cap) = FETCH_REQUEST_BINDING_PATTERN.captures(line) else {
+                continue;
+            };
+            let name = &cap[1];
+
+            let (start, end) = functions
+                .iter()
+                .filter(|f| (f.line_start..=f.line_end).contains(&i))
+                .max_by_key(|f| f.line_start)
+                .map(|f| (f.line_start, f.line_end))
+                .unwrap_or((0, lines.len() - 1));
+            let bounded = lines[start..=end]
+                .iter()
+                .flat_map(|l| FETCH_REQUEST_BOUND_PATTERN.captures_iter(l))
+                .any(|c| &c[1] == name);
+            if bounded {
+                continue;
+            }
+
+            let results: Vec<&str> = lines[start..=end]
+                .iter()
+                .filter_map(|l| FETCH_RESULTS_PATTERN.captures(l))
+                .filter(|c| &c[2] == name)
+                .map(|c| c.get(1).unwrap().as_str())
+                .collect();
+            let iterated = (start..=end).find_map(|j| {
+                let cap = FOR_IN_PATTERN.captures(&lines[j])?;
+                let sequence = cap.get(1)?.as_str();
+                let fetched = FETCH_CALL_PATTERN.captures_iter(sequence).any(|c| &c[1] == name);
+                let over_results = sequence
+                    .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .any(|word| results.contains(&word));
+                (fetched || over_results).then(|| (j, cap.get(0).unwrap().start()))
+            });
+            if let Some((j, column)) = iterated {
+                issues.push(FileIssue {
+                    issue_type: IssueType::from(CoreDataIssueType::BatchingOpportunity),
+                    severity: Severity::Medium,
+                    line: j + 1,
+                    column: Some(column),
+                    message: format!(
+                        "Boucle sur les résultats de la requête '{}' sans fetchBatchSize : tous les objets sont chargés avant la première itération",
+                        name
+                    ),
+                    suggestion: Some(format!("Définir {}.fetchBatchSize pour charger les objets par lots pendant la boucle", name)),
+                    code_snippet: raw_lines.get(j).map(|l| l.to_string()),
+                    rule_id: None,
+                    declaration: None,
+                    ownership: None,
+                    fix: None,
+                });
+                continue;
+            }
+
+            issues.push(FileIssue {
+                issue_type: IssueType::from(CoreDataIssueType::InefficientFetch),
+                severity: Severity::Low,
+                line: i + 1,
+                column: Some(cap.get(2).unwrap().start()),
+                message: format!(
+                    "Requête '{}' sans fetchLimit ni fetchBatchSize : tous les objets correspondants sont chargés en mémoire",
+                    name
+                ),
+                suggestion: Some(format!(
+                    "Définir {}.fetchBatchSize (ou fetchLimit si seuls les premiers résultats sont utilisés) avant d'exécuter la requête",
+                    name
+                )),
+                code_snippet: raw_lines.get(i).map(|l| l.to_string()),
+                rule_id: None,
+                declaration: None,
+                ownership: None,
+                fix: None,
+            });
+        }
+    }
+
+    fn issue(
+        &self,
+        kind: CoreDataIssueType,
+        severity: Severity,
+        declaration: &FetchRequestDeclaration,
+        message: String,
+        suggestion: &str,
+        raw_lines: &[&str],
+    ) -> FileIssue {
+        FileIssue {
+            issue_type: IssueType::from(kind),
+            severity,
+            line: declaration.line + 1,
+            column: Some(declaration.column),
+            message,
+            suggestion: Some(suggestion.to_string()),
+            code_snippet: raw_lines.get(declaration.line).map(|l| l.to_string()),
+            rule_id: None,
+            declaration: None,
+            ownership: None,
+            fix: None,
+        }
+    }
+}
+
+impl Rule for FetchRequestAnalyzer {
+    fn metadata(&self) -> &RuleMetadata {
+        &Self::RULE
+    }
+
+    /// Le modèle associé à l'analyseur prime sur celui du contexte
+    fn check(&self, file: &SourceFile, context: &RuleContext) -> Vec<FileIssue> {
+        let model = self.model.as_deref().unwrap_or(&context.core_data_model);
+        self.analyze_with_model(file.content, Some(model))
+    }
+}
+
+/// Indique si une ligne déclare un property wrapper `@FetchRequest` plutôt qu'une requête manuelle
+pub fn is_fetch_request_wrapper(line: &str) -> bool {
+    FETCH_REQUEST_WRAPPER_PATTERN.is_match(line)
+}
+
+/// Repère et analyse les déclarations `@FetchRequest` / `@SectionedFetchRequest` d'un fichier
+pub fn parse_fetch_request_declarations(content: &str) -> Vec<FetchRequestDeclaration> {
+    // Texte brut découpé comme le texte nettoyé (fins de ligne `\r\n` comprises) : mêmes décalages en octets
+    let raw = content.lines().collect::<Vec<_>>().join("\n");
+    let sanitized = swift_source::sanitize_lines(content).join("\n");
+    let mut declarations = Vec::new();
+
+    for m in FETCH_REQUEST_WRAPPER_PATTERN.find_iter(&sanitized) {
+        let line = swift_source::line_index_at(&sanitized, m.start());
+        let line_start = sanitized[..m.start()].rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        let mut declaration = FetchRequestDeclaration {
+            sectioned: m.as_str().contains("Sectioned"),
+            line,
+            column: m.start() - line_start,
+            property: None,
+            entity: None,
+            sort_descriptors: None,
+            sort_keys: Vec::new(),
+            predicate: None,
+            predicate_keys: Vec::new(),
+            animation: None,
+            uses_fetch_request: false,
+        };
+
+        // Parenthèses repérées dans le texte nettoyé (commentaires ignorés), arguments lus dans le
+        // texte brut pour conserver les clés en chaîne
+        let mut after_arguments = m.end();
+        if sanitized[m.end()..].trim_start().starts_with('(') {
+            let open = m.end() + sanitized[m.end()..].find('(').unwrap();
+            if let Some(close) = swift_source::matching_parenthesis(&sanitized, open) {
+                for (label, value) in swift_source::split_arguments(&raw[open + 1..close]) {
+                    apply_argument(&mut declaration, label.as_deref(), value);
+                }
+                after_arguments = close + 1;
+            }
+        }
+
+        let following = sanitized[after_arguments..].trim_start();
+        let following = following.lines().next().unwrap_or("");
+        if let Some(cap) = RESULTS_TYPE_PATTERN.captures(following) {
+            declaration.property = Some(cap[1].to_string());
+            if declaration.entity.is_none() {
+                declaration.entity = Some(cap[2].to_string());
+            }
+        }
+
+        declarations.push(declaration);
+    }
+
+    declarations
+}
+
+fn apply_argument(declaration: &mut FetchRequestDeclaration, label: Option<&str>, value: String) {
+    match label {
+        Some("entity") => {
+            if let Some(cap) = ENTITY_ARGUMENT_PATTERN.captures(&value) {
+                declaration.entity = Some(cap[1].to_string());
+            }
+        }
+        Some("sortDescriptors") => {
+            for cap in KEY_PATH_SORT_PATTERN.captures_iter(&value) {
+                if declaration.entity.is_none() {
+                    declaration.entity = cap.get(1).map(|m| m.as_str().to_string());
+                }
+                declaration.sort_keys.push(cap[2].to_string());
+            }
+            for cap in STRING_KEY_SORT_PATTERN.captures_iter(&value) {
+                declaration.sort_keys.push(cap[1].to_string());
+            }
+            declaration.sort_descriptors = Some(value);
+        }
+        Some("predicate") => {
+            if let Some(cap) = PREDICATE_FORMAT_PATTERN.captures(&value) {
+                declaration.predicate_keys = PREDICATE_KEY_PATTERN
+                    .captures_iter(&cap[1])
+                    .map(|k| k[1].to_string())
+                    .collect();
+            }
+            declaration.predicate = Some(value);
+        }
+        Some("animation") => declaration.animation = Some(value),
+        Some("fetchRequest") => declaration.uses_fetch_request = true,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> Arc<CoreDataModel> {
+        let mut model = CoreDataModel::default();
+        model.add_contents(
+            "<entity name=\"CardEntity\" representedClassName=\".CardEntity\">\n\
+                 <attribute name=\"question\" attributeType=\"String\"/>\n\
+                 <attribute name=\"createdAt\" indexed=\"YES\" attributeType=\"Date\"/>\n\
+             </entity>",
+        );
+        Arc::new(model)
+    }
+
+    #[test]
+    fn test_parse_multiline_fetch_request() {
+        let content = "@FetchRequest(\n    sortDescriptors: [NSSortDescriptor(keyPath: \\CardEntity.question, ascending: true)],\n    predicate: NSPredicate(format: \"question CONTAINS %@\", text),\n    animation: .default)\nprivate var cards: FetchedResults<CardEntity>";
+
+        let declarations = parse_fetch_request_declarations(content);
+
+        assert_eq!(declarations.len(), 1);
+        assert_eq!(declarations[0].property.as_deref(), Some("cards"));
+        assert_eq!(declarations[0].entity.as_deref(), Some("CardEntity"));
+        assert_eq!(declarations[0].sort_keys, vec!["question"]);
+        assert_eq!(declarations[0].predicate_keys, vec!["question"]);
+    }
+
+    #[test]
+    fn test_detect_missing_sort_and_unindexed_key() {
+        let analyzer = FetchRequestAnalyzer::new().with_model(Some(model()));
+        let content = "@FetchRequest(entity: CardEntity.entity(), sortDescriptors: [])\nvar all: FetchedResults<CardEntity>\n@FetchRequest(sortDescriptors: [SortDescriptor(\\.question)])\nvar sorted: FetchedResults<CardEntity>";
+
+        let issues = analyzer.analyze(content);
+
+        assert!(issues.iter().any(|i| (i.line, i.column) == (1, Some(0)) && i.issue_type == IssueType::InefficientFetch));
+        assert!(issues.iter().any(|i| i.line == 3 && i.issue_type == IssueType::MissingIndex));
+    }
+
+    #[test]
+    fn test_crlf_and_non_ascii_keep_offsets_aligned() {
+        let analyzer = FetchRequestAnalyzer::new().with_model(Some(model()));
+        let header = "// Révision\r\n".repeat(16);
+        let content = format!(
+            "{}struct DueView: View {{\r\n    /*é*/@FetchRequest(sortDescriptors: [SortDescriptor(\\.createdAt)]) // tri (\"é\")\r\n    var cards: FetchedResults<CardEntity>\r\n}}\r\n",
+            header
+        );
+
+        let declarations = parse_fetch_request_declarations(&content);
+        let issues = analyzer.analyze(&content);
+
+        assert_eq!(declarations.len(), 1);
+        assert_eq!((declarations[0].line, declarations[0].column), (17, 10));
+        assert_eq!(declarations[0].property.as_deref(), Some("cards"));
+        assert_eq!(declarations[0].sort_keys, ["createdAt"]);
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    #[test]
+    fn test_request_created_in_body_reported_once() {
+        let content = "import SwiftUI\nstruct DeckView: View {\n    var body: some View {\n        let request = NSFetchRequest<CardEntity>(entityName: \"CardEntity\")\n        return Text(\"\\(request)\")\n    }\n}\n";
+
+        let report = crate::analyzer::Analyzer::new(crate::models::AnalysisConfig::default())
+            .with_source("DeckView.swift", content)
+            .run();
+
+        let findings: Vec<(usize, Option<usize>, Option<&str>)> = report.files_analyzed[0]
+            .issues
+            .iter()
+            .filter(|issue| issue.line == 4)
+            .map(|issue| (issue.line, issue.column, issue.rule_id.as_deref()))
+            .collect();
+        assert_eq!(findings, [(4, Some(22), Some(FetchRequestAnalyzer::RULE.id))]);
+    }
+
+    #[test]
+    fn test_request_without_limit_in_its_function() {
+        let content = "final class DeckStore {\n    func dueCards() throws -> [CardEntity] {\n        let request = CardEntity.fetchRequest()\n        return try context.fetch(request)\n    }\n    func recentCards() throws -> [CardEntity] {\n        let request: NSFetchRequest<CardEntity> = CardEntity.fetchRequest()\n        request.fetchLimit = 20\n        return try context.fetch(request)\n    }\n}\nstruct DueView: View {\n    @FetchRequest(fetchRequest: CardEntity.fetchRequest())\n    var cards: FetchedResults<CardEntity>\n    var body: some View { Text(\"\") }\n}\n";
+
+        let report = crate::analyzer::Analyzer::new(crate::models::AnalysisConfig::default())
+            .with_source("DeckStore.swift", content)
+            .run();
+
+        let findings: Vec<(usize, Option<usize>, Severity)> = report.files_analyzed[0]
+            .issues
+            .iter()
+            .filter(|issue| issue.rule_id.as_deref() == Some(FetchRequestAnalyzer::RULE.id))
+            .map(|issue| (issue.line, issue.column, issue.severity))
+            .collect();
+        assert_eq!(findings, [(3, Some(22), Severity::Low)]);
+    }
+
+    #[test]
+    fn test_loop_over_results_without_batch_size() {
+        let content = "final class ReviewScheduler {\n    func reschedule() throws {\n        let request = CardEntity.fetchRequest()\n        let cards = try context.fetch(request)\n        for card in cards where card.isDue {\n            card.dueDate = nextDate(for: card)\n        }\n    }\n    func archive() throws {\n        let request = DeckEntity.fetchRequest()\n        request.fetchBatchSize = 50\n        for deck in try context.fetch(request) {\n            deck.isArchived = true\n        }\n    }\n}\n";
+
+        let issues = FetchRequestAnalyzer::new().analyze(content);
+
+        let findings: Vec<(usize, Option<usize>, Severity, IssueType)> =
+            issues.iter().map(|i| (i.line, i.column, i.severity, i.issue_type)).collect();
+        assert_eq!(findings, [(5, Some(8), Severity::Medium, IssueType::BatchingOpportunity)]);
+        assert!(issues[0].message.contains("'request'"));
+    }
+}
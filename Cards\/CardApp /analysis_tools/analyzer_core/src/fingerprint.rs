@@ -0,0 +1,93 @@
+//! Empreintes stables des problèmes, indépendantes des numéros de ligne
+//!
+//! Une empreinte combine la règle, le chemin relatif du fichier, la déclaration
+//! englobante et l'extrait de code normalisé (espaces regroupés), plus le rang
+//! de l'occurrence quand le même extrait déclenche plusieurs fois la même règle
+//! dans une déclaration.
+
+use std::collections::HashMap;
+
+use crate::models::FileIssue;
+
+/// Calcule les empreintes des problèmes dans l'ordre où ils sont présentés
+#[derive(Debug, Default)]
+pub struct Fingerprinter {
+    occurrences: HashMap<u64, usize>,
+}
+
+impl Fingerprinter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empreinte hexadécimale (32 caractères) ; `relative_path` doit être relatif à la racine du projet
+    pub fn fingerprint(&mut self, relative_path: &str, issue: &FileIssue) -> String {
+        let content = issue.code_snippet.as_deref().unwrap_or(&issue.message);
+        let key = format!(
+            "{}\0{}\0{}\0{}",
+            issue.rule_id.as_deref().unwrap_or_default(),
+            relative_path,
+            issue.declaration.as_deref().unwrap_or_default(),
+            normalize(content)
+        );
+
+        let base = fnv1a(key.as_bytes(), FNV_OFFSET);
+        let occurrence = self.occurrences.entry(base).or_insert(0);
+        *occurrence += 1;
+        let key = format!("{}\0{}", key, occurrence);
+
+        // Deux passes de graines différentes pour une empreinte sur 128 bits
+        format!("{:016x}{:016x}", fnv1a(key.as_bytes(), FNV_OFFSET), fnv1a(key.as_bytes(), FNV_OFFSET_ALT))
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_OFFSET_ALT: u64 = 0x6c62_272e_07bb_0142;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// FNV-1a 64 bits : stable d'une version de Rust à l'autre, contrairement à `DefaultHasher`
+fn fnv1a(bytes: &[u8], offset: u64) -> u64 {
+    bytes.iter().fold(offset, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME))
+}
+
+/// Extrait sans indentation ni différences d'espacement
+pub fn normalize(snippet: &str) -> String {
+    snippet.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{IssueType, Severity};
+
+    fn issue(line: usize, snippet: &str) -> FileIssue {
+        FileIssue {
+            issue_type: IssueType::MemoryLeak,
+            severity: Severity::High,
+            line,
+            column: None,
+            message: "Délégué fort".to_string(),
+            suggestion: None,
+            code_snippet: Some(snippet.to_string()),
+            rule_id: Some("memory.strong-delegate".to_string()),
+            declaration: Some("EditorViewController".to_string()),
+            ownership: None,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_and_indentation() {
+        let moved = Fingerprinter::new().fingerprint("App/Editor.swift", &issue(12, "    var delegate: EditorDelegate?"));
+        let original = Fingerprinter::new().fingerprint("App/Editor.swift", &issue(2, "var  delegate: EditorDelegate?"));
+        assert_eq!(moved, original);
+        assert_eq!(moved.len(), 32);
+
+        let mut fingerprinter = Fingerprinter::new();
+        let first = fingerprinter.fingerprint("App/Editor.swift", &issue(2, "var delegate: EditorDelegate?"));
+        let second = fingerprinter.fingerprint("App/Editor.swift", &issue(8, "var delegate: EditorDelegate?"));
+        assert_eq!(first, original);
+        assert_ne!(first, second);
+        assert_ne!(first, Fingerprinter::new().fingerprint("App/List.swift", &issue(2, "var delegate: EditorDelegate?")));
+    }
+}
@@ -0,0 +1,284 @@
+//! Informations du dépôt git contenant le projet analysé, lues localement avec libgit2
+//!
+//! Fournit le commit courant et les modifications (fichiers et lignes) depuis une
+//! référence ou dans l'index, pour n'analyser que le code modifié.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use git2::{Delta, Diff, DiffDelta, DiffOptions, Oid, Repository};
+
+use crate::discovery::relative_path;
+use crate::models::PerformanceReport;
+
+/// Modifications à considérer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeScope {
+    /// Index et copie de travail (fichiers non suivis compris) par rapport à une référence (`main`, `HEAD~3`…)
+    Since(String),
+    /// Index par rapport à `HEAD` : contenu du prochain commit (crochet pre-commit)
+    Staged,
+}
+
+/// Fichier modifié : lignes ajoutées ou modifiées, numérotées dans sa nouvelle version
+#[derive(Debug, Clone, Default)]
+pub struct ChangedFile {
+    pub lines: Vec<RangeInclusive<usize>>,
+    /// Contenu indexé (`ChangeScope::Staged`) ; sinon le fichier de la copie de travail fait foi
+    pub staged_content: Option<String>,
+}
+
+impl ChangedFile {
+    pub fn contains_line(&self, line: usize) -> bool {
+        self.lines.iter().any(|range| range.contains(&line))
+    }
+}
+
+/// Fichiers modifiés (hors suppressions), par chemin absolu dans la copie de travail
+#[derive(Debug, Clone, Default)]
+pub struct ChangeSet {
+    pub files: BTreeMap<PathBuf, ChangedFile>,
+}
+
+impl ChangeSet {
+    /// Modifications du dépôt contenant `repo`
+    pub fn load(repo: &Path, scope: &ChangeScope) -> Result<Self, String> {
+        let repository = Repository::discover(repo)
+            .map_err(|e| format!("Aucun dépôt git trouvé pour {}: {}", repo.display(), e.message()))?;
+        let workdir = repository
+            .workdir()
+            .ok_or_else(|| format!("Le dépôt git de {} n'a pas de copie de travail", repo.display()))?
+            .to_path_buf();
+
+        let mut options = DiffOptions::new();
+        options.context_lines(0);
+        let diff = match scope {
+            ChangeScope::Since(reference) => {
+                let tree = repository
+                    .revparse_single(reference)
+                    .and_then(|object| object.peel_to_tree())
+                    .map_err(|e| format!("Référence git inconnue {}: {}", reference, e.message()))?;
+                options.include_untracked(true).recurse_untracked_dirs(true).show_untracked_content(true);
+                repository.diff_tree_to_workdir_with_index(Some(&tree), Some(&mut options))
+            }
+            ChangeScope::Staged => {
+                // Dépôt sans commit : tout l'index est nouveau
+                let head = repository.head().and_then(|head| head.peel_to_tree()).ok();
+                repository.diff_tree_to_index(head.as_ref(), None, Some(&mut options))
+            }
+        }
+        .map_err(|e| format!("Impossible de comparer les modifications git: {}", e.message()))?;
+
+        let mut changes = collect_hunks(&diff, &workdir)?;
+        if *scope == ChangeScope::Staged {
+            for (path, (oid, file)) in &mut changes {
+                let blob = repository
+                    .find_blob(*oid)
+                    .map_err(|e| format!("Contenu indexé illisible pour {}: {}", path.display(), e.message()))?;
+                file.staged_content = Some(String::from_utf8_lossy(blob.content()).into_owned());
+            }
+        }
+        Ok(ChangeSet {
+            files: changes.into_iter().map(|(path, (_, file))| (path, file)).collect(),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Fichier modifié correspondant à `path` (chemins comparés une fois résolus)
+    pub fn get(&self, path: &Path) -> Option<&ChangedFile> {
+        self.files.get(path).or_else(|| self.files.get(&fs::canonicalize(path).ok()?))
+    }
+
+    /// Ne garde que les problèmes situés sur des lignes modifiées, puis recalcule les compteurs du rapport
+    ///
+    /// Avec une ligne de base, le nombre de nouveaux problèmes suit le filtrage.
+    pub fn retain_changed_lines(&self, report: &mut PerformanceReport) {
+        for result in &mut report.files_analyzed {
+            match self.get(&result.file_path) {
+                Some(file) => result.issues.retain(|issue| file.contains_line(issue.line)),
+                None => result.issues.clear(),
+            }
+        }
+        if let Some(baseline) = &mut report.baseline {
+            baseline.new = report.files_analyzed.iter().map(|result| result.issues.len()).sum();
+        }
+        crate::analyzer::recount_issues(report);
+    }
+}
+
+/// Lignes ajoutées de chaque fichier du diff, avec l'objet de sa nouvelle version
+fn collect_hunks(diff: &Diff, workdir: &Path) -> Result<BTreeMap<PathBuf, (Oid, ChangedFile)>, String> {
+    let changes = RefCell::new(BTreeMap::new());
+    let new_path = |delta: &DiffDelta| {
+        // Fichier supprimé : rien à analyser
+        if delta.status() == Delta::Deleted {
+            return None;
+        }
+        let file = delta.new_file();
+        let path = workdir.join(file.path()?);
+        Some((fs::canonicalize(&path).unwrap_or(path), file.id()))
+    };
+
+    diff.foreach(
+        &mut |delta, _| {
+            if let Some((path, oid)) = new_path(&delta) {
+                changes.borrow_mut().entry(path).or_insert((oid, ChangedFile::default()));
+            }
+            true
+        },
+        None,
+        Some(&mut |delta, hunk| {
+            if let Some((path, oid)) = new_path(&delta) {
+                if hunk.new_lines() > 0 {
+                    let start = hunk.new_start() as usize;
+                    let mut changes = changes.borrow_mut();
+                    let entry = changes.entry(path).or_insert((oid, ChangedFile::default()));
+                    entry.1.lines.push(start..=start + hunk.new_lines() as usize - 1);
+                }
+            }
+            true
+        }),
+        None,
+    )
+    .map_err(|e| format!("Impossible de lire le diff git: {}", e.message()))?;
+    Ok(changes.into_inner())
+}
+
+/// Commit courant (`HEAD`), hors dépôt git ou sans commit : `None`
+pub fn head_commit(repo: &Path) -> Option<String> {
+    let repository = Repository::discover(repo).ok()?;
+    let commit = repository.head().ok()?.peel_to_commit().ok()?;
+    Some(commit.id().to_string())
+}
+
+/// Chemins des fichiers du projet relatifs à la racine du dépôt git qui le contient,
+/// pour les rapports lus depuis cette racine (SARIF, GitLab Code Quality)
+///
+/// Hors dépôt git, les chemins restent relatifs à la racine du projet.
+#[derive(Debug, Clone)]
+pub struct RepositoryPaths {
+    project_root: PathBuf,
+    /// Racine absolue des chemins, si elle existe sur le disque
+    root: Option<PathBuf>,
+    /// Chemin du projet sous cette racine (vide à la racine du dépôt)
+    project_prefix: PathBuf,
+}
+
+impl RepositoryPaths {
+    pub fn discover(project_root: &Path) -> Self {
+        let absolute_root = fs::canonicalize(project_root).ok();
+        let workdir = Repository::discover(project_root)
+            .ok()
+            .and_then(|repository| repository.workdir().and_then(|workdir| fs::canonicalize(workdir).ok()));
+        let (root, project_prefix) = match (absolute_root, workdir) {
+            (Some(absolute_root), Some(workdir)) => match absolute_root.strip_prefix(&workdir) {
+                Ok(prefix) => (Some(workdir.clone()), prefix.to_path_buf()),
+                Err(_) => (Some(absolute_root), PathBuf::new()),
+            },
+            (absolute_root, _) => (absolute_root, PathBuf::new()),
+        };
+        RepositoryPaths {
+            project_root: project_root.to_path_buf(),
+            root,
+            project_prefix,
+        }
+    }
+
+    /// Racine absolue des chemins : copie de travail du dépôt, ou projet hors dépôt
+    pub fn root(&self) -> Option<&Path> {
+        self.root.as_deref()
+    }
+
+    /// Chemin d'un fichier du projet relatif à la racine, séparateurs `/` ; `.` pour la racine elle-même
+    pub fn relative(&self, path: &Path) -> String {
+        let relative = relative_path(&self.project_root, path);
+        let relative = relative_path(Path::new(""), &self.project_prefix.join(relative));
+        if relative.is_empty() {
+            ".".to_string()
+        } else {
+            relative
+        }
+    }
+}
+
+/// Fichiers modifiés depuis `base` (index et copie de travail, plus les fichiers non suivis), en chemins absolus
+pub fn changed_files(repo: &Path, base: &str) -> Option<Vec<PathBuf>> {
+    let changes = ChangeSet::load(repo, &ChangeScope::Since(base.to_string())).ok()?;
+    Some(changes.files.into_keys().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::STRONG_DELEGATE;
+    use git2::Signature;
+
+    #[test]
+    fn test_changed_lines_since_head_and_staged() {
+        let root = std::env::temp_dir().join(format!("swift-analyzer-git-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let repository = Repository::init(&root).unwrap();
+        let editor = root.join("Editor.swift");
+        fs::write(&editor, STRONG_DELEGATE).unwrap();
+
+        let mut index = repository.index().unwrap();
+        index.add_path(Path::new("Editor.swift")).unwrap();
+        let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("Test", "test@example.com").unwrap();
+        repository.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[]).unwrap();
+
+        let content = format!("{}\nclass Inspector {{\n    var delegate: InspectorDelegate?\n}}\n", STRONG_DELEGATE);
+        fs::write(&editor, &content).unwrap();
+        index.add_path(Path::new("Editor.swift")).unwrap();
+        index.write().unwrap();
+        fs::write(root.join("List.swift"), "class List {}\n").unwrap();
+
+        let since = ChangeSet::load(&root, &ChangeScope::Since("HEAD".to_string())).unwrap();
+        let staged = ChangeSet::load(&root, &ChangeScope::Staged).unwrap();
+        let changed = since.get(&editor).cloned().unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let files: Vec<String> =
+            since.files.keys().map(|path| path.file_name().unwrap().to_string_lossy().into_owned()).collect();
+        assert_eq!(files, ["Editor.swift", "List.swift"]);
+        assert_eq!(changed.lines, [4..=7]);
+        assert_eq!(staged.files.len(), 1);
+        assert_eq!(staged.files.values().next().unwrap().staged_content.as_deref(), Some(content.as_str()));
+
+        let path = since.files.keys().next().unwrap().clone();
+        let mut report = Analyzer::new(AnalysisConfig::default()).with_source(path, content.as_str()).run();
+        assert_eq!(report.files_analyzed[0].issues.iter().filter(|issue| issue.line == 2).count(), 1);
+        since.retain_changed_lines(&mut report);
+        let lines: Vec<usize> = report.files_analyzed[0].issues.iter().map(|issue| issue.line).collect();
+        assert_eq!(lines, [6]);
+    }
+
+    #[test]
+    fn test_repository_paths_of_project_in_subdirectory() {
+        let root = std::env::temp_dir().join(format!("swift-analyzer-git-paths-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let project = root.join("Cards/Card App");
+        fs::create_dir_all(&project).unwrap();
+        Repository::init(&root).unwrap();
+
+        let paths = RepositoryPaths::discover(&project);
+        let outside = RepositoryPaths::discover(&root.join("Absent"));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(paths.root(), Some(fs::canonicalize(std::env::temp_dir()).unwrap().join(root.file_name().unwrap()).as_path()));
+        assert_eq!(paths.relative(&project.join("Views/Deck.swift")), "Cards/Card App/Views/Deck.swift");
+        assert_eq!(paths.relative(&project), "Cards/Card App");
+        assert_eq!(outside.root(), None);
+        assert_eq!(outside.relative(&root.join("Absent/Deck.swift")), "Deck.swift");
+        assert_eq!(outside.relative(&root.join("Absent")), ".");
+    }
+}
@@ -0,0 +1,202 @@
+//! Historique des analyses : une ligne JSON par exécution (fichier en ajout seul)
+//!
+//! Chaque entrée est datée et rattachée au commit git analysé ; elle conserve le
+//! score de santé, les compteurs par sévérité et par type, les points chauds et
+//! le score de criticité de chaque fichier, pour suivre leur évolution.
+
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::calculate_criticality_score;
+use crate::discovery::relative_path;
+use crate::models::{IssueType, PerformanceReport, Severity};
+
+/// Fichier d'historique par défaut de `--history` et `trend`
+pub const DEFAULT_HISTORY_FILE: &str = "swift-analyzer-history.jsonl";
+
+/// État d'un fichier lors d'une analyse
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FileHealth {
+    pub issues: usize,
+    pub criticality_score: f64,
+}
+
+/// Résumé d'une analyse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Secondes depuis l'époque Unix
+    pub timestamp: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    pub health_score: f64,
+    pub total_files: usize,
+    pub total_lines: usize,
+    pub issues_by_severity: BTreeMap<Severity, usize>,
+    pub issues_by_type: BTreeMap<IssueType, usize>,
+    /// Points chauds du rapport, du plus critique au moins critique
+    pub hotspots: Vec<String>,
+    /// Fichiers de score de criticité non nul, par chemin relatif à la racine du projet
+    pub files: BTreeMap<String, FileHealth>,
+}
+
+impl HistoryEntry {
+    pub fn from_report(report: &PerformanceReport, project_root: &Path, timestamp: u64, commit: Option<String>) -> Self {
+        let files = report
+            .files_analyzed
+            .iter()
+            .map(|result| {
+                let health = FileHealth {
+                    issues: result.issues.len(),
+                    criticality_score: calculate_criticality_score(result),
+                };
+                (relative_path(project_root, &result.file_path), health)
+            })
+            .filter(|(_, health)| health.criticality_score > 0.0)
+            .collect();
+
+        HistoryEntry {
+            timestamp,
+            commit,
+            health_score: report.project_stats.health_score,
+            total_files: report.project_stats.total_files,
+            total_lines: report.project_stats.total_lines,
+            issues_by_severity: report.issue_count_by_severity.iter().map(|(k, v)| (*k, *v)).collect(),
+            issues_by_type: report.issue_count_by_type.iter().map(|(k, v)| (*k, *v)).collect(),
+            hotspots: report.hotspots.iter().map(|hotspot| relative_path(project_root, &hotspot.file_path)).collect(),
+            files,
+        }
+    }
+
+    pub fn issue_count(&self) -> usize {
+        self.issues_by_severity.values().sum()
+    }
+
+    /// Commit abrégé, ou `-` hors dépôt git
+    pub fn short_commit(&self) -> &str {
+        self.commit.as_deref().map_or("-", |commit| &commit[..commit.len().min(8)])
+    }
+}
+
+/// Ajoute une entrée en fin d'historique (fichier et répertoires créés au besoin)
+pub fn append(path: &Path, entry: &HistoryEntry) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| format!("Impossible de créer {}: {}", parent.display(), e))?;
+    }
+    let line = serde_json::to_string(entry).map_err(|e| format!("Erreur lors de la sérialisation de l'historique: {}", e))?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", line))
+        .map_err(|e| format!("Impossible d'écrire l'historique {}: {}", path.display(), e))
+}
+
+/// Entrées de l'historique, de la plus ancienne à la plus récente
+pub fn load(path: &Path) -> Result<Vec<HistoryEntry>, String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Impossible de lire l'historique {}: {}", path.display(), e))?;
+    let mut entries = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str::<HistoryEntry>(line)
+                .map_err(|e| format!("Historique invalide {}, ligne {}: {}", path.display(), index + 1, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.timestamp);
+    Ok(entries)
+}
+
+/// Évolution d'un fichier entre deux analyses (`None` : absent ou sans criticité)
+#[derive(Debug, Clone, Serialize)]
+pub struct FileTrend {
+    pub file: String,
+    pub old: Option<FileHealth>,
+    pub new: Option<FileHealth>,
+}
+
+impl FileTrend {
+    /// Variation du score de criticité (négative : le fichier s'est amélioré)
+    pub fn delta(&self) -> f64 {
+        let score = |health: Option<FileHealth>| health.map_or(0.0, |health| health.criticality_score);
+        score(self.new) - score(self.old)
+    }
+}
+
+/// Fichiers améliorés puis dégradés entre deux analyses, les plus fortes variations d'abord
+pub fn file_trends(old: &HistoryEntry, new: &HistoryEntry) -> (Vec<FileTrend>, Vec<FileTrend>) {
+    let mut files: BTreeMap<&str, (Option<FileHealth>, Option<FileHealth>)> = BTreeMap::new();
+    for (file, health) in &old.files {
+        files.entry(file).or_default().0 = Some(*health);
+    }
+    for (file, health) in &new.files {
+        files.entry(file).or_default().1 = Some(*health);
+    }
+
+    let mut trends: Vec<FileTrend> = files
+        .into_iter()
+        .map(|(file, (old, new))| FileTrend { file: file.to_string(), old, new })
+        .filter(|trend| trend.delta().abs() >= 0.05)
+        .collect();
+    trends.sort_by(|a, b| b.delta().abs().total_cmp(&a.delta().abs()));
+    trends.into_iter().partition(|trend| trend.delta() < 0.0)
+}
+
+/// Date UTC `AAAA-MM-JJ HH:MM` d'un horodatage Unix
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let (hours, minutes) = (timestamp % 86_400 / 3600, timestamp % 3600 / 60);
+
+    // Conversion jours → date civile (algorithme de H. Hinnant)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, hours, minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::{CLOSURE_SELF_CAPTURE, STRONG_DELEGATE, WEAK_DELEGATE};
+
+    #[test]
+    fn test_append_load_and_file_trends() {
+        let path = std::env::temp_dir().join(format!("swift-analyzer-history-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let before = Analyzer::new(AnalysisConfig::default())
+            .with_source("App/Editor.swift", STRONG_DELEGATE)
+            .with_source("App/DeckLoader.swift", "class DeckLoader {}\n")
+            .run();
+        let after = Analyzer::new(AnalysisConfig::default())
+            .with_source("App/Editor.swift", WEAK_DELEGATE)
+            .with_source("App/DeckLoader.swift", CLOSURE_SELF_CAPTURE)
+            .run();
+        append(&path, &HistoryEntry::from_report(&after, Path::new(""), 1_760_800_000, Some("b".repeat(40)))).unwrap();
+        append(&path, &HistoryEntry::from_report(&before, Path::new(""), 1_760_700_000, None)).unwrap();
+
+        let entries = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].short_commit(), entries[1].short_commit()), ("-", "bbbbbbbb"));
+        assert_eq!(format_timestamp(entries[1].timestamp), "2025-10-18 15:06");
+
+        let (improved, worsened) = file_trends(&entries[0], &entries[1]);
+        assert_eq!(improved[0].file, "App/Editor.swift");
+        assert_eq!(worsened[0].file, "App/DeckLoader.swift");
+    }
+}
@@ -0,0 +1,33 @@
+//! Bibliothèque partagée des analyseurs Swift : modèle de problèmes, découverte
+//! des fichiers, règles d'analyse et générateurs de rapports.
+
+pub mod analyzer;
+pub mod analyzers;
+pub mod baseline;
+pub mod cache;
+pub mod call_graph;
+pub mod churn;
+pub mod clone_detector;
+pub mod core_data_model;
+pub mod dead_code_analyzer;
+pub mod discovery;
+pub mod fetch_request_analyzer;
+pub mod fingerprint;
+pub mod git;
+pub mod history;
+pub mod main_thread_analyzer;
+pub mod models;
+pub mod ownership;
+pub mod project_index;
+pub mod quality_gate;
+pub mod report_diff;
+pub mod reporters;
+pub mod resource_analyzer;
+pub mod rules;
+pub mod suppression;
+pub mod swift_source;
+pub mod swiftui_analyzer;
+#[cfg(test)]
+mod test_support;
+pub mod types;
+pub mod watch;
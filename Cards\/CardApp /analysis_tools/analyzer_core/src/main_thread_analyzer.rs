@@ -0,0 +1,419 @@
+use crate::call_graph::CallGraph;
+use crate::models::{
+    AnalysisConfig, CostContribution, EntryPointKind, FileIssue, IssueType, MainThreadEstimate, Severity,
+};
+use crate::project_index::{FunctionId, IndexedFile, ProjectIndex};
+use crate::rules::{AnalysisLevel, RuleCategory, RuleExplanation, RuleMetadata};
+use crate::swift_source;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Facteur appliqué aux opérations exécutées dans une boucle
+const LOOP_MULTIPLIER: u32 = 5;
+
+/// Méthodes du cycle de vie UIKit exécutées sur le thread principal
+const UIKIT_LIFECYCLE_METHODS: &[&str] = &[
+    "viewDidLoad",
+    "viewWillAppear",
+    "viewDidAppear",
+    "viewWillLayoutSubviews",
+    "viewDidLayoutSubviews",
+    "layoutSubviews",
+    "draw",
+    "awakeFromNib",
+    "traitCollectionDidChange",
+    "tableView",
+    "collectionView",
+    "scrollViewDidScroll",
+    "sceneWillEnterForeground",
+    "sceneDidBecomeActive",
+    "applicationDidBecomeActive",
+];
+
+lazy_static! {
+    static ref EXPENSIVE_OPERATIONS: Vec<(Regex, &'static str, u32)> = vec![
+        (Regex::new(r"\.fetch\s*\(").unwrap(), "fetch CoreData", 10),
+        (Regex::new(r"\.count\s*\(\s*for\s*:").unwrap(), "count CoreData", 5),
+        (Regex::new(r"\.save\s*\(\s*\)").unwrap(), "save CoreData", 8),
+        (Regex::new(r"\bData\s*\(\s*contentsOf\s*:").unwrap(), "Data(contentsOf:)", 10),
+        (Regex::new(r"\bString\s*\(\s*contentsOf(?:File)?\s*:").unwrap(), "String(contentsOf:)", 8),
+        (Regex::new(r"\.decode\s*\(").unwrap(), "décodage JSON", 6),
+        (Regex::new(r"\bJSONDecoder\s*\(\s*\)").unwrap(), "création de JSONDecoder", 2),
+        (Regex::new(r"\bUIImage\s*\(\s*(?:data|contentsOfFile)\s*:|\bNSImage\s*\(\s*(?:data|contentsOf)\s*:|\bCGImageSourceCreate").unwrap(), "décodage d'image", 8),
+        (Regex::new(r"\bNSRegularExpression\s*\(|\bRegex\s*\(").unwrap(), "compilation d'expression régulière", 5),
+        (Regex::new(r"\b(?:Date|Number|ISO8601Date)Formatter\s*\(\s*\)").unwrap(), "création de formateur", 3),
+    ];
+
+    static ref MAIN_QUEUE_BLOCK_PATTERN: Regex = Regex::new(
+        r"DispatchQueue\.main\.(?:a?sync|asyncAfter)\b[^{]*\{|\bMainActor\.run\s*\{|\bTask\s*\{\s*@MainActor"
+    ).unwrap();
+
+    static ref BACKGROUND_BLOCK_PATTERN: Regex = Regex::new(
+        r"DispatchQueue\.global\b[^{]*\{|\bTask\.detached\b[^{]*\{|\bperformBackgroundTask\s*\{"
+    ).unwrap();
+
+    static ref LOOP_PATTERN: Regex = Regex::new(
+        r"^\s*(?:for\s+.+\s+in\s+.+|while\s+.+)\{|\.forEach\s*\{"
+    ).unwrap();
+}
+
+/// Point d'entrée exécuté sur le thread principal
+struct EntryPoint {
+    name: String,
+    kind: EntryPointKind,
+    file: usize,
+    line_start: usize,
+    line_end: usize,
+}
+
+/// Estimateur du travail effectué sur le thread principal, appels de fonctions du projet compris
+pub struct MainThreadAnalyzer {
+    config: AnalysisConfig,
+}
+
+impl MainThreadAnalyzer {
+    pub const RULE: RuleMetadata = RuleMetadata::new(
+        "performance.main-thread-cost",
+        RuleCategory::Performance,
+        Severity::High,
+        "Point d'entrée du thread principal dont le coût estimé (appels compris) dépasse le seuil",
+    )
+    .with_level(AnalysisLevel::Project)
+    .with_explanation(RuleExplanation {
+        rationale: "Les points d'entrée exécutés sur le thread principal (body, cycle de vie UIKit, actions, méthodes @MainActor) sont estimés en additionnant le coût de leurs opérations et de celles des fonctions du projet qu'ils appellent. Au-delà du seuil (--main-thread-threshold), le travail risque de faire manquer des images.",
+        bad_example: "@MainActor\nfunc refresh() {\n    let cards = repository.loadAll()          // fetch + décodage\n    state.stats = StatisticsBuilder(cards).build()\n}",
+        good_example: "@MainActor\nfunc refresh() async {\n    let stats = await Task.detached { StatisticsBuilder(repository.loadAll()).build() }.value\n    updateState { $0.stats = stats }\n}",
+        conventions: &["6.1 Utilisation de `async/await`", "6.3 Synchronisation de l'Interface Utilisateur"],
+    });
+
+    pub fn new(config: AnalysisConfig) -> Self {
+        MainThreadAnalyzer { config }
+    }
+
+    /// Estime le coût de chaque point d'entrée du thread principal, du plus coûteux au moins coûteux
+    pub fn analyze(&self, index: &ProjectIndex, graph: &CallGraph) -> Vec<MainThreadEstimate> {
+        let mut walker = CostWalker {
+            index,
+            graph,
+            background_ranges: index.files.iter().map(background_ranges).collect(),
+            memo: HashMap::new(),
+            in_progress: HashMap::new(),
+            cycle_depth: None,
+        };
+
+        let mut estimates: Vec<MainThreadEstimate> = find_entry_points(index)
+            .into_iter()
+            .filter_map(|entry| {
+                let operations = walker.scan(entry.file, entry.line_start, entry.line_end);
+                let estimated_cost: u32 = operations.iter().map(|o| o.cost).sum();
+                if estimated_cost == 0 {
+                    return None;
+                }
+                Some(MainThreadEstimate {
+                    entry_point: entry.name,
+                    kind: entry.kind,
+                    file_path: index.files[entry.file].path.clone(),
+                    line: entry.line_start + 1,
+                    estimated_cost,
+                    operations,
+                })
+            })
+            .collect();
+
+        estimates.sort_by_key(|e| std::cmp::Reverse(e.estimated_cost));
+        estimates
+    }
+
+    /// Convertit les estimations dépassant le seuil configuré en problèmes rattachés à leur fichier
+    pub fn issues(&self, estimates: &[MainThreadEstimate]) -> Vec<(PathBuf, FileIssue)> {
+        let threshold = self.config.main_thread_cost_threshold.max(1);
+
+        estimates
+            .iter()
+            .filter(|e| e.estimated_cost >= threshold)
+            .map(|estimate| {
+                let severity = if estimate.estimated_cost >= threshold * 4 {
+                    Severity::Critical
+                } else if estimate.estimated_cost >= threshold * 2 {
+                    Severity::High
+                } else {
+                    Severity::Medium
+                };
+
+                let mut operations: Vec<&CostContribution> = estimate.operations.iter().collect();
+                operations.sort_by_key(|o| std::cmp::Reverse(o.cost));
+                let summary = operations
+                    .iter()
+                    .take(3)
+                    .map(|o| format!("{} ({})", o.operation, o.cost))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let issue = FileIssue {
+                    issue_type: IssueType::ExcessiveComputation,
+                    severity,
+                    line: estimate.line,
+                    column: None,
+                    message: format!(
+                        "Coût estimé de {} sur le thread principal pour '{}' (seuil {}) : {}",
+                        estimate.estimated_cost, estimate.entry_point, threshold, summary
+                    ),
+                    suggestion: Some(
+                        "Déplacer les opérations coûteuses sur un contexte ou une tâche d'arrière-plan et ne revenir sur le thread principal que pour mettre à jour l'interface".to_string(),
+                    ),
+                    code_snippet: None,
+                    rule_id: None,
+                    declaration: None,
+                    ownership: None,
+                    fix: None,
+                };
+                (estimate.file_path.clone(), issue)
+            })
+            .collect()
+    }
+}
+
+/// Parcours mémoïsé des corps de fonctions et de leurs appels
+struct CostWalker<'a> {
+    index: &'a ProjectIndex,
+    graph: &'a CallGraph,
+    background_ranges: Vec<Vec<(usize, usize)>>,
+    memo: HashMap<FunctionId, Vec<CostContribution>>,
+    /// Fonctions en cours d'évaluation, avec leur profondeur dans la pile d'appels
+    in_progress: HashMap<FunctionId, usize>,
+    /// Profondeur de l'appel en cours le plus haut atteint par un cycle depuis l'évaluation courante
+    cycle_depth: Option<usize>,
+}
+
+impl CostWalker<'_> {
+    /// Opérations coûteuses d'une plage de lignes, appels de fonctions du projet inclus
+    fn scan(&mut self, file: usize, start: usize, end: usize) -> Vec<CostContribution> {
+        let index = self.index;
+        let indexed = &index.files[file];
+        let loops = loop_ranges(indexed, start, end);
+        let mut contributions = Vec::new();
+
+        for i in start..=end.min(indexed.lines.len().saturating_sub(1)) {
+            if self.background_ranges[file].iter().any(|&(s, e)| i > s && i <= e) {
+                continue;
+            }
+            let line = &indexed.lines[i];
+            let multiplier = if loops.iter().any(|&(s, e)| i > s && i <= e) { LOOP_MULTIPLIER } else { 1 };
+
+            for (pattern, operation, cost) in EXPENSIVE_OPERATIONS.iter() {
+                for _ in pattern.find_iter(line) {
+                    contributions.push(CostContribution {
+                        operation: operation.to_string(),
+                        file_path: indexed.path.clone(),
+                        line: i + 1,
+                        cost: cost * multiplier,
+                        call_chain: Vec::new(),
+                    });
+                }
+            }
+
+            // Un appel résolu vers plusieurs implémentations (protocole) compte pour la plus coûteuse
+            let graph = self.graph;
+            let mut calls: HashMap<usize, Vec<FunctionId>> = HashMap::new();
+            for edge in graph.calls_at(file, i) {
+                calls.entry(edge.column).or_default().push(edge.callee);
+            }
+            let mut columns: Vec<usize> = calls.keys().copied().collect();
+            columns.sort_unstable();
+
+            for column in columns {
+                let Some((callee, callee_contributions)) = calls[&column]
+                    .iter()
+                    .map(|&callee| (callee, self.function_cost(callee)))
+                    .max_by_key(|(_, c)| c.iter().map(|o| o.cost).sum::<u32>())
+                else {
+                    continue;
+                };
+                let callee_name = self.index.function(callee).qualified_name();
+                for mut contribution in callee_contributions {
+                    contribution.cost *= multiplier;
+                    contribution.call_chain.insert(0, callee_name.clone());
+                    contributions.push(contribution);
+                }
+            }
+        }
+
+        contributions
+    }
+
+    fn function_cost(&mut self, id: FunctionId) -> Vec<CostContribution> {
+        if let Some(cached) = self.memo.get(&id) {
+            return cached.clone();
+        }
+        // Appel récursif : le coût est déjà compté par l'appelant
+        if let Some(&depth) = self.in_progress.get(&id) {
+            self.cycle_depth = Some(self.cycle_depth.map_or(depth, |d| d.min(depth)));
+            return Vec::new();
+        }
+
+        let depth = self.in_progress.len();
+        self.in_progress.insert(id, depth);
+        let outer_cycle_depth = self.cycle_depth.take();
+        let function = self.index.function(id);
+        let contributions = self.scan(id.file, function.line_start, function.line_end);
+        self.in_progress.remove(&id);
+
+        // Un cycle qui remonte au-dessus de cette fonction a omis le coût d'un appelant :
+        // le résultat ne vaut que pour ce chemin et n'est pas mémoïsé
+        let open_cycle_depth = self.cycle_depth.filter(|&d| d < depth);
+        if open_cycle_depth.is_none() {
+            self.memo.insert(id, contributions.clone());
+        }
+        self.cycle_depth = outer_cycle_depth.into_iter().chain(open_cycle_depth).min();
+        contributions
+    }
+}
+
+/// Repère les points d'entrée du thread principal dans tout le projet
+fn find_entry_points(index: &ProjectIndex) -> Vec<EntryPoint> {
+    let mut entries = Vec::new();
+
+    for (file_index, file) in index.files.iter().enumerate() {
+        for property in swift_source::find_view_properties(&file.lines, &file.declarations) {
+            let owner = swift_source::innermost_type_at(&file.declarations, property.line_start)
+                .map(|d| d.name.clone());
+            entries.push(EntryPoint {
+                name: format!("{}.{}", owner.as_deref().unwrap_or("?"), property.name),
+                kind: EntryPointKind::SwiftUIBody,
+                file: file_index,
+                line_start: property.line_start,
+                line_end: property.line_end,
+            });
+        }
+
+        for function in &file.functions {
+            let owner_is_main_actor = function
+                .owner
+                .as_ref()
+                .map(|owner| {
+                    file.declarations
+                        .iter()
+                        .any(|d| &d.name == owner && d.has_attribute("@MainActor"))
+                })
+                .unwrap_or(false);
+
+            let kind = if function.has_attribute("@MainActor") || owner_is_main_actor {
+                Some(EntryPointKind::MainActor)
+            } else if function.owner.is_some() && UIKIT_LIFECYCLE_METHODS.contains(&function.name.as_str()) {
+                Some(EntryPointKind::UIKitLifecycle)
+            } else {
+                None
+            };
+
+            if let Some(kind) = kind {
+                entries.push(EntryPoint {
+                    name: function.qualified_name(),
+                    kind,
+                    file: file_index,
+                    line_start: function.line_start,
+                    line_end: function.line_end,
+                });
+            }
+        }
+
+        for (i, line) in file.lines.iter().enumerate() {
+            if MAIN_QUEUE_BLOCK_PATTERN.is_match(line) {
+                let enclosing = file
+                    .functions
+                    .iter()
+                    .filter(|f| i >= f.line_start && i <= f.line_end)
+                    .min_by_key(|f| f.line_end - f.line_start);
+                entries.push(EntryPoint {
+                    name: format!(
+                        "DispatchQueue.main ({}:{})",
+                        enclosing.map(|f| f.qualified_name()).unwrap_or_else(|| "global".to_string()),
+                        i + 1
+                    ),
+                    kind: EntryPointKind::MainQueueBlock,
+                    file: file_index,
+                    line_start: i,
+                    line_end: swift_source::find_block_end(&file.lines, i),
+                });
+            }
+        }
+    }
+
+    entries
+}
+
+fn background_ranges(file: &IndexedFile) -> Vec<(usize, usize)> {
+    file.lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| BACKGROUND_BLOCK_PATTERN.is_match(line))
+        .map(|(i, _)| (i, swift_source::find_block_end(&file.lines, i)))
+        .collect()
+}
+
+fn loop_ranges(file: &IndexedFile, start: usize, end: usize) -> Vec<(usize, usize)> {
+    (start..=end.min(file.lines.len().saturating_sub(1)))
+        .filter(|&i| LOOP_PATTERN.is_match(&file.lines[i]))
+        .map(|i| (i, swift_source::find_block_end(&file.lines, i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_index::IndexedFile;
+
+    #[test]
+    fn test_cost_follows_project_calls() {
+        let view = "import SwiftUI\nstruct DeckView: View {\n    let store: DeckStore\n    var body: some View {\n        Text(store.loadTitle())\n    }\n}";
+        let store = "final class DeckStore {\n    func loadTitle() -> String {\n        let decks = try? context.fetch(request)\n        return decks?.first?.name ?? \"\"\n    }\n}";
+        let index = ProjectIndex::from_files(vec![
+            IndexedFile::new(PathBuf::from("DeckView.swift"), view.to_string()),
+            IndexedFile::new(PathBuf::from("DeckStore.swift"), store.to_string()),
+        ]);
+
+        let estimates = MainThreadAnalyzer::new(AnalysisConfig::default()).analyze(&index, &CallGraph::build(&index));
+
+        assert_eq!(estimates.len(), 1);
+        assert_eq!(estimates[0].entry_point, "DeckView.body");
+        assert_eq!(estimates[0].estimated_cost, 10);
+        assert_eq!(estimates[0].operations[0].call_chain, vec!["DeckStore.loadTitle"]);
+    }
+
+    #[test]
+    fn test_cycle_member_cost_not_memoized_partially() {
+        let views = "import SwiftUI\nstruct DeckView: View {\n    let store: DeckStore\n    var body: some View {\n        Text(store.load())\n    }\n}\nstruct StatsView: View {\n    let store: DeckStore\n    var body: some View {\n        Text(store.normalize())\n    }\n}";
+        let store = "final class DeckStore {\n    func load() -> String {\n        let decks = try? context.fetch(request)\n        return normalize()\n    }\n    func normalize() -> String {\n        return dirty ? load() : \"\"\n    }\n}";
+        let index = ProjectIndex::from_files(vec![
+            IndexedFile::new(PathBuf::from("Views.swift"), views.to_string()),
+            IndexedFile::new(PathBuf::from("DeckStore.swift"), store.to_string()),
+        ]);
+
+        let estimates = MainThreadAnalyzer::new(AnalysisConfig::default()).analyze(&index, &CallGraph::build(&index));
+
+        let chains: Vec<(&str, u32, Vec<String>)> = estimates
+            .iter()
+            .map(|e| (e.entry_point.as_str(), e.estimated_cost, e.operations[0].call_chain.clone()))
+            .collect();
+        assert_eq!(
+            chains,
+            [
+                ("DeckView.body", 10, vec!["DeckStore.load".to_string()]),
+                ("StatsView.body", 10, vec!["DeckStore.normalize".to_string(), "DeckStore.load".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_background_blocks_are_excluded() {
+        let content = "@MainActor\nfinal class ImportViewModel {\n    func load() {\n        DispatchQueue.global().async {\n            let data = try? Data(contentsOf: url)\n        }\n        for url in urls {\n            _ = try? Data(contentsOf: url)\n        }\n    }\n}";
+        let index = ProjectIndex::from_files(vec![IndexedFile::new(PathBuf::from("Import.swift"), content.to_string())]);
+
+        let estimates = MainThreadAnalyzer::new(AnalysisConfig::default()).analyze(&index, &CallGraph::build(&index));
+
+        assert_eq!(estimates.len(), 1);
+        assert_eq!(estimates[0].kind, EntryPointKind::MainActor);
+        assert_eq!(estimates[0].estimated_cost, 10 * LOOP_MULTIPLIER);
+    }
+}
@@ -0,0 +1,417 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+
+/// Niveau de sévérité d'un problème
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 4] = [Severity::Critical, Severity::High, Severity::Medium, Severity::Low];
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Severity::ALL
+            .into_iter()
+            .find(|severity| format!("{:?}", severity).eq_ignore_ascii_case(value))
+            .ok_or_else(|| format!("Sévérité inconnue '{}' (sévérités : Critical, High, Medium, Low)", value))
+    }
+}
+
+/// Type de problème détecté
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum IssueType {
+    HighComplexity,
+    DeepNesting,
+    UnsafeClosure,
+    CoreDataMainThread,
+    MissingErrorHandling,
+    PotentialDataRace,
+    InefficientCollection,
+    MemoryLeak,
+    ResourceLeak,
+    HighCoupling,
+    ExcessiveComputation,
+    // Problèmes SwiftUI (voir `types::SwiftUIIssueType`)
+    ExcessiveViewUpdates,
+    InefficientBinding,
+    MissingViewModifier,
+    RedundantViewModifier,
+    HeavyViewComputation,
+    // Problèmes CoreData (voir `types::CoreDataIssueType`)
+    MissingIndex,
+    InefficientFetch,
+    BatchingOpportunity,
+    RedundantSaves,
+    MissingFetchedResultsController,
+    UnusedCode,
+}
+
+/// Configuration pour l'analyse (seuils ; le choix des règles relève de `rules::RuleRegistry`)
+#[derive(Debug, Clone)]
+pub struct AnalysisConfig {
+    pub cyclomatic_complexity_threshold: u32,
+    pub nesting_depth_threshold: u32,
+    pub min_severity: Severity,
+    pub main_thread_cost_threshold: u32,
+    /// Longueur minimale (en jetons) d'un fragment dupliqué
+    pub clone_min_tokens: usize,
+}
+
+impl Default for AnalysisConfig {
+    fn default() -> Self {
+        AnalysisConfig {
+            cyclomatic_complexity_threshold: 10,
+            nesting_depth_threshold: 3,
+            min_severity: Severity::Low,
+            main_thread_cost_threshold: 20,
+            clone_min_tokens: 50,
+        }
+    }
+}
+
+/// Métadonnées et métriques d'un fichier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileMetrics {
+    pub functions_count: usize,
+    pub classes_count: usize,
+    pub max_function_size: usize,
+    pub max_class_size: usize,
+    pub max_cyclomatic_complexity: f64,
+    pub avg_cyclomatic_complexity: f64,
+    pub max_nesting_depth: u32,
+    pub avg_nesting_depth: f64,
+    #[serde(default)]
+    pub self_capture_count: usize,
+    #[serde(default)]
+    pub core_data_operation_count: usize,
+    #[serde(default)]
+    pub async_operation_count: usize,
+    #[serde(default)]
+    pub concurrency_primitive_count: usize,
+}
+
+impl Default for FileMetrics {
+    fn default() -> Self {
+        FileMetrics {
+            functions_count: 0,
+            classes_count: 0,
+            max_function_size: 0,
+            max_class_size: 0,
+            max_cyclomatic_complexity: 0.0,
+            avg_cyclomatic_complexity: 0.0,
+            max_nesting_depth: 0,
+            avg_nesting_depth: 0.0,
+            self_capture_count: 0,
+            core_data_operation_count: 0,
+            async_operation_count: 0,
+            concurrency_primitive_count: 0,
+        }
+    }
+}
+
+/// Problème détecté dans un fichier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileIssue {
+    pub issue_type: IssueType,
+    pub severity: Severity,
+    pub line: usize,
+    /// Colonne en octets depuis le début de la ligne (indexée à partir de 0)
+    pub column: Option<usize>,
+    pub message: String,
+    pub suggestion: Option<String>,
+    pub code_snippet: Option<String>,
+    /// Règle à l'origine du problème (renseignée par `Analyzer`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    /// Fonction (`Type.fonction`) ou type englobant la ligne du problème (renseigné par `Analyzer`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub declaration: Option<String>,
+    /// Auteur de la ligne et propriétaires du fichier (`--blame`, `--codeowners`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ownership: Option<Ownership>,
+    /// Correction applicable telle quelle, quand la suggestion est mécanique (`fixes` du rapport SARIF)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Replacement>,
+}
+
+/// Remplacement d'une partie de la ligne d'un problème
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replacement {
+    /// Début de la partie remplacée, en octets depuis le début de la ligne (indexé à partir de 0)
+    pub column: usize,
+    /// Longueur en octets de la partie remplacée (0 pour une insertion)
+    pub length: usize,
+    pub text: String,
+}
+
+/// Attribution d'un problème
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ownership {
+    /// Auteur du dernier commit ayant modifié la ligne (`git blame`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Propriétaires du fichier selon CODEOWNERS (`@equipe`, adresse e-mail)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+}
+
+/// Résultat d'analyse d'un fichier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisResult {
+    pub file_path: PathBuf,
+    pub file_size: u64,
+    pub line_count: usize,
+    pub metrics: FileMetrics,
+    pub issues: Vec<FileIssue>,
+    /// Problèmes ignorés par un commentaire `swift-analyzer:ignore` (hors compteurs et score)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed_issues: Vec<FileIssue>,
+    /// Problèmes déjà présents dans la ligne de base (`--baseline`, hors compteurs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub baseline_issues: Vec<FileIssue>,
+}
+
+/// Structure pour représenter un fichier problématique
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotspotFile {
+    pub file_path: PathBuf,
+    pub issue_count: usize,
+    pub criticality_score: f64,
+}
+
+/// Fichier classé par fréquence de modification × complexité, d'après l'historique git
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChurnHotspot {
+    pub file_path: PathBuf,
+    /// Commits ayant modifié le fichier
+    pub commits: usize,
+    /// Auteurs distincts de ces commits
+    pub authors: usize,
+    /// Lignes ajoutées et supprimées pendant la période récente
+    pub recent_churn: usize,
+    /// Somme des complexités cyclomatiques des fonctions du fichier
+    pub complexity: f64,
+    /// `commits × complexity`
+    pub score: f64,
+}
+
+/// Statistiques globales du projet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectStats {
+    pub total_files: usize,
+    pub problematic_files: usize,
+    pub total_lines: usize,
+    pub health_score: f64,
+    /// Pourcentage des lignes appartenant à un fragment dupliqué
+    #[serde(default)]
+    pub duplication_percentage: f64,
+}
+
+/// Type de point d'entrée exécuté sur le thread principal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EntryPointKind {
+    SwiftUIBody,
+    MainActor,
+    UIKitLifecycle,
+    MainQueueBlock,
+}
+
+/// Opération coûteuse atteinte depuis un point d'entrée du thread principal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostContribution {
+    pub operation: String,
+    pub file_path: PathBuf,
+    pub line: usize,
+    pub cost: u32,
+    /// Fonctions traversées depuis le point d'entrée (vide si l'opération est directe)
+    pub call_chain: Vec<String>,
+}
+
+/// Estimation du travail effectué sur le thread principal par un point d'entrée
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MainThreadEstimate {
+    pub entry_point: String,
+    pub kind: EntryPointKind,
+    pub file_path: PathBuf,
+    pub line: usize,
+    pub estimated_cost: u32,
+    pub operations: Vec<CostContribution>,
+}
+
+/// Genre de déclaration inutilisée
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeadCodeKind {
+    Function,
+    Type,
+    Property,
+    ProtocolMember,
+    /// Protocole sans aucun type conforme
+    UnimplementedProtocol,
+    /// Fichier dont aucune déclaration n'est référencée
+    File,
+}
+
+/// Niveau de confiance d'un résultat (la réflexion et les sélecteurs peuvent masquer des usages)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+}
+
+/// Déclaration sans référence dans le projet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadCodeFinding {
+    pub kind: DeadCodeKind,
+    pub name: String,
+    pub file_path: PathBuf,
+    pub line: usize,
+    pub confidence: Confidence,
+    pub reason: String,
+}
+
+/// Type de clone : copie exacte ou copie aux identifiants et littéraux près
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CloneType {
+    Type1,
+    Type2,
+}
+
+/// Occurrence d'un fragment dupliqué
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneLocation {
+    pub file_path: PathBuf,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub code: String,
+}
+
+/// Groupe de fragments identiques après normalisation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneGroup {
+    pub clone_type: CloneType,
+    pub token_count: usize,
+    pub locations: Vec<CloneLocation>,
+}
+
+/// Rapport complet de l'analyse de performance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceReport {
+    /// Racine du projet analysé, à partir de laquelle les chemins sont relatifs (renseignée par l'appelant)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_root: Option<PathBuf>,
+    pub files_analyzed: Vec<AnalysisResult>,
+    pub issue_count_by_type: HashMap<IssueType, usize>,
+    pub issue_count_by_severity: HashMap<Severity, usize>,
+    pub hotspots: Vec<HotspotFile>,
+    /// Points chauds selon l'historique git (`--churn`), du score le plus élevé au plus faible
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub churn_hotspots: Vec<ChurnHotspot>,
+    pub project_stats: ProjectStats,
+    #[serde(default)]
+    pub main_thread_estimates: Vec<MainThreadEstimate>,
+    #[serde(default)]
+    pub dead_code: Vec<DeadCodeFinding>,
+    #[serde(default)]
+    pub clones: Vec<CloneGroup>,
+    /// Comparaison avec la ligne de base, quand `--baseline` est fourni
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<BaselineSummary>,
+    /// Problèmes par propriétaire et par auteur, quand `--codeowners` ou `--blame` est fourni
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ownership: Option<OwnershipSummary>,
+    /// Utilisation du cache d'analyse, quand il est activé
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheStats>,
+    /// Résultat de la porte qualité, quand des conditions sont configurées
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality_gate: Option<QualityGateResult>,
+    /// Erreurs sans effet sur les résultats (fichier illisible, cache non enregistré), à afficher par l'appelant
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Problèmes signalés attribués à un propriétaire ou à un auteur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnerSummary {
+    pub name: String,
+    /// Fichiers contenant au moins un de ces problèmes
+    pub files: usize,
+    pub issues: usize,
+    pub issues_by_severity: BTreeMap<Severity, usize>,
+}
+
+/// Répartition des problèmes signalés par propriétaire (CODEOWNERS) et par auteur (`git blame`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OwnershipSummary {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<OwnerSummary>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<OwnerSummary>,
+}
+
+/// Utilisation du cache d'analyse lors d'une exécution
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Fichiers dont le résultat d'analyse a été réutilisé
+    pub hits: usize,
+    /// Fichiers analysés (absents du cache ou modifiés)
+    pub misses: usize,
+    /// Fichiers indexés pour les règles du projet à partir du cache
+    pub index_hits: usize,
+    pub index_misses: usize,
+}
+
+/// Condition de la porte qualité évaluée sur un rapport
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateCondition {
+    /// Identifiant stable (`max-critical`, `min-health-score`…)
+    pub id: String,
+    pub description: String,
+    pub threshold: String,
+    pub actual: String,
+    pub passed: bool,
+    /// Éléments en cause (fichiers, problèmes), vide si la condition est remplie
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+}
+
+/// Résultat de la porte qualité : échouée dès qu'une condition n'est pas remplie
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityGateResult {
+    pub passed: bool,
+    pub conditions: Vec<GateCondition>,
+}
+
+/// Problème enregistré dans une ligne de base
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub rule_id: String,
+    /// Chemin relatif à la racine du projet
+    pub file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub declaration: Option<String>,
+    /// Ligne lors de la création, à titre indicatif (hors empreinte)
+    pub line: usize,
+    pub message: String,
+}
+
+/// Classement des problèmes par rapport à la ligne de base
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BaselineSummary {
+    pub new: usize,
+    pub unchanged: usize,
+    /// Problèmes de la ligne de base qui ne sont plus détectés
+    pub fixed: Vec<BaselineEntry>,
+} 
\ No newline at end of file
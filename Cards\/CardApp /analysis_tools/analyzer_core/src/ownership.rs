@@ -0,0 +1,260 @@
+//! Attribution des problèmes : auteur de la ligne (`git blame` local) et
+//! propriétaires du fichier (CODEOWNERS), puis synthèse par propriétaire et par auteur
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use git2::{Blame, Repository};
+use regex::Regex;
+
+use crate::discovery::relative_path;
+use crate::models::{FileIssue, OwnerSummary, Ownership, OwnershipSummary, PerformanceReport, Severity};
+
+/// Emplacements cherchés, dans l'ordre, à la racine du dépôt ou du projet
+pub const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Auteur des lignes pas encore validées
+pub const UNCOMMITTED_AUTHOR: &str = "(non validé)";
+
+/// Nom regroupant les problèmes des fichiers sans propriétaire
+pub const UNOWNED: &str = "(sans propriétaire)";
+
+/// Règles d'un fichier CODEOWNERS : la dernière règle correspondant à un chemin l'emporte
+#[derive(Debug, Clone)]
+pub struct CodeOwners {
+    /// Répertoire auquel les motifs sont relatifs
+    root: PathBuf,
+    rules: Vec<(Regex, Vec<String>)>,
+}
+
+impl CodeOwners {
+    /// Fichier CODEOWNERS ; ses motifs sont relatifs au dépôt (parent de `.github/` ou `docs/`, sinon son répertoire)
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Impossible de lire {}: {}", path.display(), e))?;
+        let directory = fs::canonicalize(path)
+            .map_err(|e| format!("Impossible de lire {}: {}", path.display(), e))?
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let root = match directory.file_name().and_then(|name| name.to_str()) {
+            Some(".github" | "docs") => directory.parent().map(Path::to_path_buf).unwrap_or(directory),
+            _ => directory,
+        };
+        Ok(CodeOwners::parse(&content, root))
+    }
+
+    /// Premier fichier CODEOWNERS trouvé à la racine du dépôt git contenant `project_root`, sinon à la racine du projet
+    pub fn discover(project_root: &Path) -> Option<PathBuf> {
+        let repository_root = Repository::discover(project_root).ok().and_then(|repo| repo.workdir().map(Path::to_path_buf));
+        repository_root
+            .into_iter()
+            .chain([project_root.to_path_buf()])
+            .flat_map(|root| CODEOWNERS_LOCATIONS.map(|location| root.join(location)))
+            .find(|path| path.is_file())
+    }
+
+    pub fn parse(content: &str, root: PathBuf) -> Self {
+        let rules = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let pattern = pattern_regex(fields.next()?)?;
+                let owners = fields.take_while(|field| !field.starts_with('#')).map(str::to_string).collect();
+                Some((pattern, owners))
+            })
+            .collect();
+        CodeOwners { root, rules }
+    }
+
+    /// Propriétaires d'un fichier (vide : aucun propriétaire, ou règle sans propriétaire)
+    pub fn owners_of(&self, path: &Path) -> &[String] {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let relative = relative_path(&self.root, &path);
+        self.rules
+            .iter()
+            .rev()
+            .find(|(pattern, _)| pattern.is_match(&relative))
+            .map_or(&[], |(_, owners)| owners.as_slice())
+    }
+}
+
+/// Motif CODEOWNERS (syntaxe gitignore) en expression régulière sur un chemin relatif
+fn pattern_regex(pattern: &str) -> Option<Regex> {
+    let directory = pattern.ends_with('/');
+    let trimmed = pattern.trim_matches('/');
+    // Motif sans `/` intérieur : valable à toute profondeur
+    let anchored = pattern.starts_with('/') || trimmed.contains('/');
+
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    // Un répertoire couvre tout son contenu
+    regex.push_str(if directory { "/.*$" } else { "(?:/.*)?$" });
+    Regex::new(&regex).ok()
+}
+
+/// Renseigne l'auteur de la ligne de chaque problème (signalé, ignoré ou de la ligne de base) d'après `git blame`
+///
+/// Les lignes modifiées depuis le dernier commit sont attribuées à `UNCOMMITTED_AUTHOR`.
+pub fn blame_authors(report: &mut PerformanceReport, project_root: &Path) -> Result<(), String> {
+    let repository = Repository::discover(project_root)
+        .map_err(|e| format!("Aucun dépôt git trouvé pour {}: {}", project_root.display(), e.message()))?;
+    let workdir = repository
+        .workdir()
+        .ok_or_else(|| format!("Le dépôt git de {} n'a pas de copie de travail", project_root.display()))?
+        .to_path_buf();
+
+    for result in &mut report.files_analyzed {
+        if result.issues.is_empty() && result.suppressed_issues.is_empty() && result.baseline_issues.is_empty() {
+            continue;
+        }
+        let path = fs::canonicalize(&result.file_path).unwrap_or_else(|_| result.file_path.clone());
+        let relative = path.strip_prefix(&workdir).unwrap_or(&path);
+        // Fichier non suivi : tout est non validé ; sinon blame de la copie de travail
+        let blame = repository.blame_file(relative, None).ok();
+        let content = fs::read(&path).unwrap_or_default();
+        let current = blame.as_ref().and_then(|blame| blame.blame_buffer(&content).ok());
+
+        let issues = result.issues.iter_mut().chain(&mut result.suppressed_issues).chain(&mut result.baseline_issues);
+        for issue in issues {
+            let author = current.as_ref().and_then(|blame| line_author(blame, issue.line));
+            ownership(issue).author = Some(author.unwrap_or_else(|| UNCOMMITTED_AUTHOR.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Auteur d'une ligne, `None` si elle n'est pas validée
+fn line_author(blame: &Blame, line: usize) -> Option<String> {
+    let hunk = blame.get_line(line)?;
+    // Hunk non validé : pas de signature
+    if hunk.final_commit_id().is_zero() {
+        return None;
+    }
+    let signature = hunk.final_signature();
+    signature.name().or(signature.email()).map(str::to_string)
+}
+
+/// Renseigne les propriétaires du fichier de chaque problème
+pub fn assign_owners(report: &mut PerformanceReport, codeowners: &CodeOwners) {
+    for result in &mut report.files_analyzed {
+        let owners = codeowners.owners_of(&result.file_path).to_vec();
+        let issues = result.issues.iter_mut().chain(&mut result.suppressed_issues).chain(&mut result.baseline_issues);
+        for issue in issues {
+            ownership(issue).owners = owners.clone();
+        }
+    }
+}
+
+fn ownership(issue: &mut FileIssue) -> &mut Ownership {
+    issue.ownership.get_or_insert_with(Ownership::default)
+}
+
+/// Problèmes signalés par propriétaire (`by_owner`, après `assign_owners`) et par auteur
+/// (`by_author`, après `blame_authors`), les plus chargés d'abord
+pub fn summarize(report: &PerformanceReport, by_owner: bool, by_author: bool) -> OwnershipSummary {
+    let mut owners: HashMap<&str, Tally> = HashMap::new();
+    let mut authors: HashMap<&str, Tally> = HashMap::new();
+
+    for result in &report.files_analyzed {
+        for issue in &result.issues {
+            let ownership = issue.ownership.as_ref();
+            if by_author {
+                let author = ownership.and_then(|o| o.author.as_deref()).unwrap_or(UNCOMMITTED_AUTHOR);
+                authors.entry(author).or_default().add(&result.file_path, issue.severity);
+            }
+            if by_owner {
+                let issue_owners = ownership.map_or(&[][..], |o| o.owners.as_slice());
+                if issue_owners.is_empty() {
+                    owners.entry(UNOWNED).or_default().add(&result.file_path, issue.severity);
+                }
+                for owner in issue_owners {
+                    owners.entry(owner).or_default().add(&result.file_path, issue.severity);
+                }
+            }
+        }
+    }
+
+    OwnershipSummary {
+        owners: summaries(owners),
+        authors: summaries(authors),
+    }
+}
+
+#[derive(Default)]
+struct Tally<'a> {
+    files: HashSet<&'a Path>,
+    issues_by_severity: BTreeMap<Severity, usize>,
+}
+
+impl<'a> Tally<'a> {
+    fn add(&mut self, file: &'a Path, severity: Severity) {
+        self.files.insert(file);
+        *self.issues_by_severity.entry(severity).or_insert(0) += 1;
+    }
+}
+
+fn summaries(tallies: HashMap<&str, Tally>) -> Vec<OwnerSummary> {
+    let mut summaries: Vec<OwnerSummary> = tallies
+        .into_iter()
+        .map(|(name, tally)| OwnerSummary {
+            name: name.to_string(),
+            files: tally.files.len(),
+            issues: tally.issues_by_severity.values().sum(),
+            issues_by_severity: tally.issues_by_severity,
+        })
+        .collect();
+    summaries.sort_by(|a, b| b.issues.cmp(&a.issues).then_with(|| a.name.cmp(&b.name)));
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+
+    #[test]
+    fn test_codeowners_patterns_and_summary() {
+        let codeowners = CodeOwners::parse(
+            "# Équipes\n* @cardapp/all\n/Core/Services/ @cardapp/core\nViews/ @cardapp/ui @design  # écrans\n*.xcdatamodeld @cardapp/data\nCore/Services/Legacy/\n",
+            PathBuf::from("/repo"),
+        );
+        let owners = |path: &str| codeowners.owners_of(Path::new(path)).join(" ");
+        assert_eq!(owners("/repo/Core/Services/Sync.swift"), "@cardapp/core");
+        assert_eq!(owners("/repo/App/Views/Deck/DeckView.swift"), "@cardapp/ui @design");
+        assert_eq!(owners("/repo/Core/Model.xcdatamodeld/contents"), "@cardapp/data");
+        assert_eq!(owners("/repo/Core/Services/Legacy/Old.swift"), "");
+        assert_eq!(owners("/repo/App/AppMain.swift"), "@cardapp/all");
+
+        let mut report = Analyzer::new(AnalysisConfig::default())
+            .with_source("/repo/Core/Services/Sync.swift", "class Sync {\n    var delegate: SyncDelegate?\n}\n")
+            .with_source("/repo/Core/Services/Legacy/Old.swift", "class Old {\n    var delegate: OldDelegate?\n}\n")
+            .run();
+        assign_owners(&mut report, &codeowners);
+        let summary = summarize(&report, true, false);
+        let names: Vec<&str> = summary.owners.iter().map(|owner| owner.name.as_str()).collect();
+        assert!(names.contains(&"@cardapp/core") && names.contains(&UNOWNED));
+        assert!(summary.authors.is_empty());
+        assert_eq!(summary.owners.iter().map(|owner| owner.files).sum::<usize>(), 2);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::swift_source::{self, FunctionDeclaration, TypeDeclaration};
+use rayon::iter::Either;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Identifiant d'une fonction dans l'index : (indice du fichier, indice de la fonction)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FunctionId {
+    pub file: usize,
+    pub function: usize,
+}
+
+/// Fichier source indexé pour les analyses à l'échelle du projet
+#[derive(Debug, Clone)]
+pub struct IndexedFile {
+    pub path: PathBuf,
+    pub content: String,
+    /// Lignes sans commentaires ni contenu de chaînes (voir `swift_source::sanitize_lines`)
+    pub lines: Vec<String>,
+    pub declarations: Vec<TypeDeclaration>,
+    pub functions: Vec<FunctionDeclaration>,
+}
+
+impl IndexedFile {
+    pub fn new(path: PathBuf, content: String) -> Self {
+        let lines = swift_source::sanitize_lines(&content);
+        let declarations = swift_source::find_type_declarations(&lines);
+        let functions = swift_source::find_functions(&lines, &declarations);
+
+        IndexedFile {
+            path,
+            content,
+            lines,
+            declarations,
+            functions,
+        }
+    }
+
+    /// Lignes brutes, pour les extraits de code des rapports
+    pub fn raw_line(&self, line: usize) -> Option<&str> {
+        self.content.lines().nth(line)
+    }
+}
+
+/// Index des déclarations de tous les fichiers Swift du projet
+#[derive(Debug, Clone, Default)]
+pub struct ProjectIndex {
+    pub files: Vec<IndexedFile>,
+    functions_by_name: HashMap<String, Vec<FunctionId>>,
+    read_errors: Vec<String>,
+}
+
+impl ProjectIndex {
+    /// Lit et indexe les fichiers en parallèle ; les fichiers illisibles sont ignorés
+    pub fn build(paths: &[PathBuf]) -> Self {
+        let (files, read_errors): (Vec<IndexedFile>, Vec<String>) = paths
+            .par_iter()
+            .partition_map(|path| match fs::read_to_string(path) {
+                Ok(content) => Either::Left(IndexedFile::new(path.clone(), content)),
+                Err(e) => Either::Right(format!("Erreur lors de la lecture du fichier {}: {}", path.display(), e)),
+            });
+
+        ProjectIndex {
+            read_errors,
+            ..Self::from_files(files)
+        }
+    }
+
+    /// Fichiers qui n'ont pas pu être lus par `build`
+    pub fn read_errors(&self) -> &[String] {
+        &self.read_errors
+    }
+
+    pub fn from_files(files: Vec<IndexedFile>) -> Self {
+        let mut functions_by_name: HashMap<String, Vec<FunctionId>> = HashMap::new();
+        for (file_index, file) in files.iter().enumerate() {
+            for (function_index, function) in file.functions.iter().enumerate() {
+                functions_by_name
+                    .entry(function.name.clone())
+                    .or_default()
+                    .push(FunctionId {
+                        file: file_index,
+                        function: function_index,
+                    });
+            }
+        }
+
+        ProjectIndex {
+            files,
+            functions_by_name,
+            read_errors: Vec::new(),
+        }
+    }
+
+    pub fn function(&self, id: FunctionId) -> &FunctionDeclaration {
+        &self.files[id.file].functions[id.function]
+    }
+
+    pub fn file(&self, id: FunctionId) -> &IndexedFile {
+        &self.files[id.file]
+    }
+
+    /// Toutes les fonctions du projet portant ce nom
+    pub fn functions_named(&self, name: &str) -> &[FunctionId] {
+        self.functions_by_name.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn function_ids(&self) -> impl Iterator<Item = FunctionId> + '_ {
+        self.files.iter().enumerate().flat_map(|(file, f)| {
+            (0..f.functions.len()).map(move |function| FunctionId { file, function })
+        })
+    }
+
+    pub fn file_index(&self, path: &Path) -> Option<usize> {
+        self.files.iter().position(|f| f.path == path)
+    }
+}
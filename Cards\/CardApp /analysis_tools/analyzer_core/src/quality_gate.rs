@@ -0,0 +1,246 @@
+//! Porte qualité : conditions configurables évaluées sur le rapport final
+//!
+//! Chaque condition non configurée est ignorée. Les conditions portent sur les
+//! problèmes signalés (hors suppressions et, avec une ligne de base, hors
+//! problèmes inchangés), sauf `max-new` qui compare à la ligne de base.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::{GateCondition, PerformanceReport, QualityGateResult, Severity};
+
+/// Nombre maximal d'éléments cités par condition échouée
+const MAX_DETAILS: usize = 20;
+
+/// Conditions de la porte qualité (`None` : condition désactivée)
+#[derive(Debug, Clone, Default)]
+pub struct QualityGate {
+    pub max_critical: Option<usize>,
+    pub max_high: Option<usize>,
+    pub min_health_score: Option<f64>,
+    /// Nouveaux problèmes tolérés par rapport à la ligne de base
+    pub max_new_issues: Option<usize>,
+    /// Complexité cyclomatique maximale d'une fonction
+    pub max_complexity: Option<f64>,
+    /// Aucun problème plus grave que cette sévérité dans `changed_files`
+    pub changed_files_max_severity: Option<Severity>,
+    /// Fichiers modifiés, pour `changed_files_max_severity`
+    pub changed_files: Vec<PathBuf>,
+}
+
+impl QualityGate {
+    pub fn is_empty(&self) -> bool {
+        self.max_critical.is_none()
+            && self.max_high.is_none()
+            && self.min_health_score.is_none()
+            && self.max_new_issues.is_none()
+            && self.max_complexity.is_none()
+            && self.changed_files_max_severity.is_none()
+    }
+
+    pub fn evaluate(&self, report: &PerformanceReport) -> QualityGateResult {
+        let mut conditions = Vec::new();
+        let count = |severity| report.issue_count_by_severity.get(&severity).copied().unwrap_or_default();
+
+        for (id, severity, max) in [
+            ("max-critical", Severity::Critical, self.max_critical),
+            ("max-high", Severity::High, self.max_high),
+        ] {
+            if let Some(max) = max {
+                let actual = count(severity);
+                conditions.push(condition(
+                    id,
+                    format!("Problèmes {:?} au plus", severity),
+                    max.to_string(),
+                    actual.to_string(),
+                    actual <= max,
+                    Vec::new(),
+                ));
+            }
+        }
+
+        if let Some(min) = self.min_health_score {
+            let actual = report.project_stats.health_score;
+            conditions.push(condition(
+                "min-health-score",
+                "Score de santé minimal".to_string(),
+                format!("{:.1}", min),
+                format!("{:.1}", actual),
+                actual >= min,
+                Vec::new(),
+            ));
+        }
+
+        if let Some(max) = self.max_new_issues {
+            let actual = report.baseline.as_ref().map_or(0, |baseline| baseline.new);
+            conditions.push(condition(
+                "max-new",
+                "Nouveaux problèmes par rapport à la ligne de base au plus".to_string(),
+                max.to_string(),
+                actual.to_string(),
+                actual <= max,
+                Vec::new(),
+            ));
+        }
+
+        if let Some(max) = self.max_complexity {
+            let mut offenders: Vec<(&Path, f64)> = report
+                .files_analyzed
+                .iter()
+                .filter(|result| result.metrics.max_cyclomatic_complexity > max)
+                .map(|result| (result.file_path.as_path(), result.metrics.max_cyclomatic_complexity))
+                .collect();
+            offenders.sort_by(|a, b| b.1.total_cmp(&a.1));
+            let actual = offenders.first().map_or_else(
+                || report.files_analyzed.iter().map(|r| r.metrics.max_cyclomatic_complexity).fold(0.0, f64::max),
+                |(_, complexity)| *complexity,
+            );
+            conditions.push(condition(
+                "max-complexity",
+                "Complexité cyclomatique d'une fonction au plus".to_string(),
+                format!("{}", max),
+                format!("{}", actual),
+                offenders.is_empty(),
+                offenders.iter().map(|(path, complexity)| format!("{} ({})", path.display(), complexity)).collect(),
+            ));
+        }
+
+        if let Some(max_severity) = self.changed_files_max_severity {
+            let changed: HashSet<PathBuf> = self.changed_files.iter().map(|path| canonical(path)).collect();
+            let offenders: Vec<String> = report
+                .files_analyzed
+                .iter()
+                .filter(|result| changed.contains(&canonical(&result.file_path)))
+                .flat_map(|result| {
+                    result
+                        .issues
+                        .iter()
+                        .filter(|issue| (issue.severity as u8) < max_severity as u8)
+                        .map(move |issue| format!("{}:{} [{:?}] {}", result.file_path.display(), issue.line, issue.severity, issue.message))
+                })
+                .collect();
+            conditions.push(condition(
+                "changed-files-severity",
+                format!("Aucun problème plus grave que {:?} dans les {} fichiers modifiés", max_severity, self.changed_files.len()),
+                format!("{:?}", max_severity),
+                offenders.len().to_string(),
+                offenders.is_empty(),
+                offenders,
+            ));
+        }
+
+        QualityGateResult {
+            passed: conditions.iter().all(|condition| condition.passed),
+            conditions,
+        }
+    }
+}
+
+fn condition(
+    id: &str,
+    description: String,
+    threshold: String,
+    actual: String,
+    passed: bool,
+    mut details: Vec<String>,
+) -> GateCondition {
+    if passed {
+        details.clear();
+    }
+    let omitted = details.len().saturating_sub(MAX_DETAILS);
+    details.truncate(MAX_DETAILS);
+    if omitted > 0 {
+        details.push(format!("… et {} autres", omitted));
+    }
+    GateCondition {
+        id: id.to_string(),
+        description,
+        threshold,
+        actual,
+        passed,
+        details,
+    }
+}
+
+/// Chemin absolu quand le fichier existe, pour comparer des chemins relatifs à des répertoires différents
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::{
+        BACKGROUND_VIEW_CONTEXT, CLOSURE_SELF_CAPTURE, STRONG_DELEGATE, UNHANDLED_FETCH, VIEW_CONTEXT_PERFORM,
+    };
+    use crate::test_support::focused_registry;
+
+    #[test]
+    fn test_failed_conditions_name_offenders() {
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(focused_registry())
+            .with_source("App/Editor.swift", STRONG_DELEGATE)
+            .with_source("App/Import.swift", BACKGROUND_VIEW_CONTEXT)
+            .with_source("App/Count.swift", UNHANDLED_FETCH)
+            .with_source("App/Refresh.swift", VIEW_CONTEXT_PERFORM)
+            .run();
+
+        // Les problèmes de sévérité égale au seuil (Medium) ou inférieure restent admis dans les fichiers modifiés
+        let gate = QualityGate {
+            max_critical: Some(0),
+            max_high: Some(1),
+            changed_files_max_severity: Some(Severity::Medium),
+            changed_files: ["App/Import.swift", "App/Count.swift", "App/Refresh.swift"].map(PathBuf::from).to_vec(),
+            ..QualityGate::default()
+        };
+        let result = gate.evaluate(&report);
+
+        assert!(!result.passed);
+        let ids: Vec<(&str, bool)> = result.conditions.iter().map(|c| (c.id.as_str(), c.passed)).collect();
+        assert_eq!(ids, [("max-critical", false), ("max-high", true), ("changed-files-severity", false)]);
+        assert_eq!(result.conditions[2].details.len(), 1);
+        assert!(result.conditions[2].details[0].starts_with("App/Import.swift:3 [Critical]"));
+
+        let relaxed = QualityGate { max_critical: Some(1), max_high: Some(1), ..QualityGate::default() };
+        assert!(relaxed.evaluate(&report).passed);
+    }
+
+    #[test]
+    fn test_high_complexity_and_health_thresholds() {
+        let grade = "func grade(_ card: Card, answer: Answer) -> Int {\n    if card.isNew && answer == .easy { return 4 }\n    if card.isNew || answer == .good { return 3 }\n    if card.lapses > 3 { return 2 }\n    if card.lapses > 1 { return 1 }\n    return 0\n}\n";
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(focused_registry())
+            .with_source("App/Editor.swift", STRONG_DELEGATE)
+            .with_source("App/Loader.swift", CLOSURE_SELF_CAPTURE)
+            .with_source("App/Grade.swift", grade)
+            .run();
+
+        let strict = QualityGate {
+            max_high: Some(1),
+            max_complexity: Some(4.0),
+            min_health_score: Some(50.0),
+            ..QualityGate::default()
+        };
+        let result = strict.evaluate(&report);
+        assert!(!result.passed);
+        let conditions: Vec<(&str, &str, bool)> =
+            result.conditions.iter().map(|c| (c.id.as_str(), c.actual.as_str(), c.passed)).collect();
+        assert_eq!(
+            conditions,
+            [("max-high", "2", false), ("min-health-score", "16.7", false), ("max-complexity", "5", false)]
+        );
+        assert_eq!(result.conditions[2].details, ["App/Grade.swift (5)"]);
+
+        // Les seuils sont inclusifs
+        let at_thresholds = QualityGate {
+            max_high: Some(2),
+            max_complexity: Some(5.0),
+            min_health_score: Some(16.6),
+            ..QualityGate::default()
+        };
+        assert!(at_thresholds.evaluate(&report).passed);
+    }
+}
@@ -0,0 +1,466 @@
+//! Comparaison de deux rapports JSON (`--output json`) : problèmes ajoutés,
+//! supprimés ou modifiés, métriques par fichier et score de santé
+//!
+//! Les problèmes sont appariés par empreinte (voir [`crate::fingerprint`]), puis
+//! les restants par règle, fichier et déclaration englobante : un problème dont
+//! l'extrait, la ligne ou la sévérité a changé est « modifié ». Les chemins sont
+//! pris relativement à la racine du projet de chaque rapport (à défaut, au
+//! répertoire commun de ses fichiers), pour comparer des analyses lancées
+//! depuis des emplacements différents.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::discovery::relative_path;
+use crate::fingerprint::Fingerprinter;
+use crate::models::{AnalysisResult, FileIssue, PerformanceReport, Severity};
+use crate::reporters::legacy;
+
+const SEVERITIES: [Severity; 4] = [Severity::Critical, Severity::High, Severity::Medium, Severity::Low];
+
+/// Valeur avant et après
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl Change<f64> {
+    pub fn delta(&self) -> f64 {
+        self.new - self.old
+    }
+}
+
+impl Change<usize> {
+    pub fn delta(&self) -> i64 {
+        self.new as i64 - self.old as i64
+    }
+}
+
+/// Problème d'un des deux rapports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+    /// Chemin relatif à la racine du projet du rapport
+    pub file: String,
+    pub line: usize,
+    pub rule_id: Option<String>,
+    pub severity: Severity,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub declaration: Option<String>,
+}
+
+/// Problème présent dans les deux rapports sous une forme différente
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangedFinding {
+    pub old: Finding,
+    pub new: Finding,
+}
+
+/// Métriques d'un fichier dans un rapport
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FileSnapshot {
+    pub lines: usize,
+    pub max_cyclomatic_complexity: f64,
+    pub max_nesting_depth: u32,
+    pub issues: usize,
+}
+
+/// Évolution d'un fichier (`None` : absent de ce rapport)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileDelta {
+    pub file: String,
+    pub old: Option<FileSnapshot>,
+    pub new: Option<FileSnapshot>,
+}
+
+/// Différences entre deux rapports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportDiff {
+    pub health_score: Change<f64>,
+    pub total_files: Change<usize>,
+    pub total_lines: Change<usize>,
+    pub issues: Change<usize>,
+    pub issues_by_severity: BTreeMap<Severity, Change<usize>>,
+    pub added: Vec<Finding>,
+    pub removed: Vec<Finding>,
+    pub changed: Vec<ChangedFinding>,
+    pub unchanged: usize,
+    /// Fichiers dont les métriques ou le nombre de problèmes ont changé
+    pub files: Vec<FileDelta>,
+}
+
+/// Charge un rapport produit par `--output json`, ou à défaut par `--legacy-format`
+/// (`performance_analysis.json`, voir [`legacy::parse_swift_analyzer_report`])
+pub fn load_report(path: &Path) -> Result<PerformanceReport, String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Impossible de lire le rapport {}: {}", path.display(), e))?;
+    serde_json::from_str(&content).or_else(|e| {
+        legacy::parse_swift_analyzer_report(&content).map_err(|_| {
+            format!(
+                "Rapport invalide {} (attendu : rapport de `--output json` ou `performance_analysis.json` de `--legacy-format`): {}",
+                path.display(),
+                e
+            )
+        })
+    })
+}
+
+impl ReportDiff {
+    pub fn between(old: &PerformanceReport, new: &PerformanceReport) -> Self {
+        let old_findings = fingerprinted_findings(old);
+        let new_findings = fingerprinted_findings(new);
+
+        // Appariement exact par empreinte
+        let mut old_by_fingerprint: HashMap<&str, usize> =
+            old_findings.iter().enumerate().map(|(i, (fingerprint, _))| (fingerprint.as_str(), i)).collect();
+        let mut old_matched = vec![false; old_findings.len()];
+        let mut pending = Vec::new();
+        let mut changed = Vec::new();
+        let mut unchanged = 0;
+        for (fingerprint, finding) in &new_findings {
+            match old_by_fingerprint.remove(fingerprint.as_str()) {
+                Some(i) => {
+                    old_matched[i] = true;
+                    let previous = &old_findings[i].1;
+                    if previous.severity != finding.severity || previous.message != finding.message {
+                        changed.push(ChangedFinding {
+                            old: previous.clone(),
+                            new: finding.clone(),
+                        });
+                    } else {
+                        unchanged += 1;
+                    }
+                }
+                None => pending.push(finding),
+            }
+        }
+
+        // Puis par règle, fichier et déclaration, dans l'ordre des rapports
+        let mut added = Vec::new();
+        for finding in pending {
+            let candidate = old_findings.iter().enumerate().position(|(i, (_, previous))| {
+                !old_matched[i]
+                    && previous.rule_id == finding.rule_id
+                    && previous.file == finding.file
+                    && previous.declaration == finding.declaration
+            });
+            match candidate {
+                Some(i) => {
+                    old_matched[i] = true;
+                    changed.push(ChangedFinding {
+                        old: old_findings[i].1.clone(),
+                        new: finding.clone(),
+                    });
+                }
+                None => added.push(finding.clone()),
+            }
+        }
+        let removed = old_findings
+            .iter()
+            .zip(&old_matched)
+            .filter(|(_, matched)| !**matched)
+            .map(|((_, finding), _)| finding.clone())
+            .collect();
+
+        let count = |report: &PerformanceReport, severity| report.issue_count_by_severity.get(&severity).copied().unwrap_or_default();
+        ReportDiff {
+            health_score: Change { old: old.project_stats.health_score, new: new.project_stats.health_score },
+            total_files: Change { old: old.project_stats.total_files, new: new.project_stats.total_files },
+            total_lines: Change { old: old.project_stats.total_lines, new: new.project_stats.total_lines },
+            issues: Change { old: old_findings.len(), new: new_findings.len() },
+            issues_by_severity: SEVERITIES
+                .iter()
+                .map(|&severity| (severity, Change { old: count(old, severity), new: count(new, severity) }))
+                .collect(),
+            added,
+            removed,
+            changed,
+            unchanged,
+            files: file_deltas(old, new),
+        }
+    }
+}
+
+/// Racine du projet, ou répertoire commun à tous les fichiers analysés pour les rapports qui ne l'indiquent pas
+fn project_root(report: &PerformanceReport) -> PathBuf {
+    if let Some(root) = &report.project_root {
+        return root.clone();
+    }
+    let mut parents = report.files_analyzed.iter().filter_map(|result| result.file_path.parent());
+    let Some(first) = parents.next() else {
+        return PathBuf::new();
+    };
+    parents.fold(first.to_path_buf(), |root, parent| {
+        root.components().zip(parent.components()).take_while(|(a, b)| a == b).map(|(a, _)| a).collect()
+    })
+}
+
+fn fingerprinted_findings(report: &PerformanceReport) -> Vec<(String, Finding)> {
+    let root = project_root(report);
+    let mut fingerprinter = Fingerprinter::new();
+    let mut findings = Vec::new();
+    for result in &report.files_analyzed {
+        let file = relative_path(&root, &result.file_path);
+        for issue in &result.issues {
+            findings.push((fingerprinter.fingerprint(&file, issue), finding(&file, issue)));
+        }
+    }
+    findings
+}
+
+fn finding(file: &str, issue: &FileIssue) -> Finding {
+    Finding {
+        file: file.to_string(),
+        line: issue.line,
+        rule_id: issue.rule_id.clone(),
+        severity: issue.severity,
+        message: issue.message.clone(),
+        declaration: issue.declaration.clone(),
+    }
+}
+
+fn snapshot(result: &AnalysisResult) -> FileSnapshot {
+    FileSnapshot {
+        lines: result.line_count,
+        max_cyclomatic_complexity: result.metrics.max_cyclomatic_complexity,
+        max_nesting_depth: result.metrics.max_nesting_depth,
+        issues: result.issues.len(),
+    }
+}
+
+fn file_deltas(old: &PerformanceReport, new: &PerformanceReport) -> Vec<FileDelta> {
+    let mut files: BTreeMap<String, (Option<FileSnapshot>, Option<FileSnapshot>)> = BTreeMap::new();
+    let old_root = project_root(old);
+    for result in &old.files_analyzed {
+        files.entry(relative_path(&old_root, &result.file_path)).or_default().0 = Some(snapshot(result));
+    }
+    let new_root = project_root(new);
+    for result in &new.files_analyzed {
+        files.entry(relative_path(&new_root, &result.file_path)).or_default().1 = Some(snapshot(result));
+    }
+
+    files
+        .into_iter()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(file, (old, new))| FileDelta { file, old, new })
+        .collect()
+}
+
+/// Résumé Markdown : score, compteurs, problèmes ajoutés, supprimés et modifiés, fichiers
+pub fn to_markdown(diff: &ReportDiff) -> String {
+    let mut markdown = String::from("## Comparaison des analyses\n\n");
+    let _ = writeln!(
+        markdown,
+        "**Score de santé : {:.1} → {:.1} ({:+.1})** · {} ajoutés · {} supprimés · {} modifiés · {} inchangés\n",
+        diff.health_score.old,
+        diff.health_score.new,
+        diff.health_score.delta(),
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len(),
+        diff.unchanged
+    );
+
+    markdown.push_str("| | Avant | Après | Écart |\n|---|---:|---:|---:|\n");
+    let mut row = |label: &str, change: &Change<usize>| {
+        let _ = writeln!(markdown, "| {} | {} | {} | {:+} |", label, change.old, change.new, change.delta());
+    };
+    row("Fichiers", &diff.total_files);
+    row("Lignes", &diff.total_lines);
+    row("Problèmes", &diff.issues);
+    for (severity, change) in &diff.issues_by_severity {
+        row(&format!("{:?}", severity), change);
+    }
+
+    for (title, findings) in [("Problèmes ajoutés", &diff.added), ("Problèmes supprimés", &diff.removed)] {
+        if findings.is_empty() {
+            continue;
+        }
+        let _ = writeln!(markdown, "\n### {} ({})\n\n| Fichier | Ligne | Règle | Sévérité | Message |\n|---|---:|---|---|---|", title, findings.len());
+        for finding in findings {
+            let _ = writeln!(
+                markdown,
+                "| `{}` | {} | `{}` | {:?} | {} |",
+                finding.file,
+                finding.line,
+                finding.rule_id.as_deref().unwrap_or("unknown"),
+                finding.severity,
+                escape_cell(&finding.message)
+            );
+        }
+    }
+
+    if !diff.changed.is_empty() {
+        let _ = writeln!(markdown, "\n### Problèmes modifiés ({})\n\n| Fichier | Ligne | Règle | Sévérité | Message |\n|---|---:|---|---|---|", diff.changed.len());
+        for ChangedFinding { old, new } in &diff.changed {
+            let severity = if old.severity == new.severity {
+                format!("{:?}", new.severity)
+            } else {
+                format!("{:?} → {:?}", old.severity, new.severity)
+            };
+            let _ = writeln!(
+                markdown,
+                "| `{}` | {} → {} | `{}` | {} | {} |",
+                new.file,
+                old.line,
+                new.line,
+                new.rule_id.as_deref().unwrap_or("unknown"),
+                severity,
+                escape_cell(&new.message)
+            );
+        }
+    }
+
+    if !diff.files.is_empty() {
+        markdown.push_str("\n### Fichiers\n\n| Fichier | Lignes | Complexité max | Imbrication max | Problèmes |\n|---|---:|---:|---:|---:|\n");
+        for delta in &diff.files {
+            let old = delta.old.as_ref();
+            let new = delta.new.as_ref();
+            let _ = writeln!(
+                markdown,
+                "| `{}` | {} | {} | {} | {} |",
+                delta.file,
+                evolution(old.map(|s| s.lines as f64), new.map(|s| s.lines as f64)),
+                evolution(old.map(|s| s.max_cyclomatic_complexity), new.map(|s| s.max_cyclomatic_complexity)),
+                evolution(old.map(|s| s.max_nesting_depth as f64), new.map(|s| s.max_nesting_depth as f64)),
+                evolution(old.map(|s| s.issues as f64), new.map(|s| s.issues as f64))
+            );
+        }
+    }
+
+    markdown
+}
+
+/// `ancien → nouveau`, ou la seule valeur connue pour un fichier ajouté ou supprimé
+pub fn evolution(old: Option<f64>, new: Option<f64>) -> String {
+    match (old, new) {
+        (Some(old), Some(new)) if old == new => format!("{}", new),
+        (Some(old), Some(new)) => format!("{} → {}", old, new),
+        (None, Some(new)) => format!("(nouveau) {}", new),
+        (Some(old), None) => format!("{} (supprimé)", old),
+        (None, None) => String::new(),
+    }
+}
+
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::{STRONG_DELEGATE, UNHANDLED_FETCH, WEAK_DELEGATE};
+    use crate::test_support::focused_registry;
+
+    fn analyze(root: &str, files: &[(&str, &str)]) -> PerformanceReport {
+        let mut report = files
+            .iter()
+            .fold(Analyzer::new(AnalysisConfig::default()).with_registry(focused_registry()), |analyzer, (path, content)| {
+                analyzer.with_source(Path::new(root).join(path), *content)
+            })
+            .run();
+        report.project_root = Some(PathBuf::from(root));
+        report
+    }
+
+    #[test]
+    fn test_added_removed_and_changed_findings() {
+        let old = analyze(
+            "/ci/old/CardApp",
+            &[
+                ("App/Editor.swift", "class Editor {\n    var delegate: EditorDelegate?\n}\nclass List {\n    var delegate: ListDelegate?\n}\n"),
+                ("App/Legacy.swift", "class Legacy {\n    var delegate: LegacyDelegate?\n}\n"),
+            ],
+        );
+        let new = analyze(
+            "checkout/CardApp",
+            &[
+                ("App/Editor.swift", "import UIKit\n\nclass Editor {\n    var delegate: EditorDelegate?\n}\nclass List {\n    var delegate: (any ListDelegate)?\n}\nclass Grid {\n    var delegate: GridDelegate?\n}\n"),
+                ("App/Other.swift", "let x = 1\n"),
+            ],
+        );
+
+        let diff = ReportDiff::between(&old, &new);
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!((diff.changed[0].old.line, diff.changed[0].new.line), (5, 7));
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].declaration.as_deref(), Some("Grid"));
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].file, "App/Legacy.swift");
+        assert_eq!(diff.issues, Change { old: 3, new: 3 });
+
+        let files: Vec<&str> = diff.files.iter().map(|delta| delta.file.as_str()).collect();
+        assert_eq!(files, ["App/Editor.swift", "App/Legacy.swift", "App/Other.swift"]);
+        assert!(to_markdown(&diff).contains("| `App/Editor.swift` | 6 → 11 |"));
+    }
+
+    #[test]
+    fn test_remaining_findings_paired_by_declaration() {
+        let old = analyze(
+            "CardApp",
+            &[
+                ("App/Loader.swift", "class DeckLoader {\n    func reload() {\n        service.fetchCards { cards in self.cards = cards }\n    }\n    func refresh() {\n        service.fetchDecks { decks in self.decks = decks }\n    }\n}\n"),
+                ("App/Count.swift", UNHANDLED_FETCH),
+            ],
+        );
+        // Fonctions inversées et closures modifiées ; countCards renommée
+        let new = analyze(
+            "CardApp",
+            &[
+                ("App/Loader.swift", "class DeckLoader {\n    func refresh() {\n        service.fetchDecks { decks in self.decks = decks.sorted() }\n    }\n    func reload() {\n        service.fetchCards { cards in self.cards = cards.shuffled() }\n    }\n}\n"),
+                ("App/Count.swift", &UNHANDLED_FETCH.replace("countCards", "cardCount")),
+            ],
+        );
+
+        let diff = ReportDiff::between(&old, &new);
+        let changed: Vec<(Option<&str>, usize, usize)> = diff
+            .changed
+            .iter()
+            .map(|change| {
+                assert_eq!(change.old.declaration, change.new.declaration);
+                (change.new.declaration.as_deref(), change.old.line, change.new.line)
+            })
+            .collect();
+        assert_eq!(changed, [(Some("DeckLoader.refresh"), 6, 3), (Some("DeckLoader.reload"), 3, 6)]);
+        assert_eq!((diff.unchanged, diff.removed.len(), diff.added.len()), (0, 1, 1));
+        assert_eq!(diff.removed[0].declaration.as_deref(), Some("countCards"));
+        assert_eq!(diff.added[0].declaration.as_deref(), Some("cardCount"));
+        assert_eq!(diff.added[0].rule_id.as_deref(), Some("coredata.unhandled-fetch"));
+    }
+
+    #[test]
+    fn test_loads_legacy_reports() {
+        let directory = std::env::temp_dir().join(format!("swift-analyzer-legacy-diff-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let write_legacy = |name: &str, files: &[(&str, &str)]| {
+            let path = directory.join(name);
+            legacy::generate_swift_analyzer_report(&analyze("/ci/CardApp", files), "/ci/CardApp", &path).unwrap();
+            path
+        };
+        let old = write_legacy("old.json", &[("App/Editor.swift", STRONG_DELEGATE), ("App/Count.swift", UNHANDLED_FETCH)]);
+        let new = write_legacy("new.json", &[("App/Editor.swift", WEAK_DELEGATE), ("App/Count.swift", UNHANDLED_FETCH)]);
+        let invalid = directory.join("invalid.json");
+        fs::write(&invalid, r#"{"issues": 3}"#).unwrap();
+
+        let (old, new, invalid) = (load_report(&old), load_report(&new), load_report(&invalid));
+        fs::remove_dir_all(&directory).unwrap();
+
+        let (old, new) = (old.unwrap(), new.unwrap());
+        assert_eq!(old.project_stats.total_files, 2);
+        assert_eq!(old.issue_count_by_severity.get(&Severity::High), Some(&1));
+        let diff = ReportDiff::between(&old, &new);
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!((diff.removed[0].file.as_str(), diff.removed[0].line), ("App/Editor.swift", 2));
+        assert!(diff.added.is_empty());
+        assert!(invalid.unwrap_err().contains("--legacy-format"));
+    }
+}
@@ -0,0 +1,62 @@
+//! Générateurs de rapports, un module par format de sortie
+
+use std::fmt::Write as _;
+
+use crate::models::QualityGateResult;
+
+pub mod json;
+pub mod html;
+pub mod sarif;
+pub mod junit;
+pub mod checkstyle;
+pub mod markdown;
+pub mod code_quality;
+pub mod interactive;
+pub mod trend;
+pub mod legacy;
+
+/// Encadré HTML de la porte qualité (styles en ligne, commun aux deux rapports HTML)
+fn quality_gate_html(gate: &QualityGateResult) -> String {
+    let (color, status) = if gate.passed { ("#28a745", "réussie") } else { ("#dc3545", "échouée") };
+    let mut html = format!(
+        "    <div class=\"container quality-gate\" style=\"border-left: 6px solid {color}; padding: 12px 20px;\">\n        <h2 style=\"color: {color};\">Porte qualité {status}</h2>\n        <ul>\n"
+    );
+    for condition in &gate.conditions {
+        let _ = write!(
+            html,
+            "            <li>{} {} : {} (seuil {})",
+            if condition.passed { "✔" } else { "✘" },
+            escape_xml(&condition.description),
+            escape_xml(&condition.actual),
+            escape_xml(&condition.threshold)
+        );
+        if !condition.details.is_empty() {
+            html.push_str("\n                <ul>\n");
+            for detail in &condition.details {
+                let _ = writeln!(html, "                    <li><code>{}</code></li>", escape_xml(detail));
+            }
+            html.push_str("                </ul>\n            ");
+        }
+        html.push_str("</li>\n");
+    }
+    html.push_str("        </ul>\n    </div>\n");
+    html
+}
+
+/// Échappe un texte pour un attribut ou un contenu XML (caractères de contrôle retirés)
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\t' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,94 @@
+//! Checkstyle XML : un élément `file` par fichier analysé, un `error` par problème
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use super::escape_xml;
+use crate::models::{PerformanceReport, Severity};
+
+pub fn generate_report(report: &PerformanceReport, output_path: &Path) -> Result<(), String> {
+    fs::write(output_path, to_xml(report))
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport Checkstyle {}: {}", output_path.display(), e))
+}
+
+pub fn to_xml(report: &PerformanceReport) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    for result in &report.files_analyzed {
+        let name = escape_xml(&result.file_path.display().to_string());
+        if result.issues.is_empty() {
+            let _ = writeln!(xml, r#"  <file name="{}"/>"#, name);
+            continue;
+        }
+        let _ = writeln!(xml, r#"  <file name="{}">"#, name);
+        for issue in &result.issues {
+            let column = issue.column.map(|column| format!(r#" column="{}""#, column + 1)).unwrap_or_default();
+            let _ = writeln!(
+                xml,
+                r#"    <error line="{}"{} severity="{}" message="{}" source="swift-analyzer.{}"/>"#,
+                issue.line,
+                column,
+                severity(issue.severity),
+                escape_xml(&issue.message),
+                escape_xml(issue.rule_id.as_deref().unwrap_or("unknown"))
+            );
+        }
+        xml.push_str("  </file>\n");
+    }
+
+    // Conditions échouées de la porte qualité, sous un pseudo-fichier
+    let failed: Vec<_> = report.quality_gate.iter().flat_map(|gate| &gate.conditions).filter(|c| !c.passed).collect();
+    if !failed.is_empty() {
+        xml.push_str("  <file name=\"swift-analyzer-quality-gate\">\n");
+        for condition in failed {
+            let _ = writeln!(
+                xml,
+                r#"    <error line="0" severity="error" message="{}" source="swift-analyzer.quality-gate.{}"/>"#,
+                escape_xml(&format!("{} : {} (seuil {})", condition.description, condition.actual, condition.threshold)),
+                escape_xml(&condition.id)
+            );
+        }
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</checkstyle>\n");
+    xml
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "info",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::{BACKGROUND_VIEW_CONTEXT, UNHANDLED_FETCH, VIEW_CONTEXT_PERFORM};
+    use crate::test_support::focused_registry;
+
+    #[test]
+    fn test_errors_per_file_with_severity() {
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(focused_registry())
+            .with_source("Import.swift", BACKGROUND_VIEW_CONTEXT)
+            .with_source("Count.swift", UNHANDLED_FETCH)
+            .with_source("Refresh.swift", VIEW_CONTEXT_PERFORM)
+            .with_source("Empty.swift", "")
+            .run();
+
+        let xml = to_xml(&report);
+        // Critical et High : error ; Medium : warning ; Low : info
+        assert!(xml.contains(r#"<file name="Import.swift">
+    <error line="3" column="26" severity="error""#));
+        assert!(xml.contains(r#"<file name="Count.swift">
+    <error line="2" severity="warning" message="Requête CoreData sans gestion d&apos;erreur""#));
+        assert!(xml.contains(r#"<file name="Refresh.swift">
+    <error line="2" severity="info""#));
+        assert!(xml.contains(r#"source="swift-analyzer.coredata.view-context-perform"/>"#));
+        assert!(xml.contains(r#"<file name="Empty.swift"/>"#));
+    }
+}
@@ -0,0 +1,187 @@
+//! GitLab Code Quality (format Code Climate) avec une empreinte stable par problème
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::fingerprint::Fingerprinter;
+use crate::git::RepositoryPaths;
+use crate::models::{PerformanceReport, Severity};
+use crate::rules::RuleRegistry;
+
+pub fn generate_report(
+    report: &PerformanceReport,
+    project_root: &Path,
+    registry: &RuleRegistry,
+    output_path: &Path,
+) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(&code_quality_issues(report, project_root, registry))
+        .map_err(|e| format!("Erreur lors de la sérialisation Code Quality: {}", e))?;
+
+    fs::write(output_path, content)
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport Code Quality {}: {}", output_path.display(), e))
+}
+
+/// Tableau des problèmes, classés selon la catégorie de leur règle dans `registry` ; GitLab attend des chemins
+/// relatifs à la racine du dépôt contenant `project_root`
+pub fn code_quality_issues(report: &PerformanceReport, project_root: &Path, registry: &RuleRegistry) -> Value {
+    let paths = RepositoryPaths::discover(project_root);
+    let mut fingerprinter = Fingerprinter::new();
+    let mut issues = Vec::new();
+
+    for result in &report.files_analyzed {
+        let path = paths.relative(&result.file_path);
+        for issue in &result.issues {
+            let rule_id = issue.rule_id.as_deref().unwrap_or("unknown");
+            let category = registry.get(rule_id).map(|metadata| metadata.category.as_str());
+            issues.push(json!({
+                "type": "issue",
+                "check_name": rule_id,
+                "description": issue.message,
+                "categories": [category_name(category)],
+                "severity": severity(issue.severity),
+                "fingerprint": fingerprinter.fingerprint(&path, issue),
+                "location": {
+                    "path": path,
+                    "lines": { "begin": issue.line.max(1) },
+                },
+            }));
+        }
+    }
+
+    // Conditions échouées de la porte qualité, rattachées à la racine du projet
+    for condition in report.quality_gate.iter().flat_map(|gate| &gate.conditions).filter(|c| !c.passed) {
+        issues.push(json!({
+            "type": "issue",
+            "check_name": format!("quality-gate.{}", condition.id),
+            "description": format!("Porte qualité : {} : {} (seuil {})", condition.description, condition.actual, condition.threshold),
+            "categories": ["Complexity"],
+            "severity": "blocker",
+            "fingerprint": format!("quality-gate-{}", condition.id),
+            "location": {
+                "path": paths.relative(project_root),
+                "lines": { "begin": 1 },
+            },
+        }));
+    }
+
+    Value::Array(issues)
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::High => "major",
+        Severity::Medium => "minor",
+        Severity::Low => "info",
+    }
+}
+
+/// Catégorie Code Climate correspondant à la catégorie de la règle
+fn category_name(category: Option<&str>) -> &'static str {
+    match category {
+        Some("complexity") => "Complexity",
+        Some("duplication") => "Duplication",
+        Some("deadcode") => "Clarity",
+        Some("memory") | Some("concurrency") | Some("coredata") | Some("resources") => "Bug Risk",
+        _ => "Performance",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::{AnalysisConfig, GateCondition, QualityGateResult};
+    use crate::test_support::fixtures::{BACKGROUND_VIEW_CONTEXT, PRINT_CALL, STRONG_DELEGATE, UNHANDLED_FETCH};
+    use crate::test_support::{focused_registry, NoPrintRule};
+
+    #[test]
+    fn test_issues_with_stable_fingerprints() {
+        let analyze = |content: &str| {
+            let report = Analyzer::new(AnalysisConfig::default())
+                .with_registry(focused_registry())
+                .with_source("App/Count.swift", content)
+                .run();
+            code_quality_issues(&report, Path::new("App"), &focused_registry())
+        };
+
+        let before = analyze(UNHANDLED_FETCH);
+        let after = analyze(&format!("// Compteur de cartes\n{}", UNHANDLED_FETCH));
+        let issue = &before[0];
+        assert_eq!(issue["check_name"], "coredata.unhandled-fetch");
+        assert_eq!(issue["severity"], "minor");
+        assert_eq!(issue["categories"][0], "Bug Risk");
+        assert_eq!(issue["location"]["path"], "Count.swift");
+        assert_eq!(after[0]["location"]["lines"]["begin"], 3);
+        assert_eq!(after[0]["fingerprint"], issue["fingerprint"]);
+    }
+
+    #[test]
+    fn test_severities_and_categories_including_a_custom_rule() {
+        let nested = "func schedule() {\n    for deck in decks {\n        if deck.isActive {\n            for card in deck.cards {\n                if card.isDue {\n                    plan(card)\n                }\n            }\n        }\n    }\n}\n";
+        let mut registry = focused_registry();
+        registry.register(NoPrintRule);
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(registry.clone())
+            .with_source("App/Import.swift", BACKGROUND_VIEW_CONTEXT)
+            .with_source("App/Editor.swift", STRONG_DELEGATE)
+            .with_source("App/Schedule.swift", nested)
+            .with_source("App/Deck.swift", PRINT_CALL)
+            .run();
+
+        let issues = code_quality_issues(&report, Path::new("App"), &registry);
+        let summary: Vec<(&str, &str, &str)> = issues
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|issue| {
+                (
+                    issue["check_name"].as_str().unwrap(),
+                    issue["severity"].as_str().unwrap(),
+                    issue["categories"][0].as_str().unwrap(),
+                )
+            })
+            .collect();
+        // La catégorie de la règle personnalisée (`resources`) vient du registre de l'analyse
+        assert_eq!(
+            summary,
+            [
+                ("coredata.background-view-context", "critical", "Bug Risk"),
+                ("memory.strong-delegate", "major", "Bug Risk"),
+                ("complexity.nesting", "minor", "Complexity"),
+                ("cardapp.no-print", "info", "Bug Risk"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paths_relative_to_repository_root() {
+        let root = std::env::temp_dir().join(format!("swift-analyzer-code-quality-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let project = root.join("Cards/CardApp");
+        fs::create_dir_all(&project).unwrap();
+        git2::Repository::init(&root).unwrap();
+        let mut report = Analyzer::new(AnalysisConfig::default())
+            .with_source(project.join("Views/Editor.swift"), STRONG_DELEGATE)
+            .run();
+        report.quality_gate = Some(QualityGateResult {
+            passed: false,
+            conditions: vec![GateCondition {
+                id: "max-critical".to_string(),
+                description: "Problèmes critiques".to_string(),
+                threshold: "0".to_string(),
+                actual: "1".to_string(),
+                passed: false,
+                details: Vec::new(),
+            }],
+        });
+
+        let issues = code_quality_issues(&report, &project, &RuleRegistry::builtin());
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(issues[0]["location"]["path"], "Cards/CardApp/Views/Editor.swift");
+        assert_eq!(issues.as_array().unwrap().last().unwrap()["location"]["path"], "Cards/CardApp");
+    }
+}
@@ -0,0 +1,1364 @@
+//! Rapport HTML autonome : synthèse, graphiques SVG intégrés et listes tronquées selon un budget de taille
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::models::{ChurnHotspot, FileIssue, IssueType, OwnerSummary, PerformanceReport, Severity};
+use crate::rules::{convention_link, RuleRegistry};
+
+/// Taille visée par défaut d'un rapport HTML (5 Mio)
+pub const DEFAULT_SIZE_BUDGET: usize = 5 * 1024 * 1024;
+
+/// Options du rapport HTML
+#[derive(Debug, Clone, Copy)]
+pub struct HtmlOptions {
+    /// Taille visée du fichier en octets : au-delà, les listes (problèmes, code mort, clones) sont tronquées
+    pub size_budget: usize,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        HtmlOptions {
+            size_budget: DEFAULT_SIZE_BUDGET,
+        }
+    }
+}
+
+/// Génère un rapport au format HTML autonome (graphiques SVG intégrés, aucune ressource externe)
+///
+/// Les règles rencontrées sont expliquées d'après `registry`.
+pub fn generate_report(
+    report: &PerformanceReport,
+    registry: &RuleRegistry,
+    options: HtmlOptions,
+    output_path: &Path,
+) -> Result<(), String> {
+    let html_content = to_html(report, registry, options);
+
+    // Écrire le rapport HTML dans un fichier
+    let mut file = File::create(output_path).map_err(|e| {
+        format!("Erreur lors de la création du fichier de rapport HTML {}: {}", output_path.display(), e)
+    })?;
+
+    file.write_all(html_content.as_bytes())
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport HTML {}: {}", output_path.display(), e))
+}
+
+pub fn to_html(report: &PerformanceReport, registry: &RuleRegistry, options: HtmlOptions) -> String {
+    let mut html_content = String::new();
+    // Parts du budget accordées aux listes ; le reste couvre les parties fixes
+    let mut issues_budget = SectionBudget::new(options.size_budget / 2);
+    let mut dead_code_budget = SectionBudget::new(options.size_budget / 5);
+    let mut clones_budget = SectionBudget::new(options.size_budget / 5);
+
+    // En-tête HTML
+    html_content.push_str(
+        r#"<!DOCTYPE html>
+<html lang="fr">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Rapport d'analyse de performance Swift</title>
+    <style>
+        :root {
+            --primary: #007bff;
+            --secondary: #6c757d;
+            --success: #28a745;
+            --danger: #dc3545;
+            --warning: #ffc107;
+            --info: #17a2b8;
+            --dark: #343a40;
+            --light: #f8f9fa;
+        }
+        
+        body {
+            font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Helvetica Neue", Arial, sans-serif;
+            line-height: 1.6;
+            color: #333;
+            max-width: 1200px;
+            margin: 0 auto;
+            padding: 20px;
+            background-color: #f5f5f7;
+        }
+        
+        .container {
+            background-color: white;
+            border-radius: 8px;
+            box-shadow: 0 2px 10px rgba(0, 0, 0, 0.1);
+            padding: 25px;
+            margin-bottom: 25px;
+        }
+        
+        h1, h2, h3 {
+            color: #333;
+        }
+        
+        h1 {
+            border-bottom: 2px solid var(--primary);
+            padding-bottom: 10px;
+            margin-bottom: 30px;
+        }
+        
+        .summary-stats {
+            display: flex;
+            flex-wrap: wrap;
+            gap: 15px;
+            margin-bottom: 30px;
+        }
+        
+        .stat-card {
+            flex: 1;
+            min-width: 200px;
+            padding: 15px;
+            border-radius: 8px;
+            box-shadow: 0 2px 5px rgba(0, 0, 0, 0.1);
+            background-color: white;
+            text-align: center;
+        }
+        
+        .stat-card h3 {
+            margin-top: 0;
+            color: var(--secondary);
+            font-size: 16px;
+        }
+        
+        .stat-card .value {
+            font-size: 28px;
+            font-weight: bold;
+            color: var(--primary);
+        }
+        
+        .health-meter {
+            height: 10px;
+            background-color: #e9ecef;
+            border-radius: 5px;
+            margin: 15px 0;
+            overflow: hidden;
+        }
+        
+        .health-meter-fill {
+            height: 100%;
+            border-radius: 5px;
+            transition: width 0.5s ease;
+        }
+        
+        .progress-good {
+            background-color: var(--success);
+        }
+        
+        .progress-warning {
+            background-color: var(--warning);
+        }
+        
+        .progress-danger {
+            background-color: var(--danger);
+        }
+        
+        table {
+            width: 100%;
+            border-collapse: collapse;
+            margin: 20px 0;
+            font-size: 14px;
+        }
+        
+        th, td {
+            padding: 12px 15px;
+            text-align: left;
+            border-bottom: 1px solid #ddd;
+        }
+        
+        th {
+            background-color: var(--light);
+            font-weight: bold;
+        }
+        
+        tr:hover {
+            background-color: rgba(0, 123, 255, 0.05);
+        }
+        
+        .severity-critical {
+            color: #b71c1c;
+            font-weight: bold;
+        }
+        
+        .severity-high {
+            color: #e65100;
+            font-weight: bold;
+        }
+        
+        .severity-medium {
+            color: #ff8f00;
+        }
+        
+        .severity-low {
+            color: #558b2f;
+        }
+        
+        .tab {
+            overflow: hidden;
+            border: 1px solid #ccc;
+            background-color: var(--light);
+            border-radius: 8px 8px 0 0;
+        }
+        
+        .tab button {
+            background-color: inherit;
+            float: left;
+            border: none;
+            outline: none;
+            cursor: pointer;
+            padding: 14px 16px;
+            transition: 0.3s;
+            font-size: 16px;
+        }
+        
+        .tab button:hover {
+            background-color: #ddd;
+        }
+        
+        .tab button.active {
+            background-color: white;
+            border-bottom: 3px solid var(--primary);
+        }
+        
+        .tabcontent {
+            display: none;
+            padding: 20px;
+            border: 1px solid #ccc;
+            border-top: none;
+            border-radius: 0 0 8px 8px;
+            background-color: white;
+        }
+        
+        .show {
+            display: block;
+        }
+        
+        .issue-card {
+            padding: 15px;
+            margin-bottom: 15px;
+            border-radius: 5px;
+            border-left: 5px solid;
+            background-color: #f8f9fa;
+        }
+        
+        .issue-critical {
+            border-left-color: #b71c1c;
+        }
+        
+        .issue-high {
+            border-left-color: #e65100;
+        }
+        
+        .issue-medium {
+            border-left-color: #ff8f00;
+        }
+        
+        .issue-low {
+            border-left-color: #558b2f;
+        }
+        
+        .issue-header {
+            display: flex;
+            justify-content: space-between;
+            margin-bottom: 5px;
+        }
+        
+        .issue-file {
+            font-family: monospace;
+            font-size: 13px;
+            color: var(--secondary);
+        }
+        
+        .issue-message {
+            margin: 10px 0;
+        }
+        
+        .issue-suggestion {
+            font-style: italic;
+            color: #2e7d32;
+            margin-top: 8px;
+        }
+        
+        .issue-rule {
+            font-family: monospace;
+            font-size: 13px;
+            margin-top: 8px;
+        }
+        
+        .rule-explanation {
+            border-bottom: 1px solid #eee;
+            padding: 10px 0 20px;
+        }
+
+        pre {
+            background-color: #282c34;
+            color: #abb2bf;
+            padding: 15px;
+            border-radius: 5px;
+            overflow-x: auto;
+            font-family: "SFMono-Regular", Consolas, "Liberation Mono", Menlo, monospace;
+            font-size: 13px;
+            margin: 10px 0;
+        }
+        
+        .clone-columns {
+            display: flex;
+            gap: 10px;
+        }
+        
+        .clone-column {
+            flex: 1;
+            min-width: 0;
+        }
+        
+        .chart-container {
+            margin: 20px 0;
+        }
+        
+        .chart-container svg {
+            width: 100%;
+            height: auto;
+            font-size: 12px;
+        }
+        
+        .budget-note {
+            font-style: italic;
+            color: var(--secondary);
+        }
+        
+        .badge {
+            display: inline-block;
+            padding: 3px 8px;
+            border-radius: 20px;
+            font-size: 12px;
+            font-weight: bold;
+            color: white;
+        }
+        
+        .badge-critical {
+            background-color: #b71c1c;
+        }
+        
+        .badge-high {
+            background-color: #e65100;
+        }
+        
+        .badge-medium {
+            background-color: #ff8f00;
+        }
+        
+        .badge-low {
+            background-color: #558b2f;
+        }
+        
+        @media (max-width: 768px) {
+            .summary-stats {
+                flex-direction: column;
+            }
+            
+            .stat-card {
+                width: 100%;
+            }
+        }
+    </style>
+</head>
+<body>
+    <h1>Rapport d'analyse de performance Swift</h1>
+"#
+    );
+
+    // Résumé
+    html_content.push_str(&format!(
+        r#"
+    <div class="container">
+        <h2>Résumé du projet</h2>
+        <div class="summary-stats">
+            <div class="stat-card">
+                <h3>Fichiers analysés</h3>
+                <div class="value">{}</div>
+            </div>
+            <div class="stat-card">
+                <h3>Problèmes détectés</h3>
+                <div class="value">{}</div>
+            </div>
+            <div class="stat-card">
+                <h3>Fichiers avec problèmes</h3>
+                <div class="value">{} ({}%)</div>
+            </div>
+            <div class="stat-card">
+                <h3>Score de santé</h3>
+                <div class="value">{:.1}%</div>
+            </div>
+        </div>
+        
+        <h3>Score de santé du projet</h3>
+        <div class="health-meter">
+            <div class="health-meter-fill {}" style="width: {}%;"></div>
+        </div>
+    </div>
+"#,
+        report.project_stats.total_files,
+        report.issue_count_by_severity.values().sum::<usize>(),
+        report.project_stats.problematic_files,
+        if report.project_stats.total_files > 0 {
+            (report.project_stats.problematic_files as f64 / report.project_stats.total_files as f64) * 100.0
+        } else {
+            0.0
+        },
+        report.project_stats.health_score,
+        if report.project_stats.health_score >= 70.0 {
+            "progress-good"
+        } else if report.project_stats.health_score >= 40.0 {
+            "progress-warning"
+        } else {
+            "progress-danger"
+        },
+        report.project_stats.health_score
+    ));
+
+    if let Some(gate) = &report.quality_gate {
+        html_content.push_str(&super::quality_gate_html(gate));
+    }
+
+    // Onglets
+    html_content.push_str(
+        r#"
+    <div class="container">
+        <div class="tab">
+            <button class="tablinks active" onclick="openTab(event, 'issues')">Problèmes détectés</button>
+            <button class="tablinks" onclick="openTab(event, 'hotspots')">Points chauds</button>
+            <button class="tablinks" onclick="openTab(event, 'stats')">Statistiques</button>
+            <button class="tablinks" onclick="openTab(event, 'clones')">Code dupliqué</button>
+            <button class="tablinks" id="rules-tab" onclick="openTab(event, 'rules')">Règles</button>
+        </div>
+        
+        <div id="issues" class="tabcontent show">
+"#
+    );
+
+    // Liste des problèmes
+    if report.issue_count_by_severity.values().sum::<usize>() == 0 {
+        html_content.push_str(
+            r#"
+            <div style="text-align: center; padding: 40px;">
+                <h3 style="color: #28a745;">Aucun problème détecté !</h3>
+                <p>Votre code est en bonne santé. Continuez le bon travail!</p>
+            </div>
+"#
+        );
+    } else {
+        // Filtrer les problèmes critiques et élevés
+        let mut all_issues: Vec<(&Path, &FileIssue)> = Vec::new();
+        for result in &report.files_analyzed {
+            for issue in &result.issues {
+                if issue.severity == Severity::Critical || issue.severity == Severity::High {
+                    all_issues.push((&result.file_path, issue));
+                }
+            }
+        }
+
+        // Trier par sévérité
+        all_issues.sort_by_key(|(_, issue)| issue.severity);
+
+        html_content.push_str(
+            r#"
+            <h3>Problèmes critiques</h3>
+"#
+        );
+
+        for (file_path, issue) in all_issues {
+            let severity_class = match issue.severity {
+                Severity::Critical => "critical",
+                Severity::High => "high",
+                Severity::Medium => "medium",
+                Severity::Low => "low",
+            };
+
+            let severity_text = match issue.severity {
+                Severity::Critical => "CRITIQUE",
+                Severity::High => "ÉLEVÉ",
+                Severity::Medium => "MOYEN",
+                Severity::Low => "FAIBLE",
+            };
+
+            let issue_type_text = match issue.issue_type {
+                IssueType::HighComplexity => "Complexité élevée",
+                IssueType::DeepNesting => "Imbrication profonde",
+                IssueType::UnsafeClosure => "Closure non sécurisée",
+                IssueType::CoreDataMainThread => "CoreData sur thread principal",
+                IssueType::MissingErrorHandling => "Gestion d'erreur manquante",
+                IssueType::PotentialDataRace => "Risque de course de données",
+                IssueType::InefficientCollection => "Collection inefficace",
+                IssueType::MemoryLeak => "Fuite mémoire",
+                IssueType::ResourceLeak => "Fuite de ressource",
+                IssueType::HighCoupling => "Couplage élevé",
+                IssueType::ExcessiveComputation => "Calcul excessif",
+                IssueType::ExcessiveViewUpdates => "Mises à jour de vue excessives",
+                IssueType::InefficientBinding => "Liaison SwiftUI inefficace",
+                IssueType::MissingViewModifier => "Modificateur de vue manquant",
+                IssueType::RedundantViewModifier => "Modificateur de vue redondant",
+                IssueType::HeavyViewComputation => "Calcul lourd dans body",
+                IssueType::MissingIndex => "Index CoreData manquant",
+                IssueType::InefficientFetch => "Requête CoreData inefficace",
+                IssueType::BatchingOpportunity => "Traitement par lot possible",
+                IssueType::RedundantSaves => "Sauvegardes redondantes",
+                IssueType::MissingFetchedResultsController => "NSFetchedResultsController manquant",
+                IssueType::UnusedCode => "Code inutilisé",
+            };
+
+            let mut card = format!(
+                r#"
+            <div class="issue-card issue-{}">
+                <div class="issue-header">
+                    <span class="badge badge-{}">{}</span>
+                    <span class="issue-file">{}:{}</span>
+                </div>
+                <div class="issue-type">{}</div>
+                <div class="issue-message">{}</div>
+"#,
+                severity_class,
+                severity_class,
+                severity_text,
+                escape_html(&file_path.display().to_string()),
+                issue.line,
+                issue_type_text,
+                escape_html(&issue.message)
+            );
+
+            if let Some(rule_id) = &issue.rule_id {
+                card.push_str(&format!(
+                    r##"                <div class="issue-rule">Règle : <a href="#rule-{0}" onclick="document.getElementById('rules-tab').click()">{0}</a></div>
+"##,
+                    escape_html(rule_id)
+                ));
+            }
+
+            if let Some(suggestion) = &issue.suggestion {
+                card.push_str(&format!(
+                    r#"                <div class="issue-suggestion">Suggestion: {}</div>
+"#,
+                    escape_html(suggestion)
+                ));
+            }
+
+            if let Some(ownership) = &issue.ownership {
+                let mut attribution = Vec::new();
+                if let Some(author) = &ownership.author {
+                    attribution.push(format!("Auteur : {}", escape_html(author)));
+                }
+                if !ownership.owners.is_empty() {
+                    attribution.push(format!("Propriétaires : {}", escape_html(&ownership.owners.join(", "))));
+                }
+                if !attribution.is_empty() {
+                    card.push_str(&format!(
+                        r#"                <div class="issue-rule">{}</div>
+"#,
+                        attribution.join(" · ")
+                    ));
+                }
+            }
+
+            if let Some(snippet) = &issue.code_snippet {
+                card.push_str(&format!(
+                    r#"                <pre>{}</pre>
+"#,
+                    escape_html(snippet.trim())
+                ));
+            }
+
+            card.push_str(
+                r#"            </div>
+"#
+            );
+            issues_budget.push(&mut html_content, &card);
+        }
+        html_content.push_str(&issues_budget.omitted_note("problèmes non affichés"));
+    }
+
+    html_content.push_str(
+        r#"        </div>
+        
+        <div id="hotspots" class="tabcontent">
+            <h3>Points chauds (fichiers les plus problématiques)</h3>
+            <table>
+                <thead>
+                    <tr>
+                        <th>Rang</th>
+                        <th>Fichier</th>
+                        <th>Problèmes</th>
+                        <th>Score de criticité</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#
+    );
+
+    // Tableau des points chauds
+    for (i, hotspot) in report.hotspots.iter().enumerate() {
+        html_content.push_str(&format!(
+            r#"                <tr>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{:.1}</td>
+                </tr>
+"#,
+            i + 1,
+            hotspot.file_path.display(),
+            hotspot.issue_count,
+            hotspot.criticality_score
+        ));
+    }
+
+    html_content.push_str(
+        r#"                </tbody>
+            </table>
+"#
+    );
+
+    // Graphique des points chauds
+    let hotspot_bars: Vec<(String, f64)> = report
+        .hotspots
+        .iter()
+        .take(10)
+        .map(|hotspot| {
+            let name = hotspot.file_path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            (name, hotspot.criticality_score)
+        })
+        .collect();
+    html_content.push_str(&format!(
+        r#"            <div class="chart-container">
+{}            </div>
+"#,
+        bar_chart(&hotspot_bars, "#dc3545")
+    ));
+
+    // Points chauds selon l'historique git
+    if !report.churn_hotspots.is_empty() {
+        html_content.push_str(
+            r#"
+            <h3>Points chauds : fréquence de modification × complexité</h3>
+            <table>
+                <thead>
+                    <tr>
+                        <th>Rang</th>
+                        <th>Fichier</th>
+                        <th>Commits</th>
+                        <th>Auteurs</th>
+                        <th>Lignes modifiées récemment</th>
+                        <th>Complexité</th>
+                        <th>Score</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#
+        );
+        for (i, hotspot) in report.churn_hotspots.iter().take(10).enumerate() {
+            html_content.push_str(&format!(
+                r#"                <tr>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{:.0}</td>
+                    <td>{:.0}</td>
+                </tr>
+"#,
+                i + 1,
+                hotspot.file_path.display(),
+                hotspot.commits,
+                hotspot.authors,
+                hotspot.recent_churn,
+                hotspot.complexity,
+                hotspot.score
+            ));
+        }
+        html_content.push_str(&format!(
+            r#"                </tbody>
+            </table>
+            <div class="chart-container">
+{}            </div>
+"#,
+            churn_scatter_plot(&report.churn_hotspots)
+        ));
+    }
+
+    // Travail estimé sur le thread principal
+    if !report.main_thread_estimates.is_empty() {
+        html_content.push_str(
+            r#"
+            <h3>Travail estimé sur le thread principal</h3>
+            <table>
+                <thead>
+                    <tr>
+                        <th>Rang</th>
+                        <th>Point d'entrée</th>
+                        <th>Type</th>
+                        <th>Emplacement</th>
+                        <th>Coût estimé</th>
+                        <th>Opérations principales</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#
+        );
+
+        for (i, estimate) in report.main_thread_estimates.iter().take(20).enumerate() {
+            let operations = estimate
+                .operations
+                .iter()
+                .take(3)
+                .map(|o| {
+                    if o.call_chain.is_empty() {
+                        format!("{} (+{})", o.operation, o.cost)
+                    } else {
+                        format!("{} (+{}) via {}", o.operation, o.cost, o.call_chain.join(" → "))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("<br>");
+
+            html_content.push_str(&format!(
+                r#"                <tr>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{:?}</td>
+                    <td>{}:{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                </tr>
+"#,
+                i + 1,
+                estimate.entry_point,
+                estimate.kind,
+                estimate.file_path.display(),
+                estimate.line,
+                estimate.estimated_cost,
+                operations
+            ));
+        }
+
+        html_content.push_str(
+            r#"                </tbody>
+            </table>
+"#
+        );
+    }
+
+    // Code mort
+    if !report.dead_code.is_empty() {
+        html_content.push_str(
+            r#"
+            <h3>Code mort</h3>
+            <table>
+                <thead>
+                    <tr>
+                        <th>Déclaration</th>
+                        <th>Genre</th>
+                        <th>Emplacement</th>
+                        <th>Confiance</th>
+                        <th>Raison</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#
+        );
+
+        for finding in &report.dead_code {
+            let row = format!(
+                r#"                <tr>
+                    <td>{}</td>
+                    <td>{:?}</td>
+                    <td>{}:{}</td>
+                    <td>{:?}</td>
+                    <td>{}</td>
+                </tr>
+"#,
+                finding.name,
+                finding.kind,
+                finding.file_path.display(),
+                finding.line,
+                finding.confidence,
+                finding.reason
+            );
+            dead_code_budget.push(&mut html_content, &row);
+        }
+
+        html_content.push_str(
+            r#"                </tbody>
+            </table>
+"#
+        );
+        html_content.push_str(&dead_code_budget.omitted_note("déclarations non affichées"));
+    }
+
+    html_content.push_str(
+        r#"        </div>
+        
+        <div id="stats" class="tabcontent">
+"#
+    );
+
+    // Statistiques par sévérité et par type
+    let severity_slices: Vec<(&str, usize, &str)> = [
+        (Severity::Critical, "Critique", "#b71c1c"),
+        (Severity::High, "Élevé", "#e65100"),
+        (Severity::Medium, "Moyen", "#ff8f00"),
+        (Severity::Low, "Faible", "#558b2f"),
+    ]
+    .iter()
+    .map(|(severity, label, color)| (*label, *report.issue_count_by_severity.get(severity).unwrap_or(&0), *color))
+    .collect();
+    let mut type_bars: Vec<(String, f64)> = report
+        .issue_count_by_type
+        .iter()
+        .map(|(issue_type, count)| (type_label(*issue_type).to_string(), *count as f64))
+        .collect();
+    type_bars.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then_with(|| a.0.cmp(&b.0)));
+
+    html_content.push_str(&format!(
+        r#"            <h3>Problèmes par sévérité</h3>
+            <div class="chart-container">
+{}            </div>
+            
+            <h3>Problèmes par type</h3>
+            <div class="chart-container">
+{}            </div>
+            
+"#,
+        pie_chart(&severity_slices),
+        bar_chart(&type_bars, "#007bff")
+    ));
+
+    // Répartition par propriétaire et par auteur
+    if let Some(ownership) = &report.ownership {
+        for (title, column, summaries) in [
+            ("Problèmes par propriétaire", "Propriétaire", &ownership.owners),
+            ("Problèmes par auteur", "Auteur", &ownership.authors),
+        ] {
+            if !summaries.is_empty() {
+                html_content.push_str(&owner_table(title, column, summaries));
+            }
+        }
+    }
+    html_content.push_str(
+        r#"            <h3>Statistiques détaillées</h3>
+            <table>
+                <thead>
+                    <tr>
+                        <th>Métrique</th>
+                        <th>Valeur</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#
+    );
+
+    // Calculer des métriques supplémentaires
+    let total_issues = report.issue_count_by_severity.values().sum::<usize>();
+    let critical_issues = *report.issue_count_by_severity.get(&Severity::Critical).unwrap_or(&0);
+
+    let critical_percent = if total_issues > 0 {
+        (critical_issues as f64 / total_issues as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    html_content.push_str(&format!(
+        r#"                <tr>
+                    <td>Lignes de code totales</td>
+                    <td>{}</td>
+                </tr>
+                <tr>
+                    <td>Problèmes par 1000 lignes</td>
+                    <td>{:.2}</td>
+                </tr>
+                <tr>
+                    <td>Pourcentage de problèmes critiques</td>
+                    <td>{:.1}%</td>
+                </tr>
+                <tr>
+                    <td>Nombre de fichiers sans problèmes</td>
+                    <td>{}</td>
+                </tr>
+                <tr>
+                    <td>Code dupliqué</td>
+                    <td>{:.1}%</td>
+                </tr>
+"#,
+        report.project_stats.total_lines,
+        if report.project_stats.total_lines > 0 {
+            (total_issues as f64 * 1000.0) / report.project_stats.total_lines as f64
+        } else {
+            0.0
+        },
+        critical_percent,
+        report.project_stats.total_files - report.project_stats.problematic_files,
+        report.project_stats.duplication_percentage
+    ));
+
+    html_content.push_str(
+        r#"                </tbody>
+            </table>
+        </div>
+        
+        <div id="clones" class="tabcontent">
+"#
+    );
+
+    // Groupes de clones, occurrences côte à côte
+    html_content.push_str(&format!(
+        r#"            <h3>Code dupliqué : {:.1}% des lignes ({} groupes)</h3>
+"#,
+        report.project_stats.duplication_percentage,
+        report.clones.len()
+    ));
+
+    for group in report.clones.iter().take(30) {
+        let mut block = format!(
+            r#"            <div class="issue">
+                <div class="issue-header">
+                    <strong>{:?} - {} jetons</strong>
+                    <span>{} occurrences</span>
+                </div>
+                <div class="clone-columns">
+"#,
+            group.clone_type,
+            group.token_count,
+            group.locations.len()
+        );
+
+        for location in &group.locations {
+            block.push_str(&format!(
+                r#"                    <div class="clone-column">
+                        <div class="issue-file">{}:{}-{}</div>
+                        <pre>{}</pre>
+                    </div>
+"#,
+                location.file_path.display(),
+                location.line_start,
+                location.line_end,
+                escape_html(&location.code)
+            ));
+        }
+
+        block.push_str(
+            r#"                </div>
+            </div>
+"#
+        );
+        clones_budget.push(&mut html_content, &block);
+    }
+    html_content.push_str(&clones_budget.omitted_note("groupes de clones non affichés"));
+
+    html_content.push_str(
+        r#"        </div>
+        
+        <div id="rules" class="tabcontent">
+"#
+    );
+
+    // Explications des règles ayant signalé au moins un problème
+    html_content.push_str(&rule_explanations(report, registry));
+
+    html_content.push_str(
+        r#"        </div>
+    </div>
+"#
+    );
+
+    // Script JavaScript
+    html_content.push_str(
+        r#"
+    <script>
+        // Fonction d'ouverture des onglets
+        function openTab(evt, tabName) {
+            var i, tabcontent, tablinks;
+            tabcontent = document.getElementsByClassName("tabcontent");
+            for (i = 0; i < tabcontent.length; i++) {
+                tabcontent[i].style.display = "none";
+            }
+            tablinks = document.getElementsByClassName("tablinks");
+            for (i = 0; i < tablinks.length; i++) {
+                tablinks[i].className = tablinks[i].className.replace(" active", "");
+            }
+            document.getElementById(tabName).style.display = "block";
+            evt.currentTarget.className += " active";
+        }
+    </script>
+</body>
+</html>
+"#
+    );
+
+    html_content
+}
+
+/// Section « Règles » : explication de chaque règle présente dans le rapport
+fn rule_explanations(report: &PerformanceReport, registry: &RuleRegistry) -> String {
+    let used: HashSet<&str> = report
+        .files_analyzed
+        .iter()
+        .flat_map(|result| &result.issues)
+        .filter_map(|issue| issue.rule_id.as_deref())
+        .collect();
+    let mut section = String::new();
+
+    for metadata in registry.metadata().filter(|m| used.contains(m.id)) {
+        section.push_str(&format!(
+            r#"            <div class="rule-explanation" id="rule-{}">
+                <h3>{}</h3>
+                <div class="issue-type">{} · {:?}</div>
+                <div class="issue-message">{}</div>
+"#,
+            metadata.id,
+            metadata.id,
+            metadata.category,
+            metadata.default_severity,
+            escape_html(metadata.description)
+        ));
+
+        if let Some(explanation) = &metadata.explanation {
+            section.push_str(&format!(
+                r#"                <p>{}</p>
+                <h4>À éviter</h4>
+                <pre>{}</pre>
+                <h4>À préférer</h4>
+                <pre>{}</pre>
+"#,
+                escape_html(explanation.rationale),
+                escape_html(explanation.bad_example),
+                escape_html(explanation.good_example)
+            ));
+            for convention in explanation.conventions {
+                section.push_str(&format!(
+                    r#"                <div class="issue-suggestion">Conventions CardApp : <a href="{}">{}</a></div>
+"#,
+                    convention_link(convention),
+                    escape_html(convention)
+                ));
+            }
+        }
+
+        section.push_str(
+            r#"            </div>
+"#
+        );
+    }
+
+    section
+}
+
+/// Part du budget de taille accordée à une liste d'éléments répétés
+struct SectionBudget {
+    remaining: usize,
+    omitted: usize,
+}
+
+impl SectionBudget {
+    fn new(bytes: usize) -> Self {
+        SectionBudget { remaining: bytes, omitted: 0 }
+    }
+
+    /// Ajoute l'élément s'il tient dans le budget restant, sinon le compte comme omis
+    fn push(&mut self, html: &mut String, item: &str) {
+        if self.omitted == 0 && item.len() <= self.remaining {
+            self.remaining -= item.len();
+            html.push_str(item);
+        } else {
+            self.omitted += 1;
+        }
+    }
+
+    /// Mention des éléments omis, `what` accordé (ex. « problèmes non affichés »)
+    fn omitted_note(&self, what: &str) -> String {
+        if self.omitted == 0 {
+            return String::new();
+        }
+        format!(
+            r#"            <p class="budget-note">{} {} (taille maximale du rapport atteinte) : la liste complète figure dans le rapport JSON.</p>
+"#,
+            self.omitted, what
+        )
+    }
+}
+
+/// Libellé court d'un type de problème, pour les graphiques
+fn type_label(issue_type: IssueType) -> &'static str {
+    match issue_type {
+        IssueType::HighComplexity => "Complexité",
+        IssueType::DeepNesting => "Imbrication",
+        IssueType::UnsafeClosure => "Closure",
+        IssueType::CoreDataMainThread => "CoreData",
+        IssueType::MissingErrorHandling => "Erreurs",
+        IssueType::PotentialDataRace => "Race",
+        IssueType::InefficientCollection => "Collection",
+        IssueType::MemoryLeak => "Mémoire",
+        IssueType::ResourceLeak => "Ressource",
+        IssueType::HighCoupling => "Couplage",
+        IssueType::ExcessiveComputation => "Calcul",
+        IssueType::ExcessiveViewUpdates => "Mises à jour",
+        IssueType::InefficientBinding => "Liaison",
+        IssueType::MissingViewModifier => "Modif. manquant",
+        IssueType::RedundantViewModifier => "Modif. redondant",
+        IssueType::HeavyViewComputation => "Body",
+        IssueType::MissingIndex => "Index",
+        IssueType::InefficientFetch => "Requête",
+        IssueType::BatchingOpportunity => "Lot",
+        IssueType::RedundantSaves => "Sauvegardes",
+        IssueType::MissingFetchedResultsController => "FRC",
+        IssueType::UnusedCode => "Inutilisé",
+    }
+}
+
+/// Diagramme circulaire SVG avec légende (libellé, valeur, couleur)
+fn pie_chart(slices: &[(&str, usize, &str)]) -> String {
+    let total: usize = slices.iter().map(|(_, value, _)| value).sum();
+    let (cx, cy, r) = (110.0, 110.0, 100.0);
+    let mut svg = String::from(
+        r#"                <svg viewBox="0 0 480 220" role="img" xmlns="http://www.w3.org/2000/svg">
+"#,
+    );
+
+    if total == 0 {
+        svg.push_str(&format!(
+            r##"                    <circle cx="{cx}" cy="{cy}" r="{r}" fill="#e9ecef"/>
+"##
+        ));
+    }
+    let mut angle = -std::f64::consts::FRAC_PI_2;
+    for (label, value, color) in slices.iter().filter(|(_, value, _)| *value > 0) {
+        let title = format!("<title>{} : {}</title>", escape_html(label), value);
+        if *value == total {
+            svg.push_str(&format!(
+                r#"                    <circle cx="{cx}" cy="{cy}" r="{r}" fill="{color}">{title}</circle>
+"#
+            ));
+            continue;
+        }
+        let sweep = *value as f64 / total as f64 * std::f64::consts::TAU;
+        let (x1, y1) = (cx + r * angle.cos(), cy + r * angle.sin());
+        angle += sweep;
+        let (x2, y2) = (cx + r * angle.cos(), cy + r * angle.sin());
+        let large_arc = u8::from(sweep > std::f64::consts::PI);
+        svg.push_str(&format!(
+            r#"                    <path d="M{cx},{cy} L{x1:.2},{y1:.2} A{r},{r} 0 {large_arc} 1 {x2:.2},{y2:.2} Z" fill="{color}">{title}</path>
+"#
+        ));
+    }
+
+    for (i, (label, value, color)) in slices.iter().enumerate() {
+        let y = 40 + i * 30;
+        svg.push_str(&format!(
+            r#"                    <rect x="250" y="{}" width="14" height="14" fill="{}"/>
+                    <text x="272" y="{}">{} ({})</text>
+"#,
+            y,
+            color,
+            y + 12,
+            escape_html(label),
+            value
+        ));
+    }
+    svg.push_str("                </svg>\n");
+    svg
+}
+
+/// Histogramme horizontal SVG (libellé, valeur), une barre par ligne
+fn bar_chart(bars: &[(String, f64)], color: &str) -> String {
+    const ROW: usize = 24;
+    const LABEL_WIDTH: f64 = 200.0;
+    const BAR_WIDTH: f64 = 380.0;
+
+    let max = bars.iter().map(|(_, value)| *value).fold(0.0, f64::max);
+    let height = (bars.len() * ROW).max(ROW);
+    let mut svg = format!(
+        r#"                <svg viewBox="0 0 660 {}" role="img" xmlns="http://www.w3.org/2000/svg">
+"#,
+        height
+    );
+    for (i, (label, value)) in bars.iter().enumerate() {
+        let y = i * ROW;
+        let width = if max > 0.0 { value / max * BAR_WIDTH } else { 0.0 };
+        svg.push_str(&format!(
+            r#"                    <text x="{:.0}" y="{}" text-anchor="end">{}</text>
+                    <rect x="{:.0}" y="{}" width="{:.1}" height="{}" fill="{}"/>
+                    <text x="{:.1}" y="{}">{}</text>
+"#,
+            LABEL_WIDTH - 8.0,
+            y + 16,
+            escape_html(label),
+            LABEL_WIDTH,
+            y + 4,
+            width,
+            ROW - 8,
+            color,
+            LABEL_WIDTH + width + 6.0,
+            y + 16,
+            if value.fract() == 0.0 { format!("{}", value) } else { format!("{:.1}", value) }
+        ));
+    }
+    svg.push_str("                </svg>\n");
+    svg
+}
+
+/// Tableau des problèmes par propriétaire ou par auteur
+fn owner_table(title: &str, column: &str, summaries: &[OwnerSummary]) -> String {
+    let mut table = format!(
+        r#"            <h3>{}</h3>
+            <table>
+                <thead>
+                    <tr>
+                        <th>{}</th>
+                        <th>Fichiers</th>
+                        <th>Problèmes</th>
+                        <th>Critique</th>
+                        <th>Élevé</th>
+                        <th>Moyen</th>
+                        <th>Faible</th>
+                    </tr>
+                </thead>
+                <tbody>
+"#,
+        title, column
+    );
+    for summary in summaries {
+        let count = |severity| summary.issues_by_severity.get(&severity).copied().unwrap_or_default();
+        table.push_str(&format!(
+            r#"                <tr>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                    <td>{}</td>
+                </tr>
+"#,
+            escape_html(&summary.name),
+            summary.files,
+            summary.issues,
+            count(Severity::Critical),
+            count(Severity::High),
+            count(Severity::Medium),
+            count(Severity::Low)
+        ));
+    }
+    table.push_str(
+        r#"                </tbody>
+            </table>
+            
+"#
+    );
+    table
+}
+
+/// Nuage de points : commits en abscisse, complexité en ordonnée ; les points chauds les plus forts sont nommés
+fn churn_scatter_plot(hotspots: &[ChurnHotspot]) -> String {
+    const LEFT: f64 = 60.0;
+    const TOP: f64 = 20.0;
+    const WIDTH: f64 = 560.0;
+    const HEIGHT: f64 = 300.0;
+    const LABELED: usize = 5;
+
+    let max_commits = hotspots.iter().map(|h| h.commits as f64).fold(1.0, f64::max);
+    let max_complexity = hotspots.iter().map(|h| h.complexity).fold(1.0, f64::max);
+    let x = |commits: usize| LEFT + commits as f64 / max_commits * WIDTH;
+    let y = |complexity: f64| TOP + HEIGHT - complexity / max_complexity * HEIGHT;
+
+    let mut svg = format!(
+        r##"                <svg viewBox="0 0 660 {height}" role="img" xmlns="http://www.w3.org/2000/svg">
+                    <line x1="{left}" y1="{bottom}" x2="{right}" y2="{bottom}" stroke="#999"/>
+                    <line x1="{left}" y1="{top}" x2="{left}" y2="{bottom}" stroke="#999"/>
+                    <text x="{right}" y="{label_y}" text-anchor="end">Commits (max {max_commits})</text>
+                    <text x="{left}" y="{top_label}">Complexité (max {max_complexity:.0})</text>
+"##,
+        height = TOP + HEIGHT + 40.0,
+        left = LEFT,
+        right = LEFT + WIDTH,
+        top = TOP,
+        top_label = TOP - 6.0,
+        bottom = TOP + HEIGHT,
+        label_y = TOP + HEIGHT + 30.0,
+        max_commits = max_commits,
+        max_complexity = max_complexity,
+    );
+    // Les plus forts scores en dernier, dessinés au-dessus des autres
+    for (i, hotspot) in hotspots.iter().enumerate().rev() {
+        let (cx, cy) = (x(hotspot.commits), y(hotspot.complexity));
+        let color = if i < LABELED { "#dc3545" } else { "#007bff" };
+        svg.push_str(&format!(
+            r#"                    <circle cx="{:.1}" cy="{:.1}" r="5" fill="{}" fill-opacity="0.7"><title>{} : {} commits, complexité {:.0}</title></circle>
+"#,
+            cx,
+            cy,
+            color,
+            escape_html(&hotspot.file_path.display().to_string()),
+            hotspot.commits,
+            hotspot.complexity
+        ));
+        if i < LABELED {
+            let name = hotspot.file_path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            svg.push_str(&format!(
+                r#"                    <text x="{:.1}" y="{:.1}" text-anchor="end">{}</text>
+"#,
+                cx - 8.0,
+                cy + 4.0,
+                escape_html(&name)
+            ));
+        }
+    }
+    svg.push_str("                </svg>\n");
+    svg
+}
+
+/// Échappe les caractères spéciaux HTML d'un extrait de code
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::PRINT_CALL;
+    use crate::test_support::NoPrintRule;
+
+    #[test]
+    fn test_self_contained_report_within_budget() {
+        let source: String = (0..200).map(|i| format!("class Editor{} {{\n    var delegate: EditorDelegate?\n}}\n", i)).collect();
+        let report = Analyzer::new(AnalysisConfig::default()).with_source("Editor.swift", source).run();
+
+        let full = to_html(&report, &RuleRegistry::builtin(), HtmlOptions::default());
+        assert!(!full.contains("src=\"http") && !full.contains("href=\"http"));
+        assert!(full.contains("<svg viewBox=\"0 0 480 220\""));
+        assert!(!full.contains("budget-note\">"));
+
+        let small = to_html(&report, &RuleRegistry::builtin(), HtmlOptions { size_budget: 100_000 });
+        assert!(small.len() < full.len());
+        assert!(small.contains("problèmes non affichés (taille maximale du rapport atteinte)"));
+    }
+
+    #[test]
+    fn test_custom_rule_explained_from_the_analysis_registry() {
+        let mut registry = RuleRegistry::new();
+        registry.register(NoPrintRule);
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(registry.clone())
+            .with_source("Deck.swift", PRINT_CALL)
+            .run();
+
+        let html = to_html(&report, &registry, HtmlOptions::default());
+        assert!(html.contains(r#"<div class="rule-explanation" id="rule-cardapp.no-print">"#));
+        assert!(html.contains("Appel à print() dans le code de production</div>"));
+        assert!(!to_html(&report, &RuleRegistry::builtin(), HtmlOptions::default()).contains("id=\"rule-cardapp.no-print\""));
+    }
+}
@@ -0,0 +1,359 @@
+//! Rapport HTML interactif : navigateur de fichiers, source coloré et problèmes annotés dans la marge
+//!
+//! Le rapport tient dans un seul fichier, sans ressource externe. Les sources
+//! sont relues sur le disque au moment de la génération ; seuls les fichiers
+//! comportant des problèmes sont inclus.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use syntect::highlighting::ThemeSet;
+use syntect::html::{css_for_theme_with_class_style, line_tokens_to_classed_spans, ClassStyle};
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxDefinition, SyntaxSet, SyntaxSetBuilder};
+use syntect::util::LinesWithEndings;
+
+use super::escape_xml as escape;
+use crate::discovery::relative_path;
+use crate::models::{AnalysisResult, FileIssue, PerformanceReport, Severity};
+
+/// Préfixe des classes de coloration, pour ne pas entrer en conflit avec celles du rapport
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+
+lazy_static! {
+    /// Grammaire Swift du dépôt, compilée à part : reconstruire l'ensemble par défaut serait bien plus lent
+    static ref SWIFT_SYNTAXES: SyntaxSet = {
+        let mut builder = SyntaxSetBuilder::new();
+        let swift = SyntaxDefinition::load_from_str(include_str!("../../assets/Swift.sublime-syntax"), true, None)
+            .expect("grammaire Swift invalide");
+        builder.add(swift);
+        builder.add_plain_text_syntax();
+        builder.build()
+    };
+    /// Grammaires fournies par syntect (Objective-C, etc.)
+    static ref DEFAULT_SYNTAXES: SyntaxSet = SyntaxSet::load_defaults_newlines();
+}
+
+pub fn generate_report(report: &PerformanceReport, project_root: &Path, output_path: &Path) -> Result<(), String> {
+    fs::write(output_path, to_html(report, project_root))
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport HTML interactif {}: {}", output_path.display(), e))
+}
+
+pub fn to_html(report: &PerformanceReport, project_root: &Path) -> String {
+    let mut files: Vec<(String, &AnalysisResult)> = report
+        .files_analyzed
+        .iter()
+        .filter(|result| !result.issues.is_empty())
+        .map(|result| (relative_path(project_root, &result.file_path), result))
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let rules: BTreeSet<&str> = files
+        .iter()
+        .flat_map(|(_, result)| &result.issues)
+        .filter_map(|issue| issue.rule_id.as_deref())
+        .collect();
+    let directories: BTreeSet<String> = files
+        .iter()
+        .flat_map(|(path, _)| {
+            let parts: Vec<&str> = path.split('/').collect();
+            (1..parts.len()).map(move |n| parts[..n].join("/"))
+        })
+        .collect();
+
+    let theme_css = ThemeSet::load_defaults()
+        .themes
+        .get("InspiredGitHub")
+        .and_then(|theme| css_for_theme_with_class_style(theme, CLASS_STYLE).ok())
+        .unwrap_or_default();
+
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        r#"<!DOCTYPE html>
+<html lang="fr">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Rapport interactif d'analyse Swift</title>
+    <style>
+{STYLE}
+{theme_css}
+    </style>
+</head>
+<body>
+    <header>
+        <h1>Analyse Swift <span class="score">Score de santé : {:.1}%</span></h1>
+        <div class="filters">
+            <input type="search" id="search" placeholder="Rechercher (message, règle, fichier)">
+"#,
+        report.project_stats.health_score
+    );
+
+    for severity in [Severity::Critical, Severity::High, Severity::Medium, Severity::Low] {
+        let _ = writeln!(
+            html,
+            r#"            <label class="sev-{0}"><input type="checkbox" class="severity" value="{0}" checked> {1} ({2})</label>"#,
+            severity_class(severity),
+            severity_label(severity),
+            report.issue_count_by_severity.get(&severity).unwrap_or(&0)
+        );
+    }
+
+    html.push_str("            <select id=\"rule\">\n                <option value=\"\">Toutes les règles</option>\n");
+    for rule in &rules {
+        let _ = writeln!(html, r#"                <option value="{0}">{0}</option>"#, escape(rule));
+    }
+    html.push_str("            </select>\n            <select id=\"directory\">\n                <option value=\"\">Tous les répertoires</option>\n");
+    for directory in &directories {
+        let _ = writeln!(html, r#"                <option value="{0}/">{0}</option>"#, escape(directory));
+    }
+    html.push_str("            </select>\n        </div>\n    </header>\n    <div class=\"layout\">\n        <nav>\n            <ul id=\"files\">\n");
+
+    for (index, (path, result)) in files.iter().enumerate() {
+        let _ = writeln!(
+            html,
+            r##"                <li data-path="{0}"><a href="#file-{1}">{0}</a> <span class="count">{2}</span></li>"##,
+            escape(path),
+            index,
+            result.issues.len()
+        );
+    }
+    html.push_str("            </ul>\n        </nav>\n        <main>\n");
+    if let Some(gate) = &report.quality_gate {
+        html.push_str(&super::quality_gate_html(gate));
+    }
+
+    if files.is_empty() {
+        html.push_str("            <p>Aucun problème détecté.</p>\n");
+    }
+    let sections: Vec<String> = files
+        .par_iter()
+        .enumerate()
+        .map(|(index, (path, result))| source_section(index, path, result))
+        .collect();
+    html.extend(sections);
+
+    let _ = write!(html, "        </main>\n    </div>\n    <script>\n{SCRIPT}    </script>\n</body>\n</html>\n");
+    html
+}
+
+/// Fichier coloré ligne à ligne, chaque problème inséré sous sa ligne et signalé dans la marge
+fn source_section(index: usize, path: &str, result: &AnalysisResult) -> String {
+    let mut html = String::new();
+    let mut issues: Vec<&FileIssue> = result.issues.iter().collect();
+    issues.sort_by_key(|issue| (issue.line, issue.severity as u8));
+
+    let _ = write!(
+        html,
+        r#"            <section class="source" id="file-{}" data-path="{}">
+                <h2>{}</h2>
+                <table class="code">
+"#,
+        index,
+        escape(path),
+        escape(path)
+    );
+
+    let lines = fs::read_to_string(&result.file_path).map(|content| highlight(&content, path)).unwrap_or_default();
+    let mut next = 0;
+    for (number, line) in lines.iter().enumerate().map(|(i, line)| (i + 1, line)) {
+        let end = issues[next..].iter().take_while(|issue| issue.line <= number).count() + next;
+        let markers: String = issues[next..end]
+            .iter()
+            .map(|issue| format!(r#"<span class="marker sev-{}" {}></span>"#, severity_class(issue.severity), issue_data(path, issue)))
+            .collect();
+        let _ = writeln!(
+            html,
+            r#"                    <tr id="file-{index}-L{number}"><td class="gutter">{markers}{number}</td><td class="line"><pre>{line}</pre></td></tr>"#
+        );
+        for issue in &issues[next..end] {
+            annotation(&mut html, path, issue);
+        }
+        next = end;
+    }
+    // Problèmes sans ligne correspondante (fichier illisible ou modifié depuis l'analyse)
+    for issue in &issues[next..] {
+        annotation(&mut html, path, issue);
+    }
+
+    html.push_str("                </table>\n            </section>\n");
+    html
+}
+
+fn annotation(html: &mut String, path: &str, issue: &FileIssue) {
+    let _ = write!(
+        html,
+        r#"                    <tr class="annotation sev-{}" {}><td class="gutter"></td><td><span class="badge">{}</span> <code>{}</code> ligne {} : {}"#,
+        severity_class(issue.severity),
+        issue_data(path, issue),
+        severity_label(issue.severity),
+        escape(issue.rule_id.as_deref().unwrap_or("-")),
+        issue.line,
+        escape(&issue.message)
+    );
+    if let Some(suggestion) = &issue.suggestion {
+        let _ = write!(html, r#"<div class="suggestion">{}</div>"#, escape(suggestion));
+    }
+    html.push_str("</td></tr>\n");
+}
+
+/// Attributs lus par les filtres (marqueur de marge et annotation partagent les mêmes)
+fn issue_data(path: &str, issue: &FileIssue) -> String {
+    let rule = issue.rule_id.as_deref().unwrap_or_default();
+    let text = format!("{} {} {} {}", path, rule, issue.message, issue.suggestion.as_deref().unwrap_or_default());
+    format!(
+        r#"data-issue data-severity="{}" data-rule="{}" data-text="{}""#,
+        severity_class(issue.severity),
+        escape(rule),
+        escape(&text.to_lowercase())
+    )
+}
+
+/// Source coloré ligne par ligne ; chaque ligne est un fragment HTML autonome (balises refermées)
+fn highlight(content: &str, path: &str) -> Vec<String> {
+    let extension = path.rsplit('.').next().unwrap_or_default();
+    let syntaxes: &SyntaxSet = if extension == "swift" { &SWIFT_SYNTAXES } else { &DEFAULT_SYNTAXES };
+    let syntax = syntaxes
+        .find_syntax_by_extension(extension)
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut lines = Vec::new();
+
+    for line in LinesWithEndings::from(content) {
+        let reopened: String = stack.as_slice().iter().map(|scope| open_span(*scope)).collect();
+        let highlighted = state
+            .parse_line(line, syntaxes)
+            .ok()
+            .and_then(|ops| line_tokens_to_classed_spans(line, &ops, CLASS_STYLE, &mut stack).ok());
+        // Les portées encore ouvertes en fin de ligne sont refermées ici et rouvertes à la ligne suivante
+        let text = match highlighted {
+            Some((spans, _)) => format!("{}{}{}", reopened, spans.replace(['\n', '\r'], ""), "</span>".repeat(stack.as_slice().len())),
+            None => escape(line.trim_end_matches(['\n', '\r'])),
+        };
+        lines.push(text);
+    }
+    lines
+}
+
+fn open_span(scope: Scope) -> String {
+    let classes: Vec<String> = scope.build_string().split('.').map(|atom| format!("hl-{}", atom)).collect();
+    format!(r#"<span class="{}">"#, classes.join(" "))
+}
+
+fn severity_class(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::High => "high",
+        Severity::Medium => "medium",
+        Severity::Low => "low",
+    }
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "Critique",
+        Severity::High => "Élevé",
+        Severity::Medium => "Moyen",
+        Severity::Low => "Faible",
+    }
+}
+
+const STYLE: &str = r#"        body { margin: 0; font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, sans-serif; color: #333; }
+        header { position: sticky; top: 0; z-index: 1; background: #f8f9fa; border-bottom: 1px solid #ddd; padding: 10px 20px; }
+        h1 { margin: 0 0 8px; font-size: 20px; }
+        h1 .score { font-size: 14px; font-weight: normal; color: #6c757d; margin-left: 12px; }
+        .filters { display: flex; flex-wrap: wrap; gap: 12px; align-items: center; font-size: 14px; }
+        .filters input[type=search] { min-width: 260px; padding: 4px 8px; }
+        .layout { display: flex; }
+        nav { width: 320px; flex-shrink: 0; border-right: 1px solid #ddd; height: calc(100vh - 90px); overflow-y: auto; position: sticky; top: 90px; }
+        nav ul { list-style: none; margin: 0; padding: 8px 0; }
+        nav li { padding: 3px 12px; font-family: monospace; font-size: 12px; word-break: break-all; }
+        nav li.current { background: #e7f1ff; }
+        nav a { color: #0056b3; text-decoration: none; }
+        .count { float: right; color: #6c757d; }
+        main { flex: 1; min-width: 0; padding: 0 20px 40px; }
+        h2 { font-family: monospace; font-size: 15px; }
+        table.code { border-collapse: collapse; width: 100%; font-family: "SFMono-Regular", Consolas, Menlo, monospace; font-size: 12px; }
+        td.gutter { width: 1%; white-space: nowrap; text-align: right; color: #999; padding: 0 8px; user-select: none; vertical-align: top; }
+        td.line pre { margin: 0; white-space: pre-wrap; }
+        .marker { display: inline-block; width: 8px; height: 8px; border-radius: 50%; margin-right: 4px; }
+        tr.annotation td { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, sans-serif; padding: 4px 8px; border-left: 4px solid; background: #fff8e6; }
+        .suggestion { font-style: italic; color: #2e7d32; }
+        .badge { font-weight: bold; }
+        .sev-critical { border-color: #b71c1c; } .marker.sev-critical { background: #b71c1c; } .sev-critical .badge, label.sev-critical { color: #b71c1c; }
+        .sev-high { border-color: #e65100; } .marker.sev-high { background: #e65100; } .sev-high .badge, label.sev-high { color: #e65100; }
+        .sev-medium { border-color: #ff8f00; } .marker.sev-medium { background: #ff8f00; } .sev-medium .badge, label.sev-medium { color: #ff8f00; }
+        .sev-low { border-color: #558b2f; } .marker.sev-low { background: #558b2f; } .sev-low .badge, label.sev-low { color: #558b2f; }
+        [hidden] { display: none !important; }"#;
+
+const SCRIPT: &str = r#"        var sections = Array.prototype.slice.call(document.querySelectorAll('section.source'));
+        var entries = Array.prototype.slice.call(document.querySelectorAll('#files li'));
+
+        // Affiche un seul fichier à la fois (ancre #file-N, éventuellement #file-N-L12)
+        function showFile() {
+            var hash = location.hash.slice(1);
+            var current = sections.filter(function (s) { return hash === s.id || hash.indexOf(s.id + '-') === 0; })[0];
+            if (!current) {
+                current = sections.filter(function (s) { return !entries[sections.indexOf(s)].hidden; })[0];
+            }
+            sections.forEach(function (s, i) {
+                s.hidden = s !== current;
+                entries[i].className = s === current ? 'current' : '';
+            });
+            var line = hash && document.getElementById(hash);
+            if (line && line.tagName === 'TR') { line.scrollIntoView({ block: 'center' }); }
+        }
+
+        // Filtres : sévérité, règle, répertoire et texte libre
+        function applyFilters() {
+            var severities = Array.prototype.slice.call(document.querySelectorAll('input.severity'))
+                .filter(function (c) { return c.checked; }).map(function (c) { return c.value; });
+            var rule = document.getElementById('rule').value;
+            var directory = document.getElementById('directory').value;
+            var query = document.getElementById('search').value.trim().toLowerCase();
+            sections.forEach(function (section, i) {
+                var inDirectory = section.getAttribute('data-path').indexOf(directory) === 0;
+                var visible = 0;
+                section.querySelectorAll('[data-issue]').forEach(function (issue) {
+                    var shown = inDirectory
+                        && severities.indexOf(issue.getAttribute('data-severity')) >= 0
+                        && (!rule || issue.getAttribute('data-rule') === rule)
+                        && (!query || issue.getAttribute('data-text').indexOf(query) >= 0);
+                    issue.hidden = !shown;
+                    if (shown && issue.tagName === 'TR') { visible++; }
+                });
+                entries[i].hidden = visible === 0;
+                entries[i].querySelector('.count').textContent = visible;
+            });
+            showFile();
+        }
+
+        document.querySelectorAll('.filters input, .filters select').forEach(function (control) {
+            control.addEventListener('input', applyFilters);
+        });
+        window.addEventListener('hashchange', showFile);
+        applyFilters();
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlighted_lines_are_self_contained() {
+        let lines = highlight("/* début\n   fin */ let title = \"Carte \\(count)\"\nfunc tap() {}\n", "Card.swift");
+
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            assert_eq!(line.matches("<span").count(), line.matches("</span>").count(), "{}", line);
+        }
+        assert!(lines[0].starts_with(r#"<span class="hl-source hl-swift"><span class="hl-comment hl-block hl-swift">"#));
+        assert!(lines[1].contains(r#"<span class="hl-storage hl-type hl-swift">let</span>"#));
+        assert!(lines[2].contains(r#"<span class="hl-entity hl-name hl-function hl-swift">tap</span>"#));
+    }
+}
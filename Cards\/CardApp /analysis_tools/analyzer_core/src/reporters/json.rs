@@ -0,0 +1,20 @@
+//! Rapport JSON : sérialisation complète du rapport d'analyse
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::models::PerformanceReport;
+
+/// Génère un rapport au format JSON
+pub fn generate_report(report: &PerformanceReport, output_path: &Path) -> Result<(), String> {
+    let json_content =
+        serde_json::to_string_pretty(report).map_err(|e| format!("Erreur lors de la sérialisation JSON: {}", e))?;
+
+    let mut file = File::create(output_path).map_err(|e| {
+        format!("Erreur lors de la création du fichier de rapport JSON {}: {}", output_path.display(), e)
+    })?;
+
+    file.write_all(json_content.as_bytes())
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport JSON {}: {}", output_path.display(), e))
+}
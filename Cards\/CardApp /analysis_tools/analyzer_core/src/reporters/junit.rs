@@ -0,0 +1,189 @@
+//! JUnit XML : un cas de test par fichier ou par règle, un échec par problème
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::escape_xml;
+use crate::models::{FileIssue, PerformanceReport, QualityGateResult};
+use crate::rules::RuleRegistry;
+
+/// Découpage des cas de test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Grouping {
+    /// Un cas par fichier analysé
+    #[default]
+    File,
+    /// Un cas par règle active
+    Rule,
+}
+
+impl FromStr for Grouping {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "file" => Ok(Grouping::File),
+            "rule" => Ok(Grouping::Rule),
+            _ => Err(format!("Regroupement JUnit inconnu: {} (file, rule)", value)),
+        }
+    }
+}
+
+/// Génère un rapport JUnit ; `registry` fournit les règles sans problème (cas réussis) en regroupement par règle
+pub fn generate_report(
+    report: &PerformanceReport,
+    grouping: Grouping,
+    registry: &RuleRegistry,
+    output_path: &Path,
+) -> Result<(), String> {
+    fs::write(output_path, to_xml(report, grouping, registry))
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport JUnit {}: {}", output_path.display(), e))
+}
+
+pub fn to_xml(report: &PerformanceReport, grouping: Grouping, registry: &RuleRegistry) -> String {
+    let cases: Vec<(String, Vec<(&Path, &FileIssue)>)> = match grouping {
+        Grouping::File => report
+            .files_analyzed
+            .iter()
+            .map(|result| {
+                let failures = result.issues.iter().map(|issue| (result.file_path.as_path(), issue)).collect();
+                (result.file_path.display().to_string(), failures)
+            })
+            .collect(),
+        Grouping::Rule => {
+            let mut by_rule: BTreeMap<String, Vec<(&Path, &FileIssue)>> = registry
+                .metadata()
+                .filter(|metadata| registry.is_enabled(metadata.id))
+                .map(|metadata| (metadata.id.to_string(), Vec::new()))
+                .collect();
+            for result in &report.files_analyzed {
+                for issue in &result.issues {
+                    let rule_id = issue.rule_id.clone().unwrap_or_else(|| "unknown".to_string());
+                    by_rule.entry(rule_id).or_default().push((result.file_path.as_path(), issue));
+                }
+            }
+            by_rule.into_iter().collect()
+        }
+    };
+
+    let tests = cases.len();
+    // `failures` compte les cas en échec, chacun pouvant porter plusieurs éléments `failure`
+    let failures = cases.iter().filter(|(_, issues)| !issues.is_empty()).count();
+    let classname = match grouping {
+        Grouping::File => "swift-analyzer.files",
+        Grouping::Rule => "swift-analyzer.rules",
+    };
+
+    let gate = report.quality_gate.as_ref();
+    let gate_tests = gate.map_or(0, |gate| gate.conditions.len());
+    let gate_failures = gate.map_or(0, |gate| gate.conditions.iter().filter(|condition| !condition.passed).count());
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        r#"<testsuites name="swift-analyzer" tests="{}" failures="{}">"#,
+        tests + gate_tests,
+        failures + gate_failures
+    );
+    if let Some(gate) = gate {
+        quality_gate_suite(&mut xml, gate);
+    }
+    let _ = writeln!(xml, r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0" skipped="0">"#, classname, tests, failures);
+    for (name, issues) in &cases {
+        if issues.is_empty() {
+            let _ = writeln!(xml, r#"    <testcase name="{}" classname="{}"/>"#, escape_xml(name), classname);
+            continue;
+        }
+        let _ = writeln!(xml, r#"    <testcase name="{}" classname="{}">"#, escape_xml(name), classname);
+        for (path, issue) in issues {
+            let mut details = format!("{}:{}: [{:?}] {}", path.display(), issue.line, issue.severity, issue.message);
+            if let Some(snippet) = &issue.code_snippet {
+                let _ = write!(details, "\n{}", snippet.trim());
+            }
+            if let Some(suggestion) = &issue.suggestion {
+                let _ = write!(details, "\nSuggestion: {}", suggestion);
+            }
+            let _ = writeln!(
+                xml,
+                r#"      <failure message="{}" type="{}">{}</failure>"#,
+                escape_xml(&issue.message),
+                escape_xml(issue.rule_id.as_deref().unwrap_or("unknown")),
+                escape_xml(&details)
+            );
+        }
+        xml.push_str("    </testcase>\n");
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// Suite `swift-analyzer.quality-gate` : un cas par condition de la porte qualité
+fn quality_gate_suite(xml: &mut String, gate: &QualityGateResult) {
+    const CLASSNAME: &str = "swift-analyzer.quality-gate";
+    let failures = gate.conditions.iter().filter(|condition| !condition.passed).count();
+    let _ = writeln!(
+        xml,
+        r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0" skipped="0">"#,
+        CLASSNAME,
+        gate.conditions.len(),
+        failures
+    );
+    for condition in &gate.conditions {
+        if condition.passed {
+            let _ = writeln!(xml, r#"    <testcase name="{}" classname="{}"/>"#, escape_xml(&condition.id), CLASSNAME);
+            continue;
+        }
+        let message = format!("{} : {} (seuil {})", condition.description, condition.actual, condition.threshold);
+        let _ = writeln!(xml, r#"    <testcase name="{}" classname="{}">"#, escape_xml(&condition.id), CLASSNAME);
+        let _ = writeln!(
+            xml,
+            r#"      <failure message="{}" type="quality-gate">{}</failure>"#,
+            escape_xml(&message),
+            escape_xml(&condition.details.join("\n"))
+        );
+        xml.push_str("    </testcase>\n");
+    }
+    xml.push_str("  </testsuite>\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::{BACKGROUND_VIEW_CONTEXT, CLOSURE_SELF_CAPTURE, PRINT_CALL, STRONG_DELEGATE};
+    use crate::test_support::{focused_registry, NoPrintRule};
+
+    #[test]
+    fn test_one_case_per_rule_with_failures() {
+        let mut registry = focused_registry();
+        registry.register(NoPrintRule);
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(registry.clone())
+            .with_source("Editor.swift", STRONG_DELEGATE)
+            .with_source("DeckLoader.swift", CLOSURE_SELF_CAPTURE)
+            .with_source("Import.swift", BACKGROUND_VIEW_CONTEXT)
+            .with_source("Shuffle.swift", PRINT_CALL)
+            .with_source("Deck.swift", "struct Deck {}\n")
+            .run();
+
+        let by_rule = to_xml(&report, Grouping::Rule, &registry);
+        assert!(by_rule.contains(r#"<testcase name="memory.strong-delegate" classname="swift-analyzer.rules">"#));
+        assert!(by_rule.contains(r#"<testcase name="memory.closure-self-capture" classname="swift-analyzer.rules">"#));
+        assert!(by_rule.contains(r#"<testcase name="complexity.cyclomatic" classname="swift-analyzer.rules"/>"#));
+        assert!(!by_rule.contains("deadcode.unused-declaration"));
+        assert!(by_rule.contains(r#"<testcase name="cardapp.no-print" classname="swift-analyzer.rules">"#));
+        assert!(by_rule.contains("Editor.swift:2: [High]"));
+        assert!(by_rule.contains("DeckLoader.swift:3: [High]"));
+        assert!(by_rule.contains("Import.swift:3: [Critical]"));
+        assert!(by_rule.contains("Shuffle.swift:2: [Low]"));
+
+        let by_file = to_xml(&report, Grouping::File, &registry);
+        assert!(by_file.contains(r#"<testsuite name="swift-analyzer.files" tests="5" failures="4""#));
+        assert!(by_file.contains(r#"<testcase name="Deck.swift" classname="swift-analyzer.files"/>"#));
+        assert!(by_file.contains(r#"type="memory.closure-self-capture""#));
+    }
+}
@@ -0,0 +1,290 @@
+//! Formats de sortie des anciens outils, conservés pour les scripts qui les consomment
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{calculate_health_score, recount_issues};
+use crate::models::{AnalysisResult, FileIssue, FileMetrics, IssueType, PerformanceReport, ProjectStats, Severity};
+
+/// Sévérité de l'ancien `swift_performance_analyzer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+enum LegacySeverity {
+    Critical,
+    Error,
+    Warning,
+}
+
+impl From<Severity> for LegacySeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Critical => LegacySeverity::Critical,
+            Severity::High => LegacySeverity::Error,
+            Severity::Medium | Severity::Low => LegacySeverity::Warning,
+        }
+    }
+}
+
+impl From<LegacySeverity> for Severity {
+    fn from(severity: LegacySeverity) -> Self {
+        match severity {
+            LegacySeverity::Critical => Severity::Critical,
+            LegacySeverity::Error => Severity::High,
+            LegacySeverity::Warning => Severity::Medium,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LegacyIssue {
+    file: String,
+    line: usize,
+    message: String,
+    severity: LegacySeverity,
+    category: String,
+    code: String,
+    suggestion: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LegacyAnalysisReport {
+    timestamp: u64,
+    project: String,
+    files_analyzed: usize,
+    total_issues: usize,
+    issues: Vec<LegacyIssue>,
+    categories: HashMap<String, usize>,
+}
+
+/// Génère le rapport JSON de l'ancien `swift_performance_analyzer`
+/// (`rapports_optimisation/performance_analysis.json`)
+pub fn generate_swift_analyzer_report(report: &PerformanceReport, project: &str, output_path: &Path) -> Result<(), String> {
+    let mut issues: Vec<LegacyIssue> = all_issues(report)
+        .map(|(file, issue)| LegacyIssue {
+            file: file.display().to_string(),
+            line: issue.line,
+            message: issue.message.clone(),
+            severity: issue.severity.into(),
+            category: category(issue.issue_type).to_string(),
+            code: issue.code_snippet.as_deref().unwrap_or_default().trim().to_string(),
+            suggestion: issue.suggestion.clone().unwrap_or_default(),
+        })
+        .collect();
+    issues.sort_by_key(|issue| issue.severity);
+
+    let mut categories = HashMap::new();
+    for issue in &issues {
+        *categories.entry(issue.category.clone()).or_insert(0) += 1;
+    }
+
+    let legacy_report = LegacyAnalysisReport {
+        timestamp: unix_timestamp(),
+        project: project.to_string(),
+        files_analyzed: report.project_stats.total_files,
+        total_issues: issues.len(),
+        issues,
+        categories,
+    };
+
+    let json_content =
+        serde_json::to_string_pretty(&legacy_report).map_err(|e| format!("Erreur lors de la sérialisation JSON: {}", e))?;
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Erreur lors de la création du répertoire {}: {}", parent.display(), e))?;
+    }
+
+    fs::write(output_path, json_content)
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport JSON {}: {}", output_path.display(), e))
+}
+
+/// Relit un rapport `performance_analysis.json` de l'ancien analyseur, pour le comparer avec `diff`
+///
+/// Ce format ne conserve ni les règles, ni les métriques, ni les fichiers sans problème :
+/// les sévérités Medium et Low y sont confondues (relues en Medium), le type de problème est
+/// déduit de la catégorie et le score de santé est recalculé sans le nombre de lignes.
+pub fn parse_swift_analyzer_report(content: &str) -> serde_json::Result<PerformanceReport> {
+    let legacy_report: LegacyAnalysisReport = serde_json::from_str(content)?;
+
+    let mut files: BTreeMap<String, Vec<FileIssue>> = BTreeMap::new();
+    for issue in legacy_report.issues {
+        files.entry(issue.file).or_default().push(FileIssue {
+            issue_type: issue_type(&issue.category),
+            severity: issue.severity.into(),
+            line: issue.line,
+            column: None,
+            message: issue.message,
+            suggestion: Some(issue.suggestion).filter(|suggestion| !suggestion.is_empty()),
+            code_snippet: Some(issue.code).filter(|code| !code.is_empty()),
+            rule_id: None,
+            declaration: None,
+            ownership: None,
+            fix: None,
+        });
+    }
+
+    let mut report = PerformanceReport {
+        project_root: Some(PathBuf::from(legacy_report.project)),
+        files_analyzed: files
+            .into_iter()
+            .map(|(file, mut issues)| {
+                issues.sort_by_key(|issue| issue.line);
+                AnalysisResult {
+                    file_path: PathBuf::from(file),
+                    file_size: 0,
+                    line_count: 0,
+                    metrics: FileMetrics::default(),
+                    issues,
+                    suppressed_issues: Vec::new(),
+                    baseline_issues: Vec::new(),
+                }
+            })
+            .collect(),
+        issue_count_by_type: HashMap::new(),
+        issue_count_by_severity: HashMap::new(),
+        hotspots: Vec::new(),
+        churn_hotspots: Vec::new(),
+        project_stats: ProjectStats {
+            total_files: legacy_report.files_analyzed,
+            problematic_files: 0,
+            total_lines: 0,
+            health_score: 100.0,
+            duplication_percentage: 0.0,
+        },
+        main_thread_estimates: Vec::new(),
+        dead_code: Vec::new(),
+        clones: Vec::new(),
+        baseline: None,
+        ownership: None,
+        cache: None,
+        quality_gate: None,
+        warnings: Vec::new(),
+    };
+    recount_issues(&mut report);
+    calculate_health_score(&mut report);
+    Ok(report)
+}
+
+/// Génère le script commenté de corrections (`swift_auto_fix.sh`) de l'ancien analyseur
+pub fn generate_fix_script(report: &PerformanceReport, output_path: &Path) -> Result<(), String> {
+    let mut script = String::new();
+    script.push_str("#!/bin/bash\n\n");
+    script.push_str("# Script de correction automatique généré par Swift Performance Analyzer\n");
+    let _ = writeln!(script, "# Date: {}\n", unix_timestamp());
+    script.push_str("echo 'Application des corrections automatiques...'\n\n");
+
+    for result in report.files_analyzed.iter().filter(|r| !r.issues.is_empty()) {
+        let _ = writeln!(script, "echo 'Traitement du fichier: {}'", result.file_path.display());
+
+        for issue in &result.issues {
+            let _ = writeln!(script, "# Ligne {}: {}", issue.line, issue.message);
+            match issue.issue_type {
+                IssueType::UnsafeClosure => {
+                    let _ = writeln!(script, "# Ajoutez [weak self] au début de la closure ligne {}\n", issue.line);
+                }
+                IssueType::HighComplexity | IssueType::DeepNesting => {
+                    let _ = writeln!(script, "# Refactorisez la fonction à la ligne {}\n", issue.line);
+                }
+                _ => {
+                    let _ = writeln!(script, "# Suggestion: {}\n", issue.suggestion.as_deref().unwrap_or_default());
+                }
+            }
+        }
+    }
+
+    script.push_str("echo 'Corrections automatiques terminées.'\n");
+
+    fs::write(output_path, script).map_err(|e| {
+        format!("Erreur lors de l'écriture du script de correction {}: {}", output_path.display(), e)
+    })?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(output_path, fs::Permissions::from_mode(0o755));
+    }
+    Ok(())
+}
+
+/// Génère le rapport Markdown de l'ancien `analyze_performance.rs`
+/// (`performance_analysis_report.md`)
+pub fn generate_markdown_report(report: &PerformanceReport, elapsed: Duration, output_path: &Path) -> Result<(), String> {
+    let mut markdown = String::new();
+
+    markdown.push_str("# Rapport d'Analyse de Performance\n");
+    markdown.push_str("\n## Métriques Globales\n");
+    let _ = writeln!(markdown, "- Temps d'analyse: {:?}", elapsed);
+    let _ = writeln!(markdown, "- Nombre de fichiers analysés: {}", report.files_analyzed.len());
+
+    markdown.push_str("\n## Problèmes de Performance\n");
+    for (file, issue) in all_issues(report) {
+        let _ = writeln!(markdown, "### {} ({}): {:?}", file.display(), issue.line, issue.issue_type);
+        let _ = writeln!(markdown, "- Description: {}", issue.message);
+        let _ = writeln!(markdown, "- Sévérité: {:?}", LegacySeverity::from(issue.severity));
+    }
+
+    markdown.push_str("\n## Métriques par Fichier\n");
+    for result in &report.files_analyzed {
+        let metrics = &result.metrics;
+        let total_complexity = metrics.avg_cyclomatic_complexity * metrics.functions_count as f64;
+        let _ = writeln!(markdown, "### {}", result.file_path.display());
+        let _ = writeln!(markdown, "- Complexité: {}", total_complexity.round() as usize);
+        let _ = writeln!(markdown, "- Nombre de méthodes: {}", metrics.functions_count);
+        let _ = writeln!(markdown, "- Appels asynchrones: {}", metrics.async_operation_count);
+        let _ = writeln!(markdown, "- Appels CoreData: {}", metrics.core_data_operation_count);
+    }
+
+    fs::write(output_path, markdown)
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport Markdown {}: {}", output_path.display(), e))
+}
+
+fn all_issues(report: &PerformanceReport) -> impl Iterator<Item = (&Path, &FileIssue)> {
+    report
+        .files_analyzed
+        .iter()
+        .flat_map(|result| result.issues.iter().map(move |issue| (result.file_path.as_path(), issue)))
+}
+
+/// Catégorie de l'ancien analyseur correspondant à un type de problème
+fn category(issue_type: IssueType) -> &'static str {
+    match issue_type {
+        IssueType::UnsafeClosure | IssueType::MemoryLeak | IssueType::ResourceLeak => "memory",
+        IssueType::PotentialDataRace => "concurrency",
+        IssueType::CoreDataMainThread
+        | IssueType::MissingErrorHandling
+        | IssueType::MissingIndex
+        | IssueType::InefficientFetch
+        | IssueType::BatchingOpportunity
+        | IssueType::RedundantSaves
+        | IssueType::MissingFetchedResultsController => "coredata",
+        IssueType::HighComplexity | IssueType::DeepNesting | IssueType::HighCoupling | IssueType::UnusedCode => {
+            "complexity"
+        }
+        IssueType::ExcessiveViewUpdates
+        | IssueType::InefficientBinding
+        | IssueType::MissingViewModifier
+        | IssueType::RedundantViewModifier
+        | IssueType::HeavyViewComputation => "swiftui",
+        IssueType::InefficientCollection | IssueType::ExcessiveComputation => "performance",
+    }
+}
+
+/// Type de problème représentatif d'une catégorie de l'ancien analyseur
+fn issue_type(category: &str) -> IssueType {
+    match category {
+        "memory" => IssueType::MemoryLeak,
+        "concurrency" => IssueType::PotentialDataRace,
+        "coredata" => IssueType::CoreDataMainThread,
+        "complexity" => IssueType::HighComplexity,
+        "swiftui" => IssueType::HeavyViewComputation,
+        _ => IssueType::ExcessiveComputation,
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
@@ -0,0 +1,237 @@
+//! Markdown pour les commentaires de merge request : tableaux de synthèse, points chauds, détails repliables par fichier
+
+use std::cmp::Reverse;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::discovery::relative_path;
+use crate::models::{AnalysisResult, PerformanceReport, Severity};
+
+const SEVERITIES: [Severity; 4] = [Severity::Critical, Severity::High, Severity::Medium, Severity::Low];
+
+/// Limites de taille du rapport (`None` : pas de limite)
+#[derive(Debug, Clone, Copy)]
+pub struct MarkdownOptions {
+    /// Nombre de fichiers détaillés
+    pub max_files: Option<usize>,
+    /// Nombre de problèmes listés par fichier
+    pub max_issues_per_file: Option<usize>,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        MarkdownOptions {
+            max_files: Some(20),
+            max_issues_per_file: Some(10),
+        }
+    }
+}
+
+pub fn generate_report(
+    report: &PerformanceReport,
+    project_root: &Path,
+    options: MarkdownOptions,
+    output_path: &Path,
+) -> Result<(), String> {
+    fs::write(output_path, to_markdown(report, project_root, options))
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport Markdown {}: {}", output_path.display(), e))
+}
+
+pub fn to_markdown(report: &PerformanceReport, project_root: &Path, options: MarkdownOptions) -> String {
+    let stats = &report.project_stats;
+    let mut markdown = String::from("## Analyse de performance Swift\n\n");
+    let _ = writeln!(
+        markdown,
+        "**Score de santé : {:.1} / 100** · {} fichiers · {} lignes · {} fichiers avec problèmes · {:.1} % de code dupliqué\n",
+        stats.health_score, stats.total_files, stats.total_lines, stats.problematic_files, stats.duplication_percentage
+    );
+    if let Some(gate) = &report.quality_gate {
+        let _ = writeln!(
+            markdown,
+            "### Porte qualité : {}\n\n| | Condition | Valeur | Seuil |\n|---|---|---:|---:|",
+            if gate.passed { "✅ réussie" } else { "❌ échouée" }
+        );
+        for condition in &gate.conditions {
+            let _ = writeln!(
+                markdown,
+                "| {} | {} | {} | {} |",
+                if condition.passed { "✅" } else { "❌" },
+                escape(&condition.description),
+                condition.actual,
+                condition.threshold
+            );
+        }
+        for condition in gate.conditions.iter().filter(|condition| !condition.details.is_empty()) {
+            let _ = writeln!(markdown, "\n<details><summary>{}</summary>\n", escape(&condition.description));
+            for detail in &condition.details {
+                let _ = writeln!(markdown, "- `{}`", detail);
+            }
+            markdown.push_str("\n</details>\n");
+        }
+        markdown.push('\n');
+    }
+    if let Some(baseline) = &report.baseline {
+        let _ = writeln!(
+            markdown,
+            "Ligne de base : **{} nouveaux** · {} inchangés · {} corrigés\n",
+            baseline.new,
+            baseline.unchanged,
+            baseline.fixed.len()
+        );
+    }
+
+    markdown.push_str("| Sévérité | Problèmes |\n|---|---:|\n");
+    for severity in SEVERITIES {
+        let count = report.issue_count_by_severity.get(&severity).copied().unwrap_or_default();
+        let _ = writeln!(markdown, "| {} {:?} | {} |", icon(severity), severity, count);
+    }
+
+    let mut by_type: Vec<_> = report.issue_count_by_type.iter().collect();
+    by_type.sort_by_key(|(issue_type, count)| (Reverse(**count), format!("{:?}", issue_type)));
+    if !by_type.is_empty() {
+        markdown.push_str("\n| Type | Problèmes |\n|---|---:|\n");
+        for (issue_type, count) in by_type {
+            let _ = writeln!(markdown, "| {:?} | {} |", issue_type, count);
+        }
+    }
+
+    if !report.hotspots.is_empty() {
+        markdown.push_str("\n### Points chauds\n\n| # | Fichier | Problèmes | Score |\n|---:|---|---:|---:|\n");
+        for (i, hotspot) in report.hotspots.iter().enumerate() {
+            let _ = writeln!(
+                markdown,
+                "| {} | `{}` | {} | {:.1} |",
+                i + 1,
+                relative_path(project_root, &hotspot.file_path),
+                hotspot.issue_count,
+                hotspot.criticality_score
+            );
+        }
+    }
+
+    // Fichiers les plus graves d'abord : sévérité maximale, puis nombre de problèmes
+    let mut files: Vec<&AnalysisResult> = report.files_analyzed.iter().filter(|r| !r.issues.is_empty()).collect();
+    files.sort_by_key(|result| {
+        let worst = result.issues.iter().map(|issue| issue.severity as u8).min().unwrap_or(u8::MAX);
+        (worst, Reverse(result.issues.len()))
+    });
+
+    if !files.is_empty() {
+        markdown.push_str("\n### Problèmes par fichier\n\n");
+    }
+    let shown_files = options.max_files.unwrap_or(files.len()).min(files.len());
+    for result in &files[..shown_files] {
+        file_details(&mut markdown, result, project_root, options.max_issues_per_file);
+    }
+    if shown_files < files.len() {
+        let _ = writeln!(markdown, "_Fichiers avec problèmes non affichés : {}_", files.len() - shown_files);
+    }
+
+    markdown
+}
+
+fn file_details(markdown: &mut String, result: &AnalysisResult, project_root: &Path, max_issues: Option<usize>) {
+    let mut issues: Vec<_> = result.issues.iter().collect();
+    issues.sort_by_key(|issue| (issue.severity as u8, issue.line));
+    let severities: Vec<String> = SEVERITIES
+        .iter()
+        .filter_map(|severity| {
+            let count = issues.iter().filter(|issue| issue.severity == *severity).count();
+            (count > 0).then(|| format!("{} {}", icon(*severity), count))
+        })
+        .collect();
+
+    let _ = writeln!(
+        markdown,
+        "<details>\n<summary><code>{}</code> — {} problèmes ({})</summary>\n",
+        escape(&relative_path(project_root, &result.file_path)),
+        issues.len(),
+        severities.join(" ")
+    );
+    markdown.push_str("| Ligne | Sévérité | Règle | Problème | Suggestion |\n|---:|---|---|---|---|\n");
+
+    let shown = max_issues.unwrap_or(issues.len()).min(issues.len());
+    for issue in &issues[..shown] {
+        let _ = writeln!(
+            markdown,
+            "| {} | {} {:?} | `{}` | {} | {} |",
+            issue.line,
+            icon(issue.severity),
+            issue.severity,
+            issue.rule_id.as_deref().unwrap_or("-"),
+            escape(&issue.message),
+            escape(issue.suggestion.as_deref().unwrap_or_default())
+        );
+    }
+    if shown < issues.len() {
+        let _ = writeln!(markdown, "\n_Problèmes non affichés : {}_", issues.len() - shown);
+    }
+    markdown.push_str("\n</details>\n\n");
+}
+
+fn icon(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "🔴",
+        Severity::High => "🟠",
+        Severity::Medium => "🟡",
+        Severity::Low => "⚪",
+    }
+}
+
+/// Texte sûr dans une cellule de tableau (barres verticales, retours à la ligne, balises)
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('|', "\\|")
+        .replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::{BACKGROUND_VIEW_CONTEXT, PRINT_CALL, UNHANDLED_FETCH, VIEW_CONTEXT_PERFORM};
+    use crate::test_support::{focused_registry, NoPrintRule};
+
+    #[test]
+    fn test_files_and_issues_ordered_by_severity() {
+        let mut registry = focused_registry();
+        registry.register(NoPrintRule);
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(registry)
+            .with_source("App/Refresh.swift", format!("{}{}", VIEW_CONTEXT_PERFORM, UNHANDLED_FETCH))
+            .with_source("App/Import.swift", format!("{}{}", PRINT_CALL, BACKGROUND_VIEW_CONTEXT))
+            .run();
+
+        let markdown = to_markdown(&report, Path::new("App"), MarkdownOptions::default());
+        assert!(markdown.contains("| 🔴 Critical | 1 |\n| 🟠 High | 0 |\n| 🟡 Medium | 1 |\n| ⚪ Low | 2 |"));
+        // Le fichier au problème critique d'abord, puis chaque problème par sévérité avant la ligne
+        let position = |text: &str| markdown.find(text).unwrap_or_else(|| panic!("{} absent", text));
+        assert!(position("<code>Import.swift</code> — 2 problèmes (🔴 1 ⚪ 1)") < position("<code>Refresh.swift</code> — 2 problèmes (🟡 1 ⚪ 1)"));
+        assert!(position("| 6 | 🔴 Critical | `coredata.background-view-context` |") < position("| 2 | ⚪ Low | `cardapp.no-print` |"));
+        assert!(position("| 5 | 🟡 Medium | `coredata.unhandled-fetch` |") < position("| 2 | ⚪ Low | `coredata.view-context-perform` |"));
+    }
+
+    #[test]
+    fn test_truncated_file_details() {
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_source("App/Editor.swift", "class Editor {\n    var delegate: EditorDelegate?\n    class Toolbar {\n        var delegate: ToolbarDelegate?\n    }\n}\n")
+            .with_source("App/List.swift", "class List {\n    var delegate: ListDelegate?\n}\n")
+            .run();
+        let options = MarkdownOptions {
+            max_files: Some(1),
+            max_issues_per_file: Some(1),
+        };
+
+        let markdown = to_markdown(&report, Path::new("App"), options);
+        assert!(markdown.contains("| 🟠 High | 3 |"));
+        assert!(markdown.contains("| 1 | `Editor.swift` | 3 | 11.2 |"));
+        assert!(markdown.contains("<summary><code>Editor.swift</code> — 3 problèmes (🟠 2 ⚪ 1)</summary>"));
+        assert!(markdown.contains("| 2 | 🟠 High | `memory.strong-delegate` |"));
+        assert!(markdown.contains("_Problèmes non affichés : 2_"));
+        assert!(markdown.contains("_Fichiers avec problèmes non affichés : 1_"));
+    }
+}
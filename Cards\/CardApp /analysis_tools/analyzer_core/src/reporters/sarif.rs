@@ -0,0 +1,356 @@
+//! Format SARIF 2.1.0, lu par les outils d'analyse de code (GitHub code scanning, IDE)
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::git::RepositoryPaths;
+use crate::models::{FileIssue, PerformanceReport, Replacement, Severity};
+use crate::rules::{RuleMetadata, RuleRegistry};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Base des chemins des résultats : racine du dépôt git contenant le projet, ou du projet hors dépôt
+const SOURCE_ROOT: &str = "%SRCROOT%";
+
+/// Génère un rapport SARIF ; les chemins sont exprimés relativement à la racine du dépôt contenant `project_root`
+pub fn generate_report(
+    report: &PerformanceReport,
+    project_root: &Path,
+    registry: &RuleRegistry,
+    output_path: &Path,
+) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(&sarif_log(report, project_root, registry))
+        .map_err(|e| format!("Erreur lors de la sérialisation SARIF: {}", e))?;
+
+    fs::write(output_path, content)
+        .map_err(|e| format!("Erreur lors de l'écriture du rapport SARIF {}: {}", output_path.display(), e))
+}
+
+/// Journal SARIF : un `reportingDescriptor` par règle rencontrée, décrite par `registry`, un `result` par problème
+pub fn sarif_log(report: &PerformanceReport, project_root: &Path, registry: &RuleRegistry) -> Value {
+    let paths = RepositoryPaths::discover(project_root);
+    let mut rule_ids: Vec<&str> = Vec::new();
+    let mut seen = HashSet::new();
+    for issue in all_issues(report).map(|(_, issue, _)| issue) {
+        let id = rule_id(issue);
+        if seen.insert(id) {
+            rule_ids.push(id);
+        }
+    }
+
+    let rules: Vec<Value> = rule_ids
+        .iter()
+        .map(|id| match registry.get(id) {
+            Some(metadata) => reporting_descriptor(metadata),
+            None => json!({ "id": id }),
+        })
+        .collect();
+
+    let results: Vec<Value> = all_issues(report)
+        .map(|(path, issue, status)| {
+            let rule_index = rule_ids.iter().position(|id| *id == rule_id(issue)).unwrap_or_default();
+            let mut result = result(&paths, path, issue, rule_index, status == Status::Suppressed);
+            if report.baseline.is_some() && status != Status::Suppressed {
+                result["baselineState"] = json!(if status == Status::Unchanged { "unchanged" } else { "new" });
+            }
+            result
+        })
+        .collect();
+
+    let mut run = json!({
+        "tool": {
+            "driver": {
+                "name": "swift-analyzer",
+                "version": env!("CARGO_PKG_VERSION"),
+                "rules": rules,
+            }
+        },
+        "columnKind": "unicodeCodePoints",
+        "results": results,
+        "properties": run_properties(report),
+    });
+    if let Some(root) = paths.root() {
+        run["originalUriBaseIds"] = json!({ SOURCE_ROOT: { "uri": root_uri(root) } });
+    }
+
+    json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [run],
+    })
+}
+
+/// Porte qualité du run, le cas échéant
+fn run_properties(report: &PerformanceReport) -> Value {
+    match &report.quality_gate {
+        Some(gate) => json!({ "qualityGate": gate }),
+        None => json!({}),
+    }
+}
+
+/// Origine d'un problème dans le journal
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Reported,
+    /// Ignoré par un commentaire dans le source
+    Suppressed,
+    /// Déjà présent dans la ligne de base
+    Unchanged,
+}
+
+/// Problèmes signalés, ignorés dans le source puis connus de la ligne de base, avec leur fichier
+fn all_issues(report: &PerformanceReport) -> impl Iterator<Item = (&Path, &FileIssue, Status)> {
+    report.files_analyzed.iter().flat_map(|result| {
+        let path = result.file_path.as_path();
+        result
+            .issues
+            .iter()
+            .map(move |issue| (path, issue, Status::Reported))
+            .chain(result.suppressed_issues.iter().map(move |issue| (path, issue, Status::Suppressed)))
+            .chain(result.baseline_issues.iter().map(move |issue| (path, issue, Status::Unchanged)))
+    })
+}
+
+fn rule_id(issue: &FileIssue) -> &str {
+    issue.rule_id.as_deref().unwrap_or("unknown")
+}
+
+fn reporting_descriptor(metadata: &RuleMetadata) -> Value {
+    let mut descriptor = json!({
+        "id": metadata.id,
+        "shortDescription": { "text": metadata.description },
+        "defaultConfiguration": { "level": level(metadata.default_severity) },
+        "properties": {
+            "category": metadata.category.as_str(),
+            "tags": [metadata.category.as_str()],
+        },
+    });
+
+    if let Some(explanation) = &metadata.explanation {
+        let conventions: Vec<String> = explanation
+            .conventions
+            .iter()
+            .map(|section| format!("Conventions CardApp : {}", section))
+            .collect();
+        descriptor["fullDescription"] = json!({ "text": explanation.rationale });
+        descriptor["help"] = json!({
+            "text": format!(
+                "{}\n\nÀ éviter :\n{}\n\nÀ préférer :\n{}\n\n{}",
+                explanation.rationale,
+                explanation.bad_example,
+                explanation.good_example,
+                conventions.join("\n")
+            ),
+            "markdown": format!(
+                "{}\n\n**À éviter**\n\n```swift\n{}\n```\n\n**À préférer**\n\n```swift\n{}\n```\n\n{}",
+                explanation.rationale,
+                explanation.bad_example,
+                explanation.good_example,
+                conventions.join("  \n")
+            ),
+        });
+    }
+
+    descriptor
+}
+
+fn result(paths: &RepositoryPaths, path: &Path, issue: &FileIssue, rule_index: usize, suppressed: bool) -> Value {
+    let mut region = json!({ "startLine": issue.line.max(1) });
+    if let Some(column) = issue.column {
+        region["startColumn"] = json!(sarif_column(issue, column));
+    }
+    if let Some(snippet) = &issue.code_snippet {
+        region["snippet"] = json!({ "text": snippet });
+    }
+
+    let location = artifact_location(paths, path);
+    let mut result = json!({
+        "ruleId": rule_id(issue),
+        "ruleIndex": rule_index,
+        "level": level(issue.severity),
+        "message": { "text": issue.message },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": location.clone(),
+                "region": region,
+            }
+        }],
+    });
+
+    match (&issue.fix, &issue.suggestion) {
+        (Some(replacement), _) => result["fixes"] = json!([fix(issue, replacement, location)]),
+        (None, Some(suggestion)) => result["properties"] = json!({ "suggestion": suggestion }),
+        (None, None) => {}
+    }
+    if suppressed {
+        result["suppressions"] = json!([{ "kind": "inSource" }]);
+    }
+
+    result
+}
+
+/// Emplacement d'un fichier ; la base `%SRCROOT%` n'est citée que si elle est déclarée dans `originalUriBaseIds`
+fn artifact_location(paths: &RepositoryPaths, path: &Path) -> Value {
+    let mut location = json!({ "uri": encode_uri_path(&paths.relative(path)) });
+    if paths.root().is_some() {
+        location["uriBaseId"] = json!(SOURCE_ROOT);
+    }
+    location
+}
+
+/// Correction fournie par la règle, sur la ligne du problème
+fn fix(issue: &FileIssue, replacement: &Replacement, location: Value) -> Value {
+    json!({
+        "description": { "text": issue.suggestion.as_deref().unwrap_or(&issue.message) },
+        "artifactChanges": [{
+            "artifactLocation": location,
+            "replacements": [{
+                "deletedRegion": {
+                    "startLine": issue.line,
+                    "startColumn": sarif_column(issue, replacement.column),
+                    "endColumn": sarif_column(issue, replacement.column + replacement.length),
+                },
+                "insertedContent": { "text": replacement.text },
+            }],
+        }],
+    })
+}
+
+/// Colonne SARIF (en caractères, à partir de 1) d'une position en octets à partir de 0 dans la ligne du problème
+fn sarif_column(issue: &FileIssue, column: usize) -> usize {
+    let column = match &issue.code_snippet {
+        Some(snippet) if snippet.is_char_boundary(column.min(snippet.len())) => {
+            snippet[..column.min(snippet.len())].chars().count()
+        }
+        _ => column,
+    };
+    column + 1
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "note",
+    }
+}
+
+/// URI `file:` absolue d'un répertoire, terminée par `/` comme l'exige `originalUriBaseIds`
+fn root_uri(root: &Path) -> String {
+    let path = root.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/");
+    format!("file:///{}/", encode_uri_path(path.trim_start_matches('/')))
+}
+
+/// Chemin sous forme d'URI (séparateurs `/`, caractères réservés encodés)
+fn encode_uri_path(path: &str) -> String {
+    let mut uri = String::new();
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+    use crate::test_support::fixtures::{BACKGROUND_VIEW_CONTEXT, PRINT_CALL, STRONG_DELEGATE, UNHANDLED_FETCH};
+    use crate::test_support::{focused_registry, NoPrintRule};
+
+    #[test]
+    fn test_results_fixes_and_suppressions() {
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(focused_registry())
+            .with_source(
+                "CardApp/Card Editor.swift",
+                "class CardEditor {\n    var delegate: CardEditorDelegate?\n}\nclass CardList {\n    var delegate: CardListDelegate? // swift-analyzer:ignore\n}\n",
+            )
+            .run();
+
+        let log = sarif_log(&report, Path::new("CardApp"), &focused_registry());
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "memory.strong-delegate");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["defaultConfiguration"]["level"], "error");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "Card%20Editor.swift");
+        // Projet introuvable sur le disque : pas de base déclarée, donc aucune référence à `%SRCROOT%`
+        assert!(location["artifactLocation"].get("uriBaseId").is_none());
+        assert!(run.get("originalUriBaseIds").is_none());
+        assert_eq!(location["region"]["startLine"], 2);
+        let replacement = &results[0]["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 5);
+        assert_eq!(replacement["deletedRegion"]["endColumn"], 5);
+        assert_eq!(replacement["insertedContent"]["text"], "weak ");
+        assert!(results[0].get("suppressions").is_none());
+        assert_eq!(results[1]["suppressions"][0]["kind"], "inSource");
+    }
+
+    #[test]
+    fn test_levels_and_custom_rule_described_by_the_analysis_registry() {
+        let mut registry = focused_registry();
+        registry.register(NoPrintRule);
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_registry(registry.clone())
+            .with_source("CardApp/Import.swift", BACKGROUND_VIEW_CONTEXT)
+            .with_source("CardApp/Count.swift", UNHANDLED_FETCH)
+            .with_source("CardApp/Deck.swift", PRINT_CALL)
+            .run();
+
+        let log = sarif_log(&report, Path::new("CardApp"), &registry);
+        let rules = log["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap();
+        let ids: Vec<&str> = rules.iter().map(|rule| rule["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, ["coredata.background-view-context", "coredata.unhandled-fetch", "cardapp.no-print"]);
+        assert!(rules[0]["help"]["markdown"].as_str().unwrap().contains("**À éviter**"));
+        assert_eq!(rules[2]["shortDescription"]["text"], "Appel à print() dans le code de production");
+        assert_eq!(rules[2]["defaultConfiguration"]["level"], "note");
+        assert_eq!(rules[2]["properties"]["category"], "resources");
+
+        // Critical : error ; Medium : warning ; Low : note
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        let levels: Vec<(u64, &str)> =
+            results.iter().map(|r| (r["ruleIndex"].as_u64().unwrap(), r["level"].as_str().unwrap())).collect();
+        assert_eq!(levels, [(0, "error"), (1, "warning"), (2, "note")]);
+
+        // Correction de la règle : `container.viewContext` remplacé par le paramètre du bloc
+        let replacement = &results[0]["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 26);
+        assert_eq!(replacement["deletedRegion"]["endColumn"], 47);
+        assert_eq!(replacement["insertedContent"]["text"], "context");
+        assert!(results[1].get("fixes").is_none());
+        assert_eq!(results[1]["properties"]["suggestion"], "Utilisez try/catch pour gérer les erreurs de fetch CoreData");
+    }
+
+    #[test]
+    fn test_uris_relative_to_repository_root() {
+        let root = std::env::temp_dir().join(format!("swift-analyzer-sarif-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let project = root.join("Cards/CardApp");
+        fs::create_dir_all(&project).unwrap();
+        git2::Repository::init(&root).unwrap();
+        let report = Analyzer::new(AnalysisConfig::default())
+            .with_source(project.join("Views/Editor.swift"), STRONG_DELEGATE)
+            .run();
+
+        let log = sarif_log(&report, &project, &RuleRegistry::builtin());
+        let workdir = fs::canonicalize(&root).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let run = &log["runs"][0];
+        let location = &run["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(location["uri"], "Cards/CardApp/Views/Editor.swift");
+        assert_eq!(location["uriBaseId"], SOURCE_ROOT);
+        assert_eq!(run["originalUriBaseIds"][SOURCE_ROOT]["uri"], format!("file://{}/", workdir.display()));
+    }
+}
@@ -0,0 +1,297 @@
+//! Page HTML d'évolution du projet d'après l'historique des analyses (`trend --format html`)
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use super::escape_xml as escape;
+use crate::history::{file_trends, format_timestamp, FileTrend, HistoryEntry};
+use crate::models::Severity;
+
+const SEVERITIES: [(Severity, &str); 4] = [
+    (Severity::Critical, "#dc3545"),
+    (Severity::High, "#fd7e14"),
+    (Severity::Medium, "#ffc107"),
+    (Severity::Low, "#17a2b8"),
+];
+
+const STYLE: &str = r#"        body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, Arial, sans-serif; color: #333; max-width: 1200px; margin: 0 auto; padding: 20px; background: #f5f5f7; }
+        .container { background: white; border-radius: 8px; box-shadow: 0 2px 10px rgba(0, 0, 0, 0.1); padding: 25px; margin-bottom: 25px; }
+        h1, h2 { color: #343a40; }
+        table { width: 100%; border-collapse: collapse; }
+        th, td { padding: 6px 10px; border-bottom: 1px solid #e9ecef; text-align: left; }
+        td.num, th.num { text-align: right; }
+        .better { color: #28a745; }
+        .worse { color: #dc3545; }
+        svg text { font-size: 12px; fill: #555; }"#;
+
+/// Écrit la page d'évolution de l'historique
+pub fn generate_report(entries: &[HistoryEntry], output_path: &Path) -> Result<(), String> {
+    fs::write(output_path, to_html(entries))
+        .map_err(|e| format!("Erreur lors de l'écriture de la page d'évolution {}: {}", output_path.display(), e))
+}
+
+/// Page autonome : courbes du score et des sévérités, points chauds, fichiers améliorés ou dégradés
+pub fn to_html(entries: &[HistoryEntry]) -> String {
+    let mut html = format!(
+        r#"<!DOCTYPE html>
+<html lang="fr">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Évolution de l'analyse Swift</title>
+    <style>
+{STYLE}
+    </style>
+</head>
+<body>
+    <h1>Évolution de l'analyse Swift</h1>
+"#
+    );
+    let (Some(first), Some(last)) = (entries.first(), entries.last()) else {
+        html.push_str("    <div class=\"container\"><p>Historique vide.</p></div>\n</body>\n</html>\n");
+        return html;
+    };
+
+    let _ = write!(
+        html,
+        r#"    <div class="container">
+        <p>{} analyses du {} au {} · score de santé {:.1} → {:.1} ({})</p>
+        <h2>Score de santé</h2>
+{}        <h2>Problèmes par sévérité</h2>
+{}    </div>
+"#,
+        entries.len(),
+        format_timestamp(first.timestamp),
+        format_timestamp(last.timestamp),
+        first.health_score,
+        last.health_score,
+        signed(last.health_score - first.health_score, true),
+        line_chart(entries, &[("Score", "#007bff", entries.iter().map(|entry| entry.health_score).collect())], 100.0),
+        line_chart(
+            entries,
+            &SEVERITIES.map(|(severity, color)| {
+                let counts = entries
+                    .iter()
+                    .map(|entry| entry.issues_by_severity.get(&severity).copied().unwrap_or_default() as f64)
+                    .collect();
+                (severity_name(severity), color, counts)
+            }),
+            1.0,
+        ),
+    );
+
+    // Points chauds de la dernière analyse et leur criticité au fil des analyses
+    html.push_str("    <div class=\"container\">\n        <h2>Points chauds</h2>\n        <table>\n            <tr><th>Fichier</th><th>Évolution</th><th class=\"num\">Première</th><th class=\"num\">Dernière</th></tr>\n");
+    for file in &last.hotspots {
+        let scores: Vec<Option<f64>> = entries
+            .iter()
+            .map(|entry| entry.files.get(file).map(|health| health.criticality_score))
+            .collect();
+        let first_score = scores.iter().flatten().next().copied().unwrap_or_default();
+        let last_score = scores.last().copied().flatten().unwrap_or_default();
+        let _ = writeln!(
+            html,
+            r#"            <tr><td>{}</td><td>{}</td><td class="num">{:.1}</td><td class="num">{:.1}</td></tr>"#,
+            escape(file),
+            sparkline(&scores),
+            first_score,
+            last_score
+        );
+    }
+    html.push_str("        </table>\n    </div>\n");
+
+    let (improved, worsened) = file_trends(first, last);
+    for (title, trends) in [("Fichiers améliorés", &improved), ("Fichiers dégradés", &worsened)] {
+        let _ = writeln!(
+            html,
+            "    <div class=\"container\">\n        <h2>{} ({})</h2>\n        <table>\n            <tr><th>Fichier</th><th class=\"num\">Problèmes</th><th class=\"num\">Criticité</th><th class=\"num\">Écart</th></tr>",
+            title,
+            trends.len()
+        );
+        for trend in trends.iter() {
+            html.push_str(&file_row(trend));
+        }
+        html.push_str("        </table>\n    </div>\n");
+    }
+
+    let types: BTreeSet<_> = first.issues_by_type.keys().chain(last.issues_by_type.keys()).collect();
+    html.push_str("    <div class=\"container\">\n        <h2>Problèmes par type</h2>\n        <table>\n            <tr><th>Type</th><th class=\"num\">Première</th><th class=\"num\">Dernière</th><th class=\"num\">Écart</th></tr>\n");
+    for issue_type in types {
+        let old = first.issues_by_type.get(issue_type).copied().unwrap_or_default();
+        let new = last.issues_by_type.get(issue_type).copied().unwrap_or_default();
+        let _ = writeln!(
+            html,
+            r#"            <tr><td>{:?}</td><td class="num">{}</td><td class="num">{}</td><td class="num">{}</td></tr>"#,
+            issue_type,
+            old,
+            new,
+            signed(new as f64 - old as f64, false)
+        );
+    }
+    html.push_str("        </table>\n    </div>\n");
+
+    html.push_str("    <div class=\"container\">\n        <h2>Analyses</h2>\n        <table>\n            <tr><th>Date (UTC)</th><th>Commit</th><th class=\"num\">Score</th><th class=\"num\">Critical</th><th class=\"num\">High</th><th class=\"num\">Medium</th><th class=\"num\">Low</th><th class=\"num\">Total</th></tr>\n");
+    for entry in entries.iter().rev() {
+        let counts: Vec<String> = SEVERITIES
+            .iter()
+            .map(|(severity, _)| format!(r#"<td class="num">{}</td>"#, entry.issues_by_severity.get(severity).unwrap_or(&0)))
+            .collect();
+        let _ = writeln!(
+            html,
+            r#"            <tr><td>{}</td><td><code>{}</code></td><td class="num">{:.1}</td>{}<td class="num">{}</td></tr>"#,
+            format_timestamp(entry.timestamp),
+            escape(entry.short_commit()),
+            entry.health_score,
+            counts.join(""),
+            entry.issue_count()
+        );
+    }
+    html.push_str("        </table>\n    </div>\n</body>\n</html>\n");
+    html
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "Critical",
+        Severity::High => "High",
+        Severity::Medium => "Medium",
+        Severity::Low => "Low",
+    }
+}
+
+/// Écart signé, coloré selon qu'une hausse est une amélioration (`higher_is_better`) ou non
+fn signed(delta: f64, higher_is_better: bool) -> String {
+    let class = match (delta > 0.0, delta < 0.0) {
+        (true, _) if higher_is_better => "better",
+        (true, _) => "worse",
+        (_, true) if higher_is_better => "worse",
+        (_, true) => "better",
+        _ => return format!("{:+.1}", delta),
+    };
+    format!(r#"<span class="{}">{:+.1}</span>"#, class, delta)
+}
+
+fn file_row(trend: &FileTrend) -> String {
+    let issues = |health: Option<crate::history::FileHealth>| health.map_or(0, |health| health.issues);
+    let score = |health: Option<crate::history::FileHealth>| health.map_or(0.0, |health| health.criticality_score);
+    format!(
+        "            <tr><td>{}</td><td class=\"num\">{} → {}</td><td class=\"num\">{:.1} → {:.1}</td><td class=\"num\">{}</td></tr>\n",
+        escape(&trend.file),
+        issues(trend.old),
+        issues(trend.new),
+        score(trend.old),
+        score(trend.new),
+        signed(trend.delta(), false)
+    )
+}
+
+/// Courbes SVG, une par série (une valeur par analyse), sur l'axe des analyses successives ;
+/// l'axe vertical va de 0 au maximum des séries, au moins `min_scale`
+fn line_chart(entries: &[HistoryEntry], series: &[(&str, &str, Vec<f64>)], min_scale: f64) -> String {
+    const WIDTH: f64 = 960.0;
+    const HEIGHT: f64 = 220.0;
+    const LEFT: f64 = 50.0;
+    const TOP: f64 = 20.0;
+    const PLOT_WIDTH: f64 = 760.0;
+    const PLOT_HEIGHT: f64 = 170.0;
+
+    let max = series
+        .iter()
+        .flat_map(|(_, _, values)| values.iter().copied())
+        .fold(0.0, f64::max)
+        .max(min_scale);
+    let x = |i: usize| LEFT + if entries.len() > 1 { i as f64 / (entries.len() - 1) as f64 * PLOT_WIDTH } else { PLOT_WIDTH / 2.0 };
+    let y = |value: f64| TOP + PLOT_HEIGHT - value / max * PLOT_HEIGHT;
+
+    let mut svg = format!(
+        r##"        <svg viewBox="0 0 {WIDTH} {HEIGHT}" role="img" xmlns="http://www.w3.org/2000/svg">
+            <line x1="{LEFT}" y1="{0:.1}" x2="{1:.1}" y2="{0:.1}" stroke="#ced4da"/>
+            <text x="{2:.1}" y="{3:.1}" text-anchor="end">{max:.0}</text>
+            <text x="{2:.1}" y="{0:.1}" text-anchor="end">0</text>
+"##,
+        TOP + PLOT_HEIGHT,
+        LEFT + PLOT_WIDTH,
+        LEFT - 6.0,
+        TOP + 4.0
+    );
+    for (index, (label, color, values)) in series.iter().enumerate() {
+        let points: Vec<String> = values.iter().enumerate().map(|(i, value)| format!("{:.1},{:.1}", x(i), y(*value))).collect();
+        let _ = writeln!(
+            svg,
+            r#"            <polyline points="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
+            points.join(" "),
+            color
+        );
+        for (i, (entry, value)) in entries.iter().zip(values).enumerate() {
+            let _ = writeln!(
+                svg,
+                r#"            <circle cx="{:.1}" cy="{:.1}" r="3" fill="{}"><title>{} · {} : {:.1}</title></circle>"#,
+                x(i),
+                y(*value),
+                color,
+                format_timestamp(entry.timestamp),
+                escape(label),
+                value
+            );
+        }
+        let legend_y = TOP + 14.0 + index as f64 * 20.0;
+        let _ = writeln!(
+            svg,
+            r#"            <rect x="{:.1}" y="{:.1}" width="12" height="12" fill="{}"/><text x="{:.1}" y="{:.1}">{}</text>"#,
+            LEFT + PLOT_WIDTH + 30.0,
+            legend_y - 10.0,
+            color,
+            LEFT + PLOT_WIDTH + 48.0,
+            legend_y,
+            escape(label)
+        );
+    }
+    svg.push_str("        </svg>\n");
+    svg
+}
+
+/// Petite courbe en ligne ; les analyses où le fichier n'a pas de criticité comptent pour zéro
+fn sparkline(values: &[Option<f64>]) -> String {
+    const WIDTH: f64 = 160.0;
+    const HEIGHT: f64 = 24.0;
+    let max = values.iter().flatten().fold(0.0, |max: f64, value| max.max(*value)).max(1.0);
+    let step = if values.len() > 1 { WIDTH / (values.len() - 1) as f64 } else { 0.0 };
+    let points: Vec<String> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| format!("{:.1},{:.1}", i as f64 * step, HEIGHT - value.unwrap_or_default() / max * (HEIGHT - 2.0) - 1.0))
+        .collect();
+    format!(
+        r##"<svg width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" xmlns="http://www.w3.org/2000/svg"><polyline points="{}" fill="none" stroke="#dc3545" stroke-width="1.5"/></svg>"##,
+        points.join(" ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+
+    #[test]
+    fn test_trend_page_lists_worsened_files() {
+        let entries: Vec<HistoryEntry> = [
+            (1_760_700_000, "class Editor {\n    weak var delegate: EditorDelegate?\n}\n"),
+            (1_760_800_000, "class Editor {\n    var delegate: EditorDelegate?\n}\n"),
+        ]
+        .into_iter()
+        .map(|(timestamp, source)| {
+            let report = Analyzer::new(AnalysisConfig::default()).with_source("App/<Editor>.swift", source).run();
+            HistoryEntry::from_report(&report, Path::new(""), timestamp, None)
+        })
+        .collect();
+
+        let html = to_html(&entries);
+        assert!(html.contains("2 analyses du 2025-10-17 11:20 au 2025-10-18 15:06"));
+        assert!(html.contains("<h2>Fichiers dégradés (1)</h2>"));
+        assert!(html.contains("<td>App/&lt;Editor&gt;.swift</td><td class=\"num\">1 → 2</td>"));
+        assert_eq!(html.matches("<polyline").count(), 1 + SEVERITIES.len() + 1);
+    }
+}
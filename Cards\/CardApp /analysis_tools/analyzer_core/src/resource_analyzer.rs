@@ -0,0 +1,465 @@
+use crate::models::{FileIssue, IssueType, Severity};
+use crate::rules::{AnalysisLevel, Rule, RuleCategory, RuleContext, RuleExplanation, RuleMetadata, SourceFile};
+use crate::swift_source::{self, TypeDeclaration, TypeKind};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref OBSERVER_ADD_PATTERN: Regex = Regex::new(
+        r"\.addObserver\s*\("
+    ).unwrap();
+
+    static ref OBSERVER_BLOCK_PATTERN: Regex = Regex::new(
+        r"\.addObserver\s*\(\s*forName\s*:"
+    ).unwrap();
+
+    static ref OBSERVER_REMOVE_PATTERN: Regex = Regex::new(
+        r"\.removeObserver\s*\("
+    ).unwrap();
+
+    static ref TIMER_PATTERN: Regex = Regex::new(
+        r"\bTimer\.scheduledTimer\s*\(|\bTimer\s*\(\s*(timeInterval|fireAt|fire)\s*:"
+    ).unwrap();
+
+    static ref DISPLAY_LINK_PATTERN: Regex = Regex::new(
+        r"\bCADisplayLink\s*\(\s*target\s*:"
+    ).unwrap();
+
+    static ref INVALIDATE_PATTERN: Regex = Regex::new(
+        r"\.invalidate\s*\(\s*\)"
+    ).unwrap();
+
+    static ref TARGET_SELF_PATTERN: Regex = Regex::new(
+        r"target\s*:\s*self\b"
+    ).unwrap();
+
+    static ref KVO_PATTERN: Regex = Regex::new(
+        r"\.observe\s*\(\s*\\"
+    ).unwrap();
+
+    static ref COMBINE_SUBSCRIPTION_PATTERN: Regex = Regex::new(
+        r"\.sink\s*[({]|\.assign\s*\(\s*to\s*:"
+    ).unwrap();
+
+    static ref ASSIGN_TO_PUBLISHED_PATTERN: Regex = Regex::new(
+        r"\.assign\s*\(\s*to\s*:\s*&"
+    ).unwrap();
+
+    static ref STORE_PATTERN: Regex = Regex::new(
+        r"\.store\s*\(\s*in\s*:"
+    ).unwrap();
+
+    static ref FILE_HANDLE_PATTERN: Regex = Regex::new(
+        r"\bFileHandle\s*\(\s*for(Reading|Writing|Updating)(AtPath|From|To)?\s*:"
+    ).unwrap();
+
+    static ref FILE_HANDLE_CLOSE_PATTERN: Regex = Regex::new(
+        r"\.close(File)?\s*\(\s*\)"
+    ).unwrap();
+
+    static ref STREAM_OPEN_PATTERN: Regex = Regex::new(
+        r"\b(\w+)\??\.open\s*\(\s*\)"
+    ).unwrap();
+
+    static ref STREAM_DECLARATION_PATTERN: Regex = Regex::new(
+        r"\b(?:let|var)\s+(\w+)\s*(?::\s*(?:Input|Output)Stream\??\s*)?=\s*(?:Input|Output)Stream\s*\("
+    ).unwrap();
+
+    static ref ASSIGNMENT_PATTERN: Regex = Regex::new(
+        r"^\s*(?:(?:private|fileprivate|internal|public|lazy)\s+)*(?:let|var)?\s*[\w.?\[\]]+\s*(?::[^=]+)?=[^=]|\breturn\b|\.append\s*\(|\.insert\s*\("
+    ).unwrap();
+}
+
+/// Ressource devant être libérée explicitement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResourceKind {
+    NotificationObserver,
+    Timer,
+    DisplayLink,
+    KeyValueObservation,
+    CombineSubscription,
+    FileHandle,
+    Stream,
+}
+
+impl ResourceKind {
+    fn label(&self) -> &'static str {
+        match self {
+            ResourceKind::NotificationObserver => "observateur NotificationCenter",
+            ResourceKind::Timer => "Timer",
+            ResourceKind::DisplayLink => "CADisplayLink",
+            ResourceKind::KeyValueObservation => "observation KVO",
+            ResourceKind::CombineSubscription => "abonnement Combine",
+            ResourceKind::FileHandle => "FileHandle",
+            ResourceKind::Stream => "flux (Input/OutputStream)",
+        }
+    }
+
+    fn teardown(&self) -> &'static str {
+        match self {
+            ResourceKind::NotificationObserver => "removeObserver",
+            ResourceKind::Timer | ResourceKind::DisplayLink => "invalidate()",
+            ResourceKind::KeyValueObservation => "invalidate() ou la libération du jeton",
+            ResourceKind::CombineSubscription => "store(in: &cancellables)",
+            ResourceKind::FileHandle | ResourceKind::Stream => "close()",
+        }
+    }
+}
+
+/// Acquisition de ressource repérée dans un fichier
+#[derive(Debug, Clone)]
+struct Acquisition {
+    kind: ResourceKind,
+    line: usize,
+    column: usize,
+    /// La valeur retournée est conservée (propriété, variable, collection)
+    stored: bool,
+}
+
+/// Portée dans laquelle les acquisitions et libérations sont appariées : un type et ses
+/// extensions déclarées dans le même fichier, ou le fichier lui-même pour le code global
+struct Scope<'a> {
+    name: String,
+    declarations: Vec<&'a TypeDeclaration>,
+}
+
+/// Analyseur du cycle de vie des ressources (observateurs, timers, abonnements, fichiers)
+#[derive(Default)]
+pub struct ResourceLifecycleAnalyzer;
+
+impl ResourceLifecycleAnalyzer {
+    pub const RULE: RuleMetadata = RuleMetadata::new(
+        "resources.lifecycle",
+        RuleCategory::Resources,
+        Severity::High,
+        "Observateur, timer, abonnement ou fichier acquis sans libération correspondante",
+    )
+    .with_level(AnalysisLevel::Ast)
+    .with_explanation(RuleExplanation {
+        rationale: "Observateurs NotificationCenter, Timer, CADisplayLink, observations KVO, abonnements Combine, FileHandle et flux doivent être libérés explicitement. Un Timer ou un observateur à bloc non invalidé retient sa cible et continue de s'exécuter après la fermeture de l'écran ; un abonnement Combine non conservé est annulé immédiatement.",
+        bad_example: "override func viewDidLoad() {\n    timer = Timer.scheduledTimer(withTimeInterval: 1, repeats: true) { _ in self.tick() }\n}",
+        good_example: "override func viewDidLoad() {\n    timer = Timer.scheduledTimer(withTimeInterval: 1, repeats: true) { [weak self] _ in self?.tick() }\n}\n\ndeinit {\n    timer?.invalidate()\n}",
+        conventions: &["5.3 Gestion des Souscriptions Combine", "6.2 Gestion des Tâches"],
+    });
+
+    pub fn new() -> Self {
+        ResourceLifecycleAnalyzer
+    }
+
+    /// Analyser un fichier pour les ressources acquises sans libération correspondante
+    pub fn analyze(&self, content: &str) -> Vec<FileIssue> {
+        let mut issues = Vec::new();
+
+        let lines = swift_source::sanitize_lines(content);
+        let raw_lines: Vec<&str> = content.lines().collect();
+        let declarations = swift_source::find_type_declarations(&lines);
+        let acquisitions = self.find_acquisitions(&lines);
+
+        for scope in self.build_scopes(&declarations) {
+            let in_scope: Vec<&Acquisition> = acquisitions
+                .iter()
+                .filter(|a| Self::scope_of(&declarations, a.line) == scope.name)
+                .collect();
+
+            if in_scope.is_empty() {
+                continue;
+            }
+
+            let scope_lines: Vec<usize> = if scope.declarations.is_empty() {
+                (0..lines.len())
+                    .filter(|&i| swift_source::innermost_type_at(&declarations, i).is_none())
+                    .collect()
+            } else {
+                scope
+                    .declarations
+                    .iter()
+                    .flat_map(|d| d.line_start..=d.line_end)
+                    .collect()
+            };
+
+            let deinit = scope
+                .declarations
+                .iter()
+                .find_map(|d| swift_source::find_deinit(&lines, d));
+
+            for acquisition in in_scope {
+                self.check_acquisition(
+                    acquisition,
+                    &scope,
+                    &scope_lines,
+                    deinit,
+                    &lines,
+                    &raw_lines,
+                    &mut issues,
+                );
+            }
+        }
+
+        issues.sort_by_key(|i| i.line);
+        issues
+    }
+
+    /// Repère toutes les acquisitions de ressources du fichier
+    fn find_acquisitions(&self, lines: &[String]) -> Vec<Acquisition> {
+        let mut acquisitions = Vec::new();
+        let streams: Vec<String> = lines
+            .iter()
+            .filter_map(|l| STREAM_DECLARATION_PATTERN.captures(l).map(|c| c[1].to_string()))
+            .collect();
+
+        for (i, line) in lines.iter().enumerate() {
+            let mut push = |kind: ResourceKind, column: usize| {
+                let (start, end) = swift_source::statement_range(lines, i);
+                let statement = lines[start..=end].join("\n");
+                let stored = ASSIGNMENT_PATTERN.is_match(&lines[start])
+                    || STORE_PATTERN.is_match(&statement);
+                acquisitions.push(Acquisition { kind, line: i, column, stored });
+            };
+
+            if let Some(m) = OBSERVER_ADD_PATTERN.find(line) {
+                push(ResourceKind::NotificationObserver, m.start());
+            }
+            if let Some(m) = TIMER_PATTERN.find(line) {
+                push(ResourceKind::Timer, m.start());
+            }
+            if let Some(m) = DISPLAY_LINK_PATTERN.find(line) {
+                push(ResourceKind::DisplayLink, m.start());
+            }
+            if let Some(m) = KVO_PATTERN.find(line) {
+                push(ResourceKind::KeyValueObservation, m.start());
+            }
+            if let Some(m) = COMBINE_SUBSCRIPTION_PATTERN.find(line) {
+                // `assign(to: &$propriete)` gère lui-même la durée de vie de l'abonnement
+                if !ASSIGN_TO_PUBLISHED_PATTERN.is_match(line) {
+                    push(ResourceKind::CombineSubscription, m.start());
+                }
+            }
+            if let Some(m) = FILE_HANDLE_PATTERN.find(line) {
+                push(ResourceKind::FileHandle, m.start());
+            }
+            if let Some(cap) = STREAM_OPEN_PATTERN.captures(line) {
+                if streams.iter().any(|s| s == &cap[1]) {
+                    push(ResourceKind::Stream, cap.get(0).unwrap().start());
+                }
+            }
+        }
+
+        acquisitions
+    }
+
+    /// Regroupe les déclarations par nom de type ; la portée globale porte un nom vide
+    fn build_scopes<'a>(&self, declarations: &'a [TypeDeclaration]) -> Vec<Scope<'a>> {
+        let mut scopes: Vec<Scope<'a>> = vec![Scope {
+            name: String::new(),
+            declarations: Vec::new(),
+        }];
+
+        for declaration in declarations.iter().filter(|d| d.kind != TypeKind::Protocol) {
+            match scopes.iter_mut().find(|s| s.name == declaration.name) {
+                Some(scope) => scope.declarations.push(declaration),
+                None => scopes.push(Scope {
+                    name: declaration.name.clone(),
+                    declarations: vec![declaration],
+                }),
+            }
+        }
+
+        scopes
+    }
+
+    fn scope_of(declarations: &[TypeDeclaration], line: usize) -> String {
+        swift_source::innermost_type_at(declarations, line)
+            .map(|d| d.name.clone())
+            .unwrap_or_default()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_acquisition(
+        &self,
+        acquisition: &Acquisition,
+        scope: &Scope,
+        scope_lines: &[usize],
+        deinit: Option<(usize, usize)>,
+        lines: &[String],
+        raw_lines: &[&str],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        let owner = if scope.name.is_empty() {
+            "le fichier".to_string()
+        } else {
+            format!("'{}'", scope.name)
+        };
+        let deinit_note = match deinit {
+            Some((start, _)) => format!("deinit ligne {}", start + 1),
+            None => "aucun deinit".to_string(),
+        };
+        let snippet = Some(raw_lines.get(acquisition.line).unwrap_or(&"").to_string());
+        let releases_in = |pattern: &Regex| -> Vec<usize> {
+            scope_lines
+                .iter()
+                .copied()
+                .filter(|&i| pattern.is_match(&lines[i]))
+                .collect()
+        };
+
+        let mut report = |severity: Severity, message: String, suggestion: String| {
+            issues.push(FileIssue {
+                issue_type: IssueType::ResourceLeak,
+                severity,
+                line: acquisition.line + 1,
+                column: Some(acquisition.column),
+                message,
+                suggestion: Some(suggestion),
+                code_snippet: snippet.clone(),
+                rule_id: None,
+                declaration: None,
+                ownership: None,
+                fix: None,
+            });
+        };
+
+        match acquisition.kind {
+            ResourceKind::KeyValueObservation | ResourceKind::CombineSubscription => {
+                if !acquisition.stored {
+                    report(
+                        Severity::High,
+                        format!(
+                            "Le résultat de l'{} n'est pas conservé dans {} ({}) : il est annulé immédiatement",
+                            acquisition.kind.label(),
+                            owner,
+                            deinit_note
+                        ),
+                        format!(
+                            "Conserver le jeton retourné, par exemple avec {}",
+                            acquisition.kind.teardown()
+                        ),
+                    );
+                }
+            }
+            ResourceKind::NotificationObserver => {
+                let is_block_based = OBSERVER_BLOCK_PATTERN.is_match(&lines[acquisition.line]);
+                if is_block_based && !acquisition.stored {
+                    report(
+                        Severity::High,
+                        format!(
+                            "Jeton d'{} par bloc non conservé dans {} ({}) : il ne pourra jamais être retiré",
+                            acquisition.kind.label(),
+                            owner,
+                            deinit_note
+                        ),
+                        "Conserver le jeton retourné par addObserver(forName:) et appeler removeObserver dans deinit".to_string(),
+                    );
+                } else if releases_in(&OBSERVER_REMOVE_PATTERN).is_empty() {
+                    report(
+                        Severity::Medium,
+                        format!(
+                            "{} ajouté sans removeObserver correspondant dans {} ({})",
+                            capitalize(acquisition.kind.label()),
+                            owner,
+                            deinit_note
+                        ),
+                        "Appeler NotificationCenter.default.removeObserver dans deinit ou lors du démontage".to_string(),
+                    );
+                }
+            }
+            ResourceKind::Timer | ResourceKind::DisplayLink => {
+                let releases = releases_in(&INVALIDATE_PATTERN);
+                if releases.is_empty() {
+                    report(
+                        Severity::High,
+                        format!(
+                            "{} créé sans invalidate() correspondant dans {} ({})",
+                            acquisition.kind.label(),
+                            owner,
+                            deinit_note
+                        ),
+                        format!(
+                            "Conserver la référence et appeler {} lors du démontage",
+                            acquisition.kind.teardown()
+                        ),
+                    );
+                } else if TARGET_SELF_PATTERN.is_match(&lines[acquisition.line])
+                    && deinit.is_some()
+                    && releases.iter().all(|&r| {
+                        deinit.map(|(start, end)| r >= start && r <= end).unwrap_or(false)
+                    })
+                {
+                    // La cible est retenue par le timer : deinit ne sera jamais appelé
+                    report(
+                        Severity::Medium,
+                        format!(
+                            "{} avec target: self invalidé uniquement dans le deinit de {} ({}) : la cible est retenue et deinit ne sera jamais appelé",
+                            acquisition.kind.label(),
+                            owner,
+                            deinit_note
+                        ),
+                        "Invalider depuis une méthode de démontage explicite (viewWillDisappear, stop()) ou utiliser une closure avec [weak self]".to_string(),
+                    );
+                }
+            }
+            ResourceKind::FileHandle | ResourceKind::Stream => {
+                if releases_in(&FILE_HANDLE_CLOSE_PATTERN).is_empty() {
+                    report(
+                        Severity::Medium,
+                        format!(
+                            "{} ouvert sans close() correspondant dans {} ({})",
+                            acquisition.kind.label(),
+                            owner,
+                            deinit_note
+                        ),
+                        "Fermer la ressource avec close() dans un bloc defer".to_string(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Rule for ResourceLifecycleAnalyzer {
+    fn metadata(&self) -> &RuleMetadata {
+        &Self::RULE
+    }
+
+    fn check(&self, file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+        self.analyze(file.content)
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyzer() -> ResourceLifecycleAnalyzer {
+        ResourceLifecycleAnalyzer::new()
+    }
+
+    #[test]
+    fn test_detect_timer_without_invalidate() {
+        let content = "final class Ticker {\n    var timer: Timer?\n    func start() {\n        timer = Timer.scheduledTimer(withTimeInterval: 1, repeats: true) { _ in }\n    }\n}";
+
+        let issues = analyzer().analyze(content);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].line, issues[0].column), (4, Some(16)));
+        assert!(issues[0].message.contains("aucun deinit"));
+    }
+
+    #[test]
+    fn test_sink_stored_across_lines_is_not_reported() {
+        let content = "class ViewModel {\n    private var cancellables = Set<AnyCancellable>()\n    init() {\n        $query\n            .sink { [weak self] _ in\n                self?.reload()\n            }\n            .store(in: &cancellables)\n        $other.sink { _ in }\n    }\n}";
+
+        let issues = analyzer().analyze(content);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, 9);
+        assert!(issues[0].message.contains("Combine"));
+    }
+}
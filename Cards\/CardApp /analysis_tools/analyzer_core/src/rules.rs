@@ -0,0 +1,417 @@
+use crate::clone_detector::CloneDetector;
+use crate::core_data_model::CoreDataModel;
+use crate::dead_code_analyzer::DeadCodeAnalyzer;
+use crate::main_thread_analyzer::MainThreadAnalyzer;
+use crate::models::{AnalysisConfig, FileIssue, Severity};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Fichier source soumis aux règles
+#[derive(Debug, Clone, Copy)]
+pub struct SourceFile<'a> {
+    pub path: &'a Path,
+    pub content: &'a str,
+}
+
+impl<'a> SourceFile<'a> {
+    pub fn new(path: &'a Path, content: &'a str) -> Self {
+        SourceFile { path, content }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.content.lines()
+    }
+}
+
+/// Conventions de codage de CardApp (à la racine du projet), citées par les explications
+pub const CONVENTIONS_DOCUMENT: &str = "CodingConventions.md";
+
+/// Version de la détection des règles intégrées, à incrémenter à chaque modification de leurs résultats
+///
+/// Elle fait partie de la clé du cache d'analyse : les résultats d'une version précédente ne sont pas réutilisés.
+pub const RULES_VERSION: u32 = 1;
+
+/// Famille de règles, utilisable comme sélecteur (`category:coredata`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleCategory {
+    Complexity,
+    Memory,
+    Concurrency,
+    CoreData,
+    SwiftUI,
+    Resources,
+    Performance,
+    DeadCode,
+    Duplication,
+}
+
+impl RuleCategory {
+    pub const ALL: [RuleCategory; 9] = [
+        RuleCategory::Complexity,
+        RuleCategory::Memory,
+        RuleCategory::Concurrency,
+        RuleCategory::CoreData,
+        RuleCategory::SwiftUI,
+        RuleCategory::Resources,
+        RuleCategory::Performance,
+        RuleCategory::DeadCode,
+        RuleCategory::Duplication,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RuleCategory::Complexity => "complexity",
+            RuleCategory::Memory => "memory",
+            RuleCategory::Concurrency => "concurrency",
+            RuleCategory::CoreData => "coredata",
+            RuleCategory::SwiftUI => "swiftui",
+            RuleCategory::Resources => "resources",
+            RuleCategory::Performance => "performance",
+            RuleCategory::DeadCode => "deadcode",
+            RuleCategory::Duplication => "duplication",
+        }
+    }
+}
+
+impl fmt::Display for RuleCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RuleCategory {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        RuleCategory::ALL
+            .into_iter()
+            .find(|category| category.as_str().eq_ignore_ascii_case(value))
+            .ok_or_else(|| {
+                let known: Vec<&str> = RuleCategory::ALL.iter().map(|c| c.as_str()).collect();
+                format!("Catégorie inconnue '{}' (catégories : {})", value, known.join(", "))
+            })
+    }
+}
+
+/// Langage source auquel une règle s'applique
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Swift,
+    ObjectiveC,
+}
+
+impl Language {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::Swift => "swift",
+            Language::ObjectiveC => "objc",
+        }
+    }
+
+    /// Langage d'un fichier d'après son extension
+    pub fn from_path(path: &Path) -> Option<Language> {
+        match path.extension()?.to_str()? {
+            "swift" => Some(Language::Swift),
+            "m" | "mm" | "h" => Some(Language::ObjectiveC),
+            _ => None,
+        }
+    }
+}
+
+/// Niveau d'analyse nécessaire à une règle, du plus simple au plus coûteux
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnalysisLevel {
+    /// Motifs ligne par ligne
+    Line,
+    /// Motifs sur le texte du fichier (plusieurs lignes, jetons)
+    Token,
+    /// Structure des déclarations (types, propriétés, corps de fonctions)
+    Ast,
+    /// Ensemble des fichiers du projet (index, graphe d'appels)
+    Project,
+}
+
+impl AnalysisLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalysisLevel::Line => "ligne",
+            AnalysisLevel::Token => "jetons",
+            AnalysisLevel::Ast => "AST",
+            AnalysisLevel::Project => "projet",
+        }
+    }
+}
+
+/// Explication détaillée d'une règle, affichée par `explain` et dans le rapport HTML
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleExplanation {
+    /// Pourquoi le motif pose problème
+    pub rationale: &'static str,
+    /// Code Swift signalé par la règle
+    pub bad_example: &'static str,
+    /// Correction attendue
+    pub good_example: &'static str,
+    /// Titres des sections de `CodingConventions.md` concernées (ex. `5.4 Références Circulaires`)
+    pub conventions: &'static [&'static str],
+}
+
+/// Lien vers une section de `CodingConventions.md` (ancre au format des titres Markdown)
+pub fn convention_link(section: &str) -> String {
+    let anchor: String = section
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' | '-' => Some('-'),
+            c if c.is_alphanumeric() || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect();
+    format!("{}#{}", CONVENTIONS_DOCUMENT, anchor)
+}
+
+/// Description d'une règle : identification, classement et portée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleMetadata {
+    /// Identifiant stable (ex. `coredata.unhandled-save`)
+    pub id: &'static str,
+    pub category: RuleCategory,
+    /// Sévérité habituelle des problèmes signalés (certaines règles la modulent)
+    pub default_severity: Severity,
+    pub description: &'static str,
+    pub languages: &'static [Language],
+    pub level: AnalysisLevel,
+    pub explanation: Option<RuleExplanation>,
+}
+
+impl RuleMetadata {
+    /// Règle Swift analysée ligne par ligne
+    pub const fn new(
+        id: &'static str,
+        category: RuleCategory,
+        default_severity: Severity,
+        description: &'static str,
+    ) -> Self {
+        RuleMetadata {
+            id,
+            category,
+            default_severity,
+            description,
+            languages: &[Language::Swift],
+            level: AnalysisLevel::Line,
+            explanation: None,
+        }
+    }
+
+    pub const fn with_explanation(mut self, explanation: RuleExplanation) -> Self {
+        self.explanation = Some(explanation);
+        self
+    }
+
+    pub const fn with_level(mut self, level: AnalysisLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub const fn with_languages(mut self, languages: &'static [Language]) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    /// Les fichiers dont le langage n'est pas reconnu sont soumis à toutes les règles
+    pub fn applies_to(&self, path: &Path) -> bool {
+        Language::from_path(path).is_none_or(|language| self.languages.contains(&language))
+    }
+}
+
+/// Paramètres de l'analyse en cours, partagés par toutes les règles
+#[derive(Debug, Clone)]
+pub struct RuleContext {
+    pub config: AnalysisConfig,
+    /// Modèle CoreData du projet (vérification des index des requêtes)
+    pub core_data_model: Arc<CoreDataModel>,
+}
+
+impl RuleContext {
+    pub fn new(config: AnalysisConfig) -> Self {
+        RuleContext {
+            config,
+            core_data_model: Arc::new(CoreDataModel::default()),
+        }
+    }
+}
+
+/// Vérification appliquée à chaque fichier analysé
+///
+/// Les règles sont partagées entre les threads d'analyse : `check` ne doit
+/// dépendre que du fichier et du contexte reçus.
+pub trait Rule: Send + Sync {
+    fn metadata(&self) -> &RuleMetadata;
+
+    /// Problèmes détectés dans le fichier ; les lignes commencent à 1
+    fn check(&self, file: &SourceFile, context: &RuleContext) -> Vec<FileIssue>;
+}
+
+/// Sélection de règles : identifiant exact ou `category:<catégorie>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleSelector {
+    Id(String),
+    Category(RuleCategory),
+}
+
+impl RuleSelector {
+    fn matches(&self, metadata: &RuleMetadata) -> bool {
+        match self {
+            RuleSelector::Id(id) => metadata.id == id,
+            RuleSelector::Category(category) => metadata.category == *category,
+        }
+    }
+}
+
+impl fmt::Display for RuleSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleSelector::Id(id) => f.write_str(id),
+            RuleSelector::Category(category) => write!(f, "category:{}", category),
+        }
+    }
+}
+
+impl FromStr for RuleSelector {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.strip_prefix("category:") {
+            Some(category) => category.parse().map(RuleSelector::Category),
+            None if value.is_empty() => Err("Sélecteur de règle vide".to_string()),
+            None => Ok(RuleSelector::Id(value.to_string())),
+        }
+    }
+}
+
+/// Ensemble des règles connues et de leur activation
+///
+/// Les analyses de niveau projet (graphe d'appels, code mort, clones) n'ont
+/// pas de `check` par fichier : elles sont enregistrées par leurs seules
+/// métadonnées et exécutées par `Analyzer` lorsqu'elles sont actives.
+#[derive(Clone, Default)]
+pub struct RuleRegistry {
+    rules: Vec<Arc<dyn Rule>>,
+    project_rules: Vec<RuleMetadata>,
+    disabled: HashSet<&'static str>,
+}
+
+impl RuleRegistry {
+    /// Registre vide
+    pub fn new() -> Self {
+        RuleRegistry::default()
+    }
+
+    /// Registre des règles intégrées, toutes actives
+    pub fn builtin() -> Self {
+        RuleRegistry {
+            rules: crate::analyzers::builtin_rules(),
+            project_rules: vec![MainThreadAnalyzer::RULE, DeadCodeAnalyzer::RULE, CloneDetector::RULE],
+            disabled: HashSet::new(),
+        }
+    }
+
+    /// Ajoute une règle, active par défaut
+    pub fn register(&mut self, rule: impl Rule + 'static) {
+        self.rules.push(Arc::new(rule));
+    }
+
+    /// Désactive les règles sélectionnées ; erreur si aucune ne correspond
+    pub fn disable(&mut self, selector: &RuleSelector) -> Result<(), String> {
+        let ids = self.matching_ids(selector)?;
+        self.disabled.extend(ids);
+        Ok(())
+    }
+
+    /// Réactive les règles sélectionnées ; erreur si aucune ne correspond
+    pub fn enable(&mut self, selector: &RuleSelector) -> Result<(), String> {
+        for id in self.matching_ids(selector)? {
+            self.disabled.remove(id);
+        }
+        Ok(())
+    }
+
+    /// Métadonnées d'une règle enregistrée
+    pub fn get(&self, id: &str) -> Option<&RuleMetadata> {
+        self.metadata().find(|metadata| metadata.id == id)
+    }
+
+    /// Vrai si la règle est enregistrée et active
+    pub fn is_enabled(&self, id: &str) -> bool {
+        !self.disabled.contains(id) && self.get(id).is_some()
+    }
+
+    /// Métadonnées de toutes les règles, actives ou non
+    pub fn metadata(&self) -> impl Iterator<Item = &RuleMetadata> {
+        self.rules.iter().map(|rule| rule.metadata()).chain(&self.project_rules)
+    }
+
+    /// Règles par fichier actives et applicables au langage du fichier
+    pub fn rules_for<'a>(&'a self, path: &'a Path) -> impl Iterator<Item = &'a dyn Rule> {
+        self.rules
+            .iter()
+            .map(|rule| rule.as_ref())
+            .filter(move |rule| !self.disabled.contains(rule.metadata().id) && rule.metadata().applies_to(path))
+    }
+
+    fn matching_ids(&self, selector: &RuleSelector) -> Result<Vec<&'static str>, String> {
+        let ids: Vec<&'static str> = self
+            .metadata()
+            .filter(|metadata| selector.matches(metadata))
+            .map(|metadata| metadata.id)
+            .collect();
+        if ids.is_empty() {
+            return Err(format!("Aucune règle ne correspond à '{}'", selector));
+        }
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selectors() {
+        assert_eq!("category:coredata".parse(), Ok(RuleSelector::Category(RuleCategory::CoreData)));
+        assert_eq!("memory.strong-delegate".parse(), Ok(RuleSelector::Id("memory.strong-delegate".to_string())));
+        assert!("category:network".parse::<RuleSelector>().is_err());
+    }
+
+    #[test]
+    fn test_disable_category_then_enable_single_rule() {
+        let mut registry = RuleRegistry::builtin();
+        registry.disable(&"category:coredata".parse().unwrap()).unwrap();
+        registry.enable(&"coredata.unhandled-save".parse().unwrap()).unwrap();
+
+        let path = Path::new("CardStore.swift");
+        let active: Vec<&str> = registry.rules_for(path).map(|rule| rule.metadata().id).collect();
+
+        assert!(active.contains(&"coredata.unhandled-save"));
+        assert!(!active.contains(&"coredata.fetch-request"));
+        assert!(active.contains(&"memory.strong-delegate"));
+        assert!(registry.is_enabled(DeadCodeAnalyzer::RULE.id));
+        assert!(registry.disable(&"cardapp.unknown".parse().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_builtin_rules_are_explained() {
+        let registry = RuleRegistry::builtin();
+
+        for metadata in registry.metadata() {
+            assert!(metadata.explanation.is_some(), "règle sans explication : {}", metadata.id);
+        }
+        assert_eq!(
+            convention_link("5.1 Utilisation de `self` dans les Closures"),
+            "CodingConventions.md#51-utilisation-de-self-dans-les-closures"
+        );
+        assert_eq!(convention_link("6.3 Synchronisation de l'Interface Utilisateur"), "CodingConventions.md#63-synchronisation-de-linterface-utilisateur");
+    }
+}
@@ -0,0 +1,92 @@
+//! Suppressions dans le source : `// swift-analyzer:ignore [règle, …] [-- justification]`
+//!
+//! En fin de ligne, le commentaire vise sa propre ligne ; seul sur sa ligne, il
+//! vise la ligne suivante. Sans identifiant de règle, toutes les règles sont ignorées.
+
+use crate::models::FileIssue;
+
+/// Marqueur reconnu dans les commentaires
+pub const IGNORE_MARKER: &str = "swift-analyzer:ignore";
+
+/// Suppressions déclarées dans un fichier
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    /// Ligne visée (à partir de 1) et règles ignorées (vide : toutes)
+    entries: Vec<(usize, Vec<String>)>,
+}
+
+impl Suppressions {
+    pub fn parse(content: &str) -> Self {
+        let mut entries = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let Some(comment_start) = line.find("//") else {
+                continue;
+            };
+            let comment = &line[comment_start + 2..];
+            let Some(marker) = comment.find(IGNORE_MARKER) else {
+                continue;
+            };
+
+            let spec = comment[marker + IGNORE_MARKER.len()..].split("--").next().unwrap_or_default();
+            let rules = spec
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|rule| !rule.is_empty())
+                .map(str::to_string)
+                .collect();
+            let target = if line[..comment_start].trim().is_empty() { index + 2 } else { index + 1 };
+            entries.push((target, rules));
+        }
+        Suppressions { entries }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Le problème est-il couvert par une suppression (ligne et règle) ?
+    pub fn is_suppressed(&self, issue: &FileIssue) -> bool {
+        self.entries.iter().any(|(line, rules)| {
+            *line == issue.line
+                && (rules.is_empty() || issue.rule_id.as_deref().is_some_and(|id| rules.iter().any(|rule| rule == id)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{IssueType, Severity};
+
+    fn issue(line: usize, rule_id: &str) -> FileIssue {
+        FileIssue {
+            issue_type: IssueType::MemoryLeak,
+            severity: Severity::High,
+            line,
+            column: None,
+            message: String::new(),
+            suggestion: None,
+            code_snippet: None,
+            rule_id: Some(rule_id.to_string()),
+            declaration: None,
+            ownership: None,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_trailing_and_preceding_comments() {
+        let content = "\
+var delegate: Delegate? // swift-analyzer:ignore memory.strong-delegate -- possédé par le parent
+// swift-analyzer:ignore
+let cache = [String: Card]()
+let other = 1
+";
+        let suppressions = Suppressions::parse(content);
+
+        assert!(suppressions.is_suppressed(&issue(1, "memory.strong-delegate")));
+        assert!(!suppressions.is_suppressed(&issue(1, "memory.self-in-collection")));
+        assert!(suppressions.is_suppressed(&issue(3, "performance.unsized-collection")));
+        assert!(!suppressions.is_suppressed(&issue(2, "performance.unsized-collection")));
+        assert!(!suppressions.is_suppressed(&issue(4, "performance.unsized-collection")));
+    }
+}
@@ -0,0 +1,690 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+lazy_static! {
+    static ref TYPE_DECLARATION_PATTERN: Regex = Regex::new(
+        r"^\s*((?:(?:@\w+(?:\([^)]*\))?|public|private|fileprivate|internal|open|final|indirect)\s+)*)(class|struct|enum|actor|extension|protocol)\s+([\p{L}_][\w.]*)(?:\s*<[^{]*?>)?\s*(?::\s*([^{]*))?"
+    ).unwrap();
+
+    static ref DEINIT_PATTERN: Regex = Regex::new(r"^\s*deinit\s*\{").unwrap();
+
+    static ref FUNCTION_PATTERN: Regex = Regex::new(
+        r"^\s*((?:@\w+(?:\([^)]*\))?\s+)*)((?:(?:public|private|fileprivate|internal|open|final|override|static|class|mutating|nonisolated|convenience|required|dynamic)\s+)*)(?:func\s+(\w+)|(init)\??)\s*(?:<[^>]*>)?\s*\("
+    ).unwrap();
+
+    static ref PROPERTY_PATTERN: Regex = Regex::new(
+        r"^\s*((?:@\w+(?:\([^)]*\))?\s+)*)((?:(?:public|private|fileprivate|internal|open|final|override|static|class|lazy|weak|unowned|nonisolated|dynamic)(?:\(set\))?\s+)*)(let|var)\s+(\w+)"
+    ).unwrap();
+
+    static ref VIEW_PROPERTY_PATTERN: Regex = Regex::new(
+        r"^\s*(?:@ViewBuilder\s+)?(?:(?:private|fileprivate|internal|public)\s+)?var\s+(\w+)\s*:\s*some\s+View\s*\{"
+    ).unwrap();
+}
+
+/// Genre d'une déclaration de type Swift
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeKind {
+    Class,
+    Struct,
+    Enum,
+    Actor,
+    Extension,
+    Protocol,
+}
+
+/// Déclaration de type (ou extension) repérée dans un fichier Swift
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeDeclaration {
+    pub kind: TypeKind,
+    pub name: String,
+    pub inherited: Vec<String>,
+    /// Attributs déclarés sur le type (`@MainActor`, `@objc`...)
+    pub attributes: Vec<String>,
+    /// Modificateurs (`private`, `final`, `public`...)
+    pub modifiers: Vec<String>,
+    /// Ligne de la déclaration (indexée à partir de 0)
+    pub line_start: usize,
+    /// Ligne de l'accolade fermante (indexée à partir de 0)
+    pub line_end: usize,
+}
+
+impl TypeDeclaration {
+    /// Indique si la ligne donnée appartient au corps du type
+    pub fn contains(&self, line: usize) -> bool {
+        line >= self.line_start && line <= self.line_end
+    }
+
+    /// Indique si le type déclare une conformance ou un héritage donné
+    pub fn conforms_to(&self, name: &str) -> bool {
+        self.inherited.iter().any(|i| i == name)
+    }
+
+    pub fn has_attribute(&self, attribute: &str) -> bool {
+        self.attributes.iter().any(|a| a == attribute)
+    }
+
+    pub fn has_modifier(&self, modifier: &str) -> bool {
+        self.modifiers.iter().any(|m| m == modifier)
+    }
+}
+
+/// Déclaration de fonction (ou d'initialiseur) repérée dans un fichier Swift
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionDeclaration {
+    pub name: String,
+    /// Type (ou extension) englobant, `None` pour une fonction globale
+    pub owner: Option<String>,
+    /// Attributs déclarés sur la fonction (`@MainActor`, `@objc`...)
+    pub attributes: Vec<String>,
+    /// Modificateurs (`private`, `override`, `static`...)
+    pub modifiers: Vec<String>,
+    /// Ligne de la déclaration (indexée à partir de 0)
+    pub line_start: usize,
+    /// Ligne de l'accolade fermante (indexée à partir de 0)
+    pub line_end: usize,
+}
+
+impl FunctionDeclaration {
+    /// Nom qualifié `Type.fonction`
+    pub fn qualified_name(&self) -> String {
+        match &self.owner {
+            Some(owner) => format!("{}.{}", owner, self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    pub fn has_attribute(&self, attribute: &str) -> bool {
+        self.attributes.iter().any(|a| a == attribute)
+    }
+
+    pub fn has_modifier(&self, modifier: &str) -> bool {
+        self.modifiers.iter().any(|m| m == modifier)
+    }
+}
+
+/// Propriété déclarée dans un type, hors de toute fonction
+#[derive(Debug, Clone)]
+pub struct PropertyDeclaration {
+    pub name: String,
+    pub owner: String,
+    /// Attributs et wrappers (`@Published`, `@NSManaged`, `@IBOutlet`...)
+    pub attributes: Vec<String>,
+    /// Modificateurs (`private`, `static`, `lazy`...)
+    pub modifiers: Vec<String>,
+    /// Déclarée avec `var`
+    pub mutable: bool,
+    /// Ligne de la déclaration (indexée à partir de 0)
+    pub line: usize,
+}
+
+impl PropertyDeclaration {
+    pub fn has_attribute(&self, attribute: &str) -> bool {
+        self.attributes.iter().any(|a| a == attribute)
+    }
+
+    pub fn has_modifier(&self, modifier: &str) -> bool {
+        self.modifiers.iter().any(|m| m == modifier)
+    }
+}
+
+/// Propriété `some View` (dont `body`) d'un type conforme à `View`
+#[derive(Debug, Clone)]
+pub struct ViewProperty {
+    pub name: String,
+    pub line_start: usize,
+    pub line_end: usize,
+}
+
+/// Remplace les commentaires et le contenu des chaînes littérales par des espaces.
+///
+/// Le nombre de lignes et la longueur en octets de chaque ligne sont conservés, ce qui
+/// permet de compter les accolades sans être trompé par `"{"` ou `// }` et de réutiliser
+/// les positions trouvées dans le contenu d'origine.
+pub fn sanitize_lines(content: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut in_block_comment = false;
+    let mut in_multiline_string = false;
+
+    for line in content.lines() {
+        let chars: Vec<char> = line.chars().collect();
+        let mut out = String::with_capacity(line.len());
+        let mut in_string = false;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if in_block_comment {
+                if c == '*' && next == Some('/') {
+                    in_block_comment = false;
+                    out.push_str("  ");
+                    i += 2;
+                } else {
+                    blank(&mut out, c);
+                    i += 1;
+                }
+                continue;
+            }
+
+            if in_multiline_string {
+                if c == '"' && next == Some('"') && chars.get(i + 2) == Some(&'"') {
+                    in_multiline_string = false;
+                    out.push_str("\"\"\"");
+                    i += 3;
+                } else {
+                    blank(&mut out, c);
+                    i += 1;
+                }
+                continue;
+            }
+
+            if in_string {
+                if c == '\\' {
+                    out.push(' ');
+                    if let Some(escaped) = next {
+                        blank(&mut out, escaped);
+                    }
+                    i += 2;
+                } else if c == '"' {
+                    in_string = false;
+                    out.push('"');
+                    i += 1;
+                } else {
+                    blank(&mut out, c);
+                    i += 1;
+                }
+                continue;
+            }
+
+            if c == '/' && next == Some('/') {
+                chars[i..].iter().for_each(|&rest| blank(&mut out, rest));
+                break;
+            }
+            if c == '/' && next == Some('*') {
+                in_block_comment = true;
+                out.push_str("  ");
+                i += 2;
+                continue;
+            }
+            if c == '"' {
+                if next == Some('"') && chars.get(i + 2) == Some(&'"') {
+                    in_multiline_string = true;
+                    out.push_str("\"\"\"");
+                    i += 3;
+                } else {
+                    in_string = true;
+                    out.push('"');
+                    i += 1;
+                }
+                continue;
+            }
+
+            out.push(c);
+            i += 1;
+        }
+
+        result.push(out);
+    }
+
+    result
+}
+
+/// Remplace un caractère par autant d'espaces que sa longueur en octets
+fn blank(out: &mut String, c: char) {
+    out.extend(std::iter::repeat_n(' ', c.len_utf8()));
+}
+
+/// Trouve la ligne de l'accolade fermante du bloc ouvert à partir de `start`.
+///
+/// Le bloc commence à la première accolade ouvrante rencontrée à partir de `start`.
+/// Retourne la dernière ligne du fichier si le bloc n'est jamais refermé.
+pub fn find_block_end(lines: &[String], start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut opened = false;
+
+    for (i, line) in lines.iter().enumerate().skip(start) {
+        for c in line.chars() {
+            match c {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' if depth > 0 => {
+                    depth -= 1;
+                    if opened && depth == 0 {
+                        return i;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    lines.len().saturating_sub(1)
+}
+
+/// Repère les déclarations de types, y compris imbriquées, dans des lignes nettoyées
+pub fn find_type_declarations(lines: &[String]) -> Vec<TypeDeclaration> {
+    let mut declarations = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some(cap) = TYPE_DECLARATION_PATTERN.captures(line) else {
+            continue;
+        };
+
+        let name = cap[3].to_string();
+        if matches!(name.as_str(), "func" | "var" | "let" | "subscript") {
+            // `class func`, `class var` : membres de classe et non des types
+            continue;
+        }
+
+        let kind = match &cap[2] {
+            "class" => TypeKind::Class,
+            "struct" => TypeKind::Struct,
+            "enum" => TypeKind::Enum,
+            "actor" => TypeKind::Actor,
+            "extension" => TypeKind::Extension,
+            _ => TypeKind::Protocol,
+        };
+
+        let inherited = cap
+            .get(4)
+            .map(|m| {
+                let clause = m.as_str().split(" where ").next().unwrap_or("");
+                clause
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        declarations.push(TypeDeclaration {
+            kind,
+            name,
+            inherited,
+            attributes: collect_attributes(lines, i, &cap[1]),
+            modifiers: collect_modifiers(&cap[1]),
+            line_start: i,
+            line_end: find_block_end(lines, i),
+        });
+    }
+
+    declarations
+}
+
+/// Repère les fonctions et initialiseurs (hors exigences de protocole) dans des lignes nettoyées.
+///
+/// Les attributs placés sur la ligne précédente (`@MainActor` seul sur sa ligne) sont
+/// rattachés à la fonction.
+pub fn find_functions(lines: &[String], declarations: &[TypeDeclaration]) -> Vec<FunctionDeclaration> {
+    let mut functions = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some(cap) = FUNCTION_PATTERN.captures(line) else {
+            continue;
+        };
+        let name = cap.get(3).or_else(|| cap.get(4)).unwrap().as_str().to_string();
+
+        let attributes = collect_attributes(lines, i, &cap[1]);
+
+        // Les exigences de protocole n'ont pas de corps
+        let owner = innermost_type_at(declarations, i);
+        if owner.map(|o| o.kind == TypeKind::Protocol).unwrap_or(false) {
+            continue;
+        }
+
+        functions.push(FunctionDeclaration {
+            name,
+            owner: owner.map(|o| o.name.clone()),
+            attributes,
+            modifiers: collect_modifiers(&cap[2]),
+            line_start: i,
+            line_end: find_block_end(lines, i),
+        });
+    }
+
+    functions
+}
+
+/// Repère les propriétés déclarées directement dans le corps des types (hors fonctions, accesseurs
+/// et exigences de protocole)
+pub fn find_properties(
+    lines: &[String],
+    declarations: &[TypeDeclaration],
+    functions: &[FunctionDeclaration],
+) -> Vec<PropertyDeclaration> {
+    let mut properties = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let Some(cap) = PROPERTY_PATTERN.captures(&lines[i]) else {
+            i += 1;
+            continue;
+        };
+        let inside_function = functions.iter().any(|f| i > f.line_start && i <= f.line_end);
+        let owner = innermost_type_at(declarations, i)
+            .filter(|d| d.line_start != i && d.kind != TypeKind::Protocol);
+        let (Some(owner), false) = (owner, inside_function) else {
+            i += 1;
+            continue;
+        };
+
+        properties.push(PropertyDeclaration {
+            name: cap[4].to_string(),
+            owner: owner.name.clone(),
+            attributes: collect_attributes(lines, i, &cap[1]),
+            modifiers: collect_modifiers(&cap[2]),
+            mutable: &cap[3] == "var",
+            line: i,
+        });
+
+        // Les variables locales d'un accesseur ou d'un `body` ne sont pas des propriétés
+        i = if lines[i].contains('{') { find_block_end(lines, i) + 1 } else { i + 1 };
+    }
+
+    properties
+}
+
+/// Rassemble les attributs d'une déclaration, y compris ceux placés seuls sur les lignes précédentes
+fn collect_attributes(lines: &[String], line: usize, prefix: &str) -> Vec<String> {
+    let mut attribute_text = prefix.to_string();
+    let mut previous = line;
+    while previous > 0 && lines[previous - 1].trim_start().starts_with('@') {
+        previous -= 1;
+        attribute_text.push(' ');
+        attribute_text.push_str(&lines[previous]);
+    }
+
+    attribute_text
+        .split_whitespace()
+        .filter(|a| a.starts_with('@'))
+        .map(|a| a.split('(').next().unwrap_or(a).to_string())
+        .collect()
+}
+
+/// Modificateurs (mots-clés hors attributs) d'un préfixe de déclaration
+fn collect_modifiers(prefix: &str) -> Vec<String> {
+    prefix
+        .split_whitespace()
+        .filter(|m| !m.starts_with('@'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Retourne la déclaration la plus interne contenant la ligne donnée
+pub fn innermost_type_at(declarations: &[TypeDeclaration], line: usize) -> Option<&TypeDeclaration> {
+    declarations
+        .iter()
+        .filter(|d| d.contains(line))
+        .min_by_key(|d| d.line_end - d.line_start)
+}
+
+/// Déclaration la plus interne contenant la ligne (indexée à partir de 0) : fonction qualifiée, sinon type
+pub fn enclosing_declaration(
+    declarations: &[TypeDeclaration],
+    functions: &[FunctionDeclaration],
+    line: usize,
+) -> Option<String> {
+    functions
+        .iter()
+        .filter(|f| line >= f.line_start && line <= f.line_end)
+        .max_by_key(|f| f.line_start)
+        .map(FunctionDeclaration::qualified_name)
+        .or_else(|| innermost_type_at(declarations, line).map(|t| t.name.clone()))
+}
+
+/// Retourne la plage de lignes du `deinit` d'un type, s'il en déclare un
+pub fn find_deinit(lines: &[String], declaration: &TypeDeclaration) -> Option<(usize, usize)> {
+    (declaration.line_start + 1..declaration.line_end)
+        .find(|&i| DEINIT_PATTERN.is_match(&lines[i]))
+        .map(|i| (i, find_block_end(lines, i)))
+}
+
+/// Repère `body` et les autres propriétés `some View` des types conformes à `View`
+pub fn find_view_properties(lines: &[String], declarations: &[TypeDeclaration]) -> Vec<ViewProperty> {
+    let view_types: Vec<&str> = declarations
+        .iter()
+        .filter(|d| d.kind != TypeKind::Protocol && d.conforms_to("View"))
+        .map(|d| d.name.as_str())
+        .collect();
+
+    let mut properties = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let Some(cap) = VIEW_PROPERTY_PATTERN.captures(line) else {
+            continue;
+        };
+        let in_view = innermost_type_at(declarations, i)
+            .map(|d| view_types.contains(&d.name.as_str()))
+            .unwrap_or(false);
+        if in_view {
+            properties.push(ViewProperty {
+                name: cap[1].to_string(),
+                line_start: i,
+                line_end: find_block_end(lines, i),
+            });
+        }
+    }
+    properties
+}
+
+/// Reconstitue l'instruction complète autour d'une ligne.
+///
+/// Les appels chaînés sur plusieurs lignes (`.sink { ... }` suivi de `.store(in:)`)
+/// sont regroupés : on remonte tant que la ligne courante commence par `.`, puis on
+/// descend jusqu'à la fin des blocs ouverts et des maillons de chaîne suivants.
+pub fn statement_range(lines: &[String], line: usize) -> (usize, usize) {
+    let mut start = line;
+    while start > 0 && lines[start].trim_start().starts_with('.') {
+        start -= 1;
+    }
+
+    let mut end = line;
+    let mut depth: i32 = 0;
+    loop {
+        for c in lines[end].chars() {
+            match c {
+                '{' | '(' | '[' => depth += 1,
+                '}' | ')' | ']' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        let next_is_chain = lines
+            .get(end + 1)
+            .map(|l| l.trim_start().starts_with('.'))
+            .unwrap_or(false);
+
+        if end + 1 >= lines.len() || (depth <= 0 && !next_is_chain) {
+            break;
+        }
+        end += 1;
+    }
+
+    (start, end)
+}
+
+/// Retourne l'indice (en octets) de la parenthèse fermant celle ouverte à `open`.
+///
+/// Les chaînes littérales sont ignorées, ce qui permet de travailler sur le contenu brut.
+pub fn matching_parenthesis(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text[open..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Découpe une liste d'arguments d'appel en paires (étiquette, valeur).
+///
+/// Les virgules imbriquées dans des parenthèses, crochets, accolades ou chaînes sont ignorées.
+pub fn split_arguments(arguments: &str) -> Vec<(Option<String>, String)> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in arguments.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth -= 1,
+            ',' if !in_string && depth == 0 => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        parts.push(current);
+    }
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let part = part.trim();
+            match part.split_once(':') {
+                Some((label, value))
+                    if !label.is_empty() && label.trim().chars().all(|c| c.is_alphanumeric() || c == '_') =>
+                {
+                    (Some(label.trim().to_string()), value.trim().to_string())
+                }
+                _ => (None, part.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Numéro de ligne (indexé à partir de 0) correspondant à un décalage en octets
+pub fn line_index_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_blanks_comments_and_strings_keeping_byte_offsets() {
+        let content = "let café = \"{é}\" // }\r\n/* début\r\n } */ let a = \"\"\"\r\n  }) \"é\r\n  \"\"\"\r\nlet b = \"\\\"}\"\r\n";
+        let sanitized = sanitize_lines(content);
+        let raw: Vec<&str> = content.lines().collect();
+
+        assert_eq!(sanitized.len(), raw.len());
+        for (clean, original) in sanitized.iter().zip(&raw) {
+            assert_eq!(clean.len(), original.len());
+        }
+        assert_eq!(sanitized[0].trim_end(), "let café = \"    \"");
+        assert_eq!(sanitized[1].trim(), "");
+        assert_eq!(sanitized[2].trim(), "let a = \"\"\"");
+        assert_eq!(sanitized[3].trim(), "");
+        assert_eq!(sanitized[4].trim(), "\"\"\"");
+        assert_eq!(sanitized[5], "let b = \"   \"");
+    }
+
+    #[test]
+    fn test_declarations_and_blocks_ignore_braces_in_strings_and_comments() {
+        let content = "\
+@MainActor
+final class Éditeur: NSObject, ObservableObject {
+    let pattern = \"{{\" // {
+    struct Ligne<T>: Hashable where T: Equatable {
+        /* } */
+    }
+    func rendre(_ texte: String) -> String {
+        return \"}\" + texte
+    }
+    init?(valeur: Int) {}
+}
+protocol Source {
+    func charger()
+}
+func global() {}
+";
+        let lines = sanitize_lines(content);
+        let declarations = find_type_declarations(&lines);
+        let summary: Vec<(&str, TypeKind, usize, usize)> =
+            declarations.iter().map(|d| (d.name.as_str(), d.kind, d.line_start, d.line_end)).collect();
+        assert_eq!(
+            summary,
+            [
+                ("Éditeur", TypeKind::Class, 1, 10),
+                ("Ligne", TypeKind::Struct, 3, 5),
+                ("Source", TypeKind::Protocol, 11, 13)
+            ]
+        );
+        assert_eq!(declarations[0].inherited, ["NSObject", "ObservableObject"]);
+        assert_eq!(declarations[0].attributes, ["@MainActor"]);
+        assert_eq!(declarations[0].modifiers, ["final"]);
+        assert_eq!(declarations[1].inherited, ["Hashable"]);
+
+        let functions = find_functions(&lines, &declarations);
+        let summary: Vec<(String, usize, usize)> =
+            functions.iter().map(|f| (f.qualified_name(), f.line_start, f.line_end)).collect();
+        assert_eq!(
+            summary,
+            [("Éditeur.rendre".to_string(), 6, 8), ("Éditeur.init".to_string(), 9, 9), ("global".to_string(), 14, 14)]
+        );
+        assert_eq!(enclosing_declaration(&declarations, &functions, 7).as_deref(), Some("Éditeur.rendre"));
+        assert_eq!(enclosing_declaration(&declarations, &functions, 4).as_deref(), Some("Ligne"));
+        assert_eq!(find_block_end(&lines, 11), 13);
+        // Bloc jamais refermé : dernière ligne
+        assert_eq!(find_block_end(&sanitize_lines("func f() {\n  if x {\n"), 0), 1);
+    }
+
+    #[test]
+    fn test_statements_parentheses_and_arguments() {
+        let lines = sanitize_lines("publisher\n    .sink { value in\n        print(value)\n    }\n    .store(in: &bag)\nlet next = 1\n");
+        assert_eq!(statement_range(&lines, 1), (0, 4));
+        assert_eq!(statement_range(&lines, 5), (5, 5));
+
+        let call = "fetch(\"a)\", sort: [key(\"é\")], limit: (1, 2)) + rest()";
+        let close = matching_parenthesis(call, 5).unwrap();
+        assert_eq!(&call[close..close + 3], ") +");
+        assert_eq!(matching_parenthesis("open(never", 4), None);
+
+        let arguments = split_arguments(&call[6..close]);
+        assert_eq!(
+            arguments,
+            [
+                (None, "\"a)\"".to_string()),
+                (Some("sort".to_string()), "[key(\"é\")]".to_string()),
+                (Some("limit".to_string()), "(1, 2)".to_string())
+            ]
+        );
+        assert_eq!(
+            split_arguments("\"a\\\", b\", c: 1"),
+            [(None, "\"a\\\", b\"".to_string()), (Some("c".to_string()), "1".to_string())]
+        );
+    }
+}
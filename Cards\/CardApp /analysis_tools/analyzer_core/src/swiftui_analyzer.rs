@@ -0,0 +1,425 @@
+use crate::models::{FileIssue, IssueType, Severity};
+use crate::rules::{AnalysisLevel, Rule, RuleCategory, RuleContext, RuleExplanation, RuleMetadata, SourceFile};
+use crate::swift_source::{self, PropertyDeclaration, TypeDeclaration, TypeKind, ViewProperty};
+use crate::types::SwiftUIIssueType;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Nombre de propriétés stockées à partir duquel un struct publié est jugé volumineux
+const LARGE_PUBLISHED_STRUCT_FIELDS: usize = 8;
+
+/// Longueur minimale d'une chaîne de modificateurs pour être signalée comme dupliquée
+const MIN_DUPLICATED_CHAIN_LENGTH: usize = 3;
+
+lazy_static! {
+    static ref OBSERVED_OBJECT_INLINE_PATTERN: Regex = Regex::new(
+        r"@ObservedObject\s+(?:(?:private|fileprivate|internal|public)\s+)?var\s+(\w+)\s*(?::\s*[\w.]+)?\s*=\s*[\w.]+\s*\("
+    ).unwrap();
+
+    static ref PUBLISHED_PATTERN: Regex = Regex::new(
+        r"@Published\s+(?:(?:private|fileprivate|internal|public)\s+)?(?:private\(set\)\s+)?var\s+(\w+)\s*(?::\s*(\w+))?(?:\s*=\s*(\w+)\s*\()?"
+    ).unwrap();
+
+    static ref STORED_PROPERTY_PATTERN: Regex = Regex::new(
+        r"^\s*(?:@\w+\s+)*(?:(?:private|fileprivate|internal|public)\s+)?(?:let|var)\s+\w+"
+    ).unwrap();
+
+    static ref FOR_EACH_SELF_ID_PATTERN: Regex = Regex::new(
+        r"ForEach\s*\(\s*\$?([\w.]+)\s*,\s*id\s*:\s*\\\.self"
+    ).unwrap();
+
+    static ref ON_APPEAR_TASK_PATTERN: Regex = Regex::new(
+        r"\.onAppear\s*\{\s*(?:Task\s*(?:\(\s*priority\s*:[^)]*\))?\s*\{|$)"
+    ).unwrap();
+
+    static ref MODIFIER_PATTERN: Regex = Regex::new(
+        r"^\s*\.(\w+)\s*(\(.*\))?\s*$"
+    ).unwrap();
+
+    static ref EXPENSIVE_BODY_PATTERNS: Vec<(Regex, &'static str, Severity)> = vec![
+        // La création d'une NSFetchRequest dans body relève de `coredata.fetch-request`
+        (Regex::new(r"\.fetch\s*\(").unwrap(), "l'exécution d'une requête Core Data", Severity::High),
+        (Regex::new(r"\bJSONDecoder\s*\(\s*\)").unwrap(), "un décodage JSON", Severity::High),
+        (Regex::new(r"\b(?:Date|Number|ISO8601Date)Formatter\s*\(\s*\)").unwrap(), "la création d'un formateur", Severity::Medium),
+        (Regex::new(r"\.sorted\s*[({]|\.sort\s*[({]").unwrap(), "un tri", Severity::Medium),
+        (Regex::new(r"\.filter\s*[({]").unwrap(), "un filtrage", Severity::Medium),
+    ];
+}
+
+/// Analyseur des problèmes de performance propres à SwiftUI
+#[derive(Default)]
+pub struct SwiftUIAnalyzer;
+
+impl SwiftUIAnalyzer {
+    pub const RULE: RuleMetadata = RuleMetadata::new(
+        "swiftui.view-performance",
+        RuleCategory::SwiftUI,
+        Severity::Medium,
+        "Mises à jour de vues excessives, calculs lourds dans body et modificateurs redondants",
+    )
+    .with_level(AnalysisLevel::Ast)
+    .with_explanation(RuleExplanation {
+        rationale: "body est réévalué à chaque changement d'état observé. Un @ObservedObject créé en ligne est recréé à chaque reconstruction du parent, un gros struct @Published invalide toutes les vues observatrices, un ForEach identifié par \\.self recrée ses lignes et les calculs (tri, formatage, requêtes) placés dans body sont refaits à chaque rendu.",
+        bad_example: "struct DeckView: View {\n    @ObservedObject var viewModel = DeckViewModel()\n    var body: some View {\n        List(viewModel.cards.sorted { $0.dueDate < $1.dueDate }) { CardRow(card: $0) }\n    }\n}",
+        good_example: "struct DeckView: View {\n    @StateObject private var viewModel = DeckViewModel()\n    var body: some View {\n        List(viewModel.state.sortedCards) { CardRow(card: $0) }\n    }\n}",
+        conventions: &["2.1 Principe de Séparation", "3.3 Gestion des Propriétés Publiées"],
+    });
+
+    pub fn new() -> Self {
+        SwiftUIAnalyzer
+    }
+
+    /// Analyser un fichier pour les problèmes potentiels de SwiftUI
+    pub fn analyze(&self, content: &str) -> Vec<FileIssue> {
+        let mut issues = Vec::new();
+
+        if !content.contains("SwiftUI") {
+            return issues;
+        }
+
+        let lines = swift_source::sanitize_lines(content);
+        let raw_lines: Vec<&str> = content.lines().collect();
+        let declarations = swift_source::find_type_declarations(&lines);
+        let view_properties = swift_source::find_view_properties(&lines, &declarations);
+        let functions = swift_source::find_functions(&lines, &declarations);
+        let properties = swift_source::find_properties(&lines, &declarations, &functions);
+
+        self.analyze_body_computation(&lines, &raw_lines, &view_properties, &mut issues);
+        self.analyze_observed_objects(&lines, &raw_lines, &mut issues);
+        self.analyze_published_structs(&lines, &raw_lines, &declarations, &mut issues);
+        self.analyze_for_each_identity(&lines, &raw_lines, &declarations, &properties, &mut issues);
+        self.analyze_task_modifiers(&lines, &raw_lines, &view_properties, &mut issues);
+        self.analyze_modifier_chains(&lines, &raw_lines, &view_properties, &mut issues);
+
+        issues.sort_by_key(|i| i.line);
+        issues
+    }
+
+    /// Travail coûteux (tri, filtrage, formatage, requêtes) dans `body`
+    fn analyze_body_computation(
+        &self,
+        lines: &[String],
+        raw_lines: &[&str],
+        view_properties: &[ViewProperty],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        for property in view_properties {
+            for (i, line) in lines.iter().enumerate().take(property.line_end + 1).skip(property.line_start) {
+                for (pattern, description, severity) in EXPENSIVE_BODY_PATTERNS.iter() {
+                    if let Some(m) = pattern.find(line) {
+                        issues.push(self.issue(
+                            SwiftUIIssueType::HeavyViewComputation,
+                            *severity,
+                            i,
+                            Some(m.start()),
+                            format!(
+                                "'{}' effectue {} à chaque évaluation de la vue",
+                                property.name, description
+                            ),
+                            "Déplacer ce calcul dans le view model ou le mettre en cache dans une propriété calculée une seule fois",
+                            raw_lines,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// `@ObservedObject` initialisé en ligne au lieu de `@StateObject`
+    fn analyze_observed_objects(&self, lines: &[String], raw_lines: &[&str], issues: &mut Vec<FileIssue>) {
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(cap) = OBSERVED_OBJECT_INLINE_PATTERN.captures(line) {
+                issues.push(self.issue(
+                    SwiftUIIssueType::ExcessiveViewUpdates,
+                    Severity::High,
+                    i,
+                    cap.get(0).map(|m| m.start()),
+                    format!(
+                        "@ObservedObject '{}' initialisé en ligne : l'objet est recréé à chaque reconstruction de la vue parente",
+                        &cap[1]
+                    ),
+                    "Utiliser @StateObject pour les objets créés par la vue, ou injecter l'objet depuis le parent",
+                    raw_lines,
+                ));
+            }
+        }
+    }
+
+    /// Structs volumineux publiés d'un bloc : toute modification invalide toutes les vues abonnées
+    fn analyze_published_structs(
+        &self,
+        lines: &[String],
+        raw_lines: &[&str],
+        declarations: &[TypeDeclaration],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        let struct_sizes: HashMap<&str, usize> = declarations
+            .iter()
+            .filter(|d| d.kind == TypeKind::Struct)
+            .map(|d| (d.name.as_str(), count_stored_properties(lines, d)))
+            .collect();
+
+        for (i, line) in lines.iter().enumerate() {
+            let Some(cap) = PUBLISHED_PATTERN.captures(line) else {
+                continue;
+            };
+            let type_name = cap.get(2).or_else(|| cap.get(3)).map(|m| m.as_str());
+            let Some(type_name) = type_name else {
+                continue;
+            };
+
+            if let Some(&fields) = struct_sizes.get(type_name) {
+                if fields >= LARGE_PUBLISHED_STRUCT_FIELDS {
+                    issues.push(self.issue(
+                        SwiftUIIssueType::ExcessiveViewUpdates,
+                        Severity::Medium,
+                        i,
+                        cap.get(0).map(|m| m.start()),
+                        format!(
+                            "@Published '{}' publie le struct '{}' ({} propriétés) : chaque modification invalide toutes les vues observatrices",
+                            &cap[1], type_name, fields
+                        ),
+                        "Découper l'état en plusieurs propriétés @Published ciblées ou isoler les champs modifiés fréquemment",
+                        raw_lines,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// `ForEach(..., id: \.self)` sur une propriété `var` de la vue qui le contient
+    fn analyze_for_each_identity(
+        &self,
+        lines: &[String],
+        raw_lines: &[&str],
+        declarations: &[TypeDeclaration],
+        properties: &[PropertyDeclaration],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        for (i, line) in lines.iter().enumerate() {
+            let Some(cap) = FOR_EACH_SELF_ID_PATTERN.captures(line) else {
+                continue;
+            };
+            // Seules les propriétés du type englobant sont connues (`items` ou `self.items`)
+            let collection = cap[1].strip_prefix("self.").unwrap_or(&cap[1]).to_string();
+            let Some(owner) = swift_source::innermost_type_at(declarations, i) else {
+                continue;
+            };
+            let mutable = properties
+                .iter()
+                .any(|property| property.owner == owner.name && property.name == collection && property.mutable);
+
+            if mutable {
+                issues.push(self.issue(
+                    SwiftUIIssueType::InefficientBinding,
+                    Severity::Medium,
+                    i,
+                    cap.get(0).map(|m| m.start()),
+                    format!(
+                        "ForEach sur la collection mutable '{}' identifiée par \\.self : toute modification d'un élément recrée sa vue",
+                        collection
+                    ),
+                    "Rendre les éléments Identifiable (id stable) et retirer id: \\.self",
+                    raw_lines,
+                ));
+            }
+        }
+    }
+
+    /// Tâches lancées depuis `.onAppear` au lieu du modificateur `.task`
+    fn analyze_task_modifiers(
+        &self,
+        lines: &[String],
+        raw_lines: &[&str],
+        view_properties: &[ViewProperty],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        for property in view_properties {
+            for i in property.line_start..=property.line_end {
+                let Some(m) = ON_APPEAR_TASK_PATTERN.find(&lines[i]) else {
+                    continue;
+                };
+                let launches_task = lines[i].contains("Task")
+                    || lines.get(i + 1).map(|l| l.trim_start().starts_with("Task")).unwrap_or(false);
+                if launches_task {
+                    issues.push(self.issue(
+                        SwiftUIIssueType::MissingViewModifier,
+                        Severity::Low,
+                        i,
+                        Some(m.start()),
+                        "Tâche asynchrone lancée depuis .onAppear : elle n'est pas annulée quand la vue disparaît".to_string(),
+                        "Utiliser le modificateur .task { } qui annule automatiquement la tâche",
+                        raw_lines,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Modificateurs appliqués deux fois de suite et chaînes de modificateurs dupliquées
+    fn analyze_modifier_chains(
+        &self,
+        lines: &[String],
+        raw_lines: &[&str],
+        view_properties: &[ViewProperty],
+        issues: &mut Vec<FileIssue>,
+    ) {
+        let mut chains: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for property in view_properties {
+            let mut chain: Vec<(usize, String)> = Vec::new();
+
+            // Une ligne vide en fin de plage termine la dernière chaîne en cours
+            let range = lines
+                .iter()
+                .map(String::as_str)
+                .enumerate()
+                .take(property.line_end + 1)
+                .skip(property.line_start);
+            for (i, line) in range.chain(std::iter::once((property.line_end + 1, ""))) {
+                let modifier = MODIFIER_PATTERN
+                    .captures(line)
+                    .map(|cap| format!(".{}{}", &cap[1], cap.get(2).map(|m| m.as_str()).unwrap_or("")));
+
+                match modifier {
+                    Some(modifier) => {
+                        if let Some((_, previous)) = chain.last() {
+                            if *previous == modifier {
+                                issues.push(self.issue(
+                                    SwiftUIIssueType::RedundantViewModifier,
+                                    Severity::Low,
+                                    i,
+                                    None,
+                                    format!("Modificateur {} appliqué deux fois de suite", modifier),
+                                    "Supprimer le modificateur redondant",
+                                    raw_lines,
+                                ));
+                            }
+                        }
+                        chain.push((i, modifier));
+                    }
+                    None => {
+                        if chain.len() >= MIN_DUPLICATED_CHAIN_LENGTH {
+                            let key = chain.iter().map(|(_, m)| m.as_str()).collect::<Vec<_>>().join("");
+                            chains.entry(key).or_default().push(chain[0].0);
+                        }
+                        chain.clear();
+                    }
+                }
+            }
+        }
+
+        for occurrences in chains.values().filter(|o| o.len() > 1) {
+            for &line in occurrences.iter().skip(1) {
+                issues.push(self.issue(
+                    SwiftUIIssueType::RedundantViewModifier,
+                    Severity::Low,
+                    line,
+                    None,
+                    format!(
+                        "Chaîne de modificateurs dupliquée {} fois dans le fichier (première occurrence ligne {})",
+                        occurrences.len(),
+                        occurrences[0] + 1
+                    ),
+                    "Extraire la chaîne dans un ViewModifier personnalisé ou une extension de View",
+                    raw_lines,
+                ));
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn issue(
+        &self,
+        kind: SwiftUIIssueType,
+        severity: Severity,
+        line: usize,
+        column: Option<usize>,
+        message: String,
+        suggestion: &str,
+        raw_lines: &[&str],
+    ) -> FileIssue {
+        FileIssue {
+            issue_type: IssueType::from(kind),
+            severity,
+            line: line + 1,
+            column,
+            message,
+            suggestion: Some(suggestion.to_string()),
+            code_snippet: raw_lines.get(line).map(|l| l.to_string()),
+            rule_id: None,
+            declaration: None,
+            ownership: None,
+            fix: None,
+        }
+    }
+}
+
+impl Rule for SwiftUIAnalyzer {
+    fn metadata(&self) -> &RuleMetadata {
+        &Self::RULE
+    }
+
+    fn check(&self, file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+        self.analyze(file.content)
+    }
+}
+
+/// Compte les propriétés stockées déclarées directement dans un type
+fn count_stored_properties(lines: &[String], declaration: &TypeDeclaration) -> usize {
+    let mut depth = 0i32;
+    let mut count = 0;
+
+    for line in &lines[declaration.line_start..=declaration.line_end] {
+        if depth == 1 && STORED_PROPERTY_PATTERN.is_match(line) && !line.trim_end().ends_with('{') {
+            count += 1;
+        }
+        depth += line.matches('{').count() as i32 - line.matches('}').count() as i32;
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyzer() -> SwiftUIAnalyzer {
+        SwiftUIAnalyzer::new()
+    }
+
+    #[test]
+    fn test_detect_sorting_in_body() {
+        let content = "import SwiftUI\nstruct DeckList: View {\n    var decks: [Deck]\n    var body: some View {\n        List(decks.sorted { $0.name < $1.name }) { deck in\n            Text(deck.name)\n        }\n    }\n}";
+
+        let issues = analyzer().analyze(content);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_type, IssueType::HeavyViewComputation);
+        assert_eq!((issues[0].line, issues[0].column), (5, Some(18)));
+    }
+
+    #[test]
+    fn test_detect_inline_observed_object() {
+        let content = "import SwiftUI\nstruct StatsView: View {\n    @ObservedObject var viewModel = StatisticsViewModel()\n    var body: some View {\n        Text(\"\")\n    }\n}";
+
+        let issues = analyzer().analyze(content);
+
+        assert!(issues.iter().any(|i| i.issue_type == IssueType::ExcessiveViewUpdates && i.line == 3));
+    }
+
+    #[test]
+    fn test_for_each_self_id_only_on_mutable_property_of_the_view() {
+        let content = "import SwiftUI\nstruct TagList: View {\n    let tags: [String]\n    var body: some View {\n        ForEach(tags, id: \\.self) { Text($0) }\n    }\n}\nstruct Editor: View {\n    @State private var tags: [String] = []\n    var body: some View {\n        ForEach(self.tags, id: \\.self) { Text($0) }\n    }\n}\nfinal class Store {\n    var tags: [String] = []\n}\n";
+
+        let lines: Vec<usize> = analyzer()
+            .analyze(content)
+            .iter()
+            .filter(|issue| issue.issue_type == IssueType::InefficientBinding)
+            .map(|issue| issue.line)
+            .collect();
+
+        assert_eq!(lines, [11]);
+    }
+}
@@ -0,0 +1,104 @@
+//! Outils partagés par les tests unitaires : règles restreintes, règle personnalisée et sources d'exemple
+
+use crate::models::{FileIssue, IssueType, Severity};
+use crate::rules::{Rule, RuleCategory, RuleContext, RuleMetadata, RuleRegistry, RuleSelector, SourceFile};
+
+/// Règles intégrées sans les catégories `performance` et `deadcode`, pour ne détecter
+/// que les problèmes visés par les sources d'exemple
+pub fn focused_registry() -> RuleRegistry {
+    let mut registry = RuleRegistry::builtin();
+    for selector in ["category:performance", "category:deadcode"] {
+        registry.disable(&selector.parse::<RuleSelector>().unwrap()).unwrap();
+    }
+    registry
+}
+
+/// Règle personnalisée d'exemple : interdit `print(` dans le code de production (Low, catégorie `resources`)
+pub struct NoPrintRule;
+
+impl NoPrintRule {
+    pub const RULE: RuleMetadata = RuleMetadata::new(
+        "cardapp.no-print",
+        RuleCategory::Resources,
+        Severity::Low,
+        "Appel à print() dans le code de production",
+    );
+}
+
+impl Rule for NoPrintRule {
+    fn metadata(&self) -> &RuleMetadata {
+        &Self::RULE
+    }
+
+    fn check(&self, file: &SourceFile, _context: &RuleContext) -> Vec<FileIssue> {
+        file.lines()
+            .enumerate()
+            .filter(|(_, line)| line.contains("print("))
+            .map(|(i, line)| FileIssue {
+                issue_type: IssueType::ExcessiveComputation,
+                severity: Severity::Low,
+                line: i + 1,
+                column: line.find("print("),
+                message: "Appel à print() dans le code de production".to_string(),
+                suggestion: Some("Utilisez os.Logger".to_string()),
+                code_snippet: Some(line.to_string()),
+                rule_id: None,
+                declaration: None,
+                ownership: None,
+                fix: None,
+            })
+            .collect()
+    }
+}
+
+/// Sources d'exemple déclenchant chacune une règle connue, à une ligne connue
+pub mod fixtures {
+    /// `memory.strong-delegate` (High), ligne 2
+    pub const STRONG_DELEGATE: &str = "class Editor {\n    var delegate: EditorDelegate?\n}\n";
+    /// [`STRONG_DELEGATE`] corrigé : aucun problème
+    pub const WEAK_DELEGATE: &str = "class Editor {\n    weak var delegate: EditorDelegate?\n}\n";
+    /// `memory.closure-self-capture` (High), ligne 3
+    pub const CLOSURE_SELF_CAPTURE: &str =
+        "class DeckLoader {\n    func reload() {\n        service.fetchCards { cards in self.cards = cards }\n    }\n}\n";
+    /// `coredata.background-view-context` (Critical), ligne 3
+    pub const BACKGROUND_VIEW_CONTEXT: &str = "func importCards(container: NSPersistentContainer) {\n    container.performBackgroundTask { context in\n        let decks = try? container.viewContext.fetch(request)\n    }\n}\n";
+    /// `coredata.unhandled-fetch` (Medium), ligne 2
+    pub const UNHANDLED_FETCH: &str =
+        "func countCards() -> Int {\n    let cards = context.fetch(NSFetchRequest<Card>(entityName: \"Card\"))\n    return cards.count\n}\n";
+    /// `coredata.view-context-perform` (Low), ligne 2
+    pub const VIEW_CONTEXT_PERFORM: &str = "func refresh() {\n    container.viewContext.perform(refreshAll)\n}\n";
+    /// `cardapp.no-print` de [`NoPrintRule`](super::NoPrintRule) (Low), ligne 2 ; aucune règle intégrée
+    pub const PRINT_CALL: &str = "func shuffle() {\n    print(\"shuffle\")\n}\n";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::*;
+    use super::*;
+    use crate::analyzer::Analyzer;
+    use crate::models::AnalysisConfig;
+
+    #[test]
+    fn test_each_fixture_triggers_its_rule_only() {
+        let cases = [
+            (STRONG_DELEGATE, Some(("memory.strong-delegate", Severity::High, 2))),
+            (WEAK_DELEGATE, None),
+            (CLOSURE_SELF_CAPTURE, Some(("memory.closure-self-capture", Severity::High, 3))),
+            (BACKGROUND_VIEW_CONTEXT, Some(("coredata.background-view-context", Severity::Critical, 3))),
+            (UNHANDLED_FETCH, Some(("coredata.unhandled-fetch", Severity::Medium, 2))),
+            (VIEW_CONTEXT_PERFORM, Some(("coredata.view-context-perform", Severity::Low, 2))),
+        ];
+        for (source, expected) in cases {
+            let report = Analyzer::new(AnalysisConfig::default())
+                .with_registry(focused_registry())
+                .with_source("Fixture.swift", source)
+                .run();
+            let issues: Vec<(&str, Severity, usize)> = report.files_analyzed[0]
+                .issues
+                .iter()
+                .map(|issue| (issue.rule_id.as_deref().unwrap_or_default(), issue.severity, issue.line))
+                .collect();
+            assert_eq!(issues, Vec::from_iter(expected), "{}", source);
+        }
+    }
+}
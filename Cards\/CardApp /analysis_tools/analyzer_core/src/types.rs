@@ -12,6 +12,18 @@ pub enum SwiftUIIssueType {
     HeavyViewComputation,
 }
 
+impl From<SwiftUIIssueType> for IssueType {
+    fn from(issue_type: SwiftUIIssueType) -> Self {
+        match issue_type {
+            SwiftUIIssueType::ExcessiveViewUpdates => IssueType::ExcessiveViewUpdates,
+            SwiftUIIssueType::InefficientBinding => IssueType::InefficientBinding,
+            SwiftUIIssueType::MissingViewModifier => IssueType::MissingViewModifier,
+            SwiftUIIssueType::RedundantViewModifier => IssueType::RedundantViewModifier,
+            SwiftUIIssueType::HeavyViewComputation => IssueType::HeavyViewComputation,
+        }
+    }
+}
+
 /// Types de problèmes spécifiques à CoreData
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CoreDataIssueType {
@@ -23,6 +35,19 @@ pub enum CoreDataIssueType {
     MissingFetchedResultsController,
 }
 
+impl From<CoreDataIssueType> for IssueType {
+    fn from(issue_type: CoreDataIssueType) -> Self {
+        match issue_type {
+            CoreDataIssueType::MissingIndex => IssueType::MissingIndex,
+            CoreDataIssueType::InefficientFetch => IssueType::InefficientFetch,
+            CoreDataIssueType::BatchingOpportunity => IssueType::BatchingOpportunity,
+            CoreDataIssueType::RedundantSaves => IssueType::RedundantSaves,
+            CoreDataIssueType::ThreadingViolation => IssueType::CoreDataMainThread,
+            CoreDataIssueType::MissingFetchedResultsController => IssueType::MissingFetchedResultsController,
+        }
+    }
+}
+
 /// Information sur la complexité d'une fonction
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionComplexity {
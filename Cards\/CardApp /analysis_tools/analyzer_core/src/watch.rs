@@ -0,0 +1,304 @@
+//! Analyse continue d'un projet : réanalyse à chaque modification des sources
+//! ou du modèle CoreData, et différences avec l'analyse précédente
+//!
+//! Les sources sont conservées en mémoire avec un cache d'analyse en mémoire :
+//! seuls les fichiers dont le contenu a changé sont réanalysés, les règles à
+//! l'échelle du projet étant réévaluées sur l'index complet. Le modèle CoreData
+//! modifié est rechargé, ce qui réanalyse tous les fichiers. Les notifications
+//! du système de fichiers sont à la charge de l'appelant, qui transmet les
+//! chemins modifiés à `WatchSession::update`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::analyzer::Analyzer;
+use crate::cache::AnalysisCache;
+use crate::core_data_model::CoreDataModel;
+use crate::discovery::{self, collect_source_files};
+use crate::fetch_request_analyzer::FetchRequestAnalyzer;
+use crate::models::{AnalysisConfig, PerformanceReport};
+use crate::report_diff::ReportDiff;
+use crate::rules::RuleRegistry;
+
+/// Options de l'analyse continue
+#[derive(Clone)]
+pub struct WatchOptions {
+    /// Extensions des sources analysées
+    pub extensions: Vec<String>,
+    /// Règles actives
+    pub registry: RuleRegistry,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        WatchOptions {
+            extensions: vec!["swift".to_string()],
+            registry: RuleRegistry::builtin(),
+        }
+    }
+}
+
+/// Réanalyse après une modification
+#[derive(Debug, Clone)]
+pub struct WatchUpdate {
+    /// Sources ajoutées, modifiées ou supprimées
+    pub changed_files: Vec<PathBuf>,
+    /// Le modèle CoreData a été rechargé
+    pub model_reloaded: bool,
+    /// Différences avec le rapport précédent
+    pub diff: ReportDiff,
+}
+
+/// Projet analysé en continu : sources en mémoire et dernier rapport
+pub struct WatchSession {
+    project: WatchedProject,
+    report: PerformanceReport,
+}
+
+struct WatchedProject {
+    root: PathBuf,
+    config: AnalysisConfig,
+    options: WatchOptions,
+    cache: AnalysisCache,
+    sources: BTreeMap<PathBuf, String>,
+    core_data_model: Arc<CoreDataModel>,
+}
+
+impl WatchSession {
+    /// Analyse initiale de tout le projet sous `root` ; les sources illisibles sont listées dans `PerformanceReport::warnings`
+    pub fn start(root: &Path, config: AnalysisConfig, options: WatchOptions) -> Self {
+        // Chemins résolus, comme ceux des notifications du système de fichiers
+        let root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+        let mut read_errors = Vec::new();
+        let sources = collect_source_files(&root, &options.extensions)
+            .into_iter()
+            .filter_map(|path| match fs::read_to_string(&path) {
+                Ok(content) => Some((path, content)),
+                Err(e) => {
+                    read_errors.push(format!("Erreur lors de la lecture du fichier {}: {}", path.display(), e));
+                    None
+                }
+            })
+            .collect();
+        let project = WatchedProject {
+            core_data_model: Arc::new(load_model(&root, &options.registry)),
+            root,
+            config,
+            options,
+            cache: AnalysisCache::in_memory(),
+            sources,
+        };
+        // Les sources illisibles ne sont signalées que dans le rapport initial
+        let mut report = project.analyze();
+        report.warnings.extend(read_errors);
+        WatchSession { report, project }
+    }
+
+    /// Racine du projet, résolue
+    pub fn root(&self) -> &Path {
+        &self.project.root
+    }
+
+    /// Dernier rapport
+    pub fn report(&self) -> &PerformanceReport {
+        &self.report
+    }
+
+    /// Réanalyse après la modification de `paths` (fichiers ou répertoires, existants ou supprimés)
+    ///
+    /// `None` si aucun de ces chemins ne modifie une source ou le modèle CoreData.
+    pub fn update(&mut self, paths: &[PathBuf]) -> Option<WatchUpdate> {
+        let (changed_files, model_reloaded) = self.project.apply(paths);
+        if changed_files.is_empty() && !model_reloaded {
+            return None;
+        }
+
+        let report = self.project.analyze();
+        let diff = ReportDiff::between(&self.report, &report);
+        self.report = report;
+        Some(WatchUpdate {
+            changed_files,
+            model_reloaded,
+            diff,
+        })
+    }
+}
+
+impl WatchedProject {
+    /// Relit les sources et le modèle modifiés ; renvoie les sources changées et si le modèle a changé
+    fn apply(&mut self, paths: &[PathBuf]) -> (Vec<PathBuf>, bool) {
+        let mut changed_files = Vec::new();
+        let mut model_changed = false;
+        for path in paths {
+            if !path.starts_with(&self.root) || discovery::is_ignored(&self.root, path) {
+                continue;
+            }
+            if is_model_path(path) {
+                model_changed = true;
+            } else if path.is_dir() {
+                // Répertoire créé ou renommé : ses sources n'ont pas forcément été notifiées une à une
+                for file in collect_source_files(path, &self.options.extensions) {
+                    if self.refresh(&file) {
+                        changed_files.push(file);
+                    }
+                }
+            } else if self.is_source(path) {
+                if self.refresh(path) {
+                    changed_files.push(path.clone());
+                }
+            } else if !path.exists() {
+                // Répertoire supprimé ou renommé : ses sources disparaissent
+                let removed: Vec<PathBuf> =
+                    self.sources.keys().filter(|source| source.starts_with(path)).cloned().collect();
+                for source in removed {
+                    self.sources.remove(&source);
+                    changed_files.push(source);
+                }
+            }
+        }
+
+        let model_reloaded = model_changed && {
+            let model = load_model(&self.root, &self.options.registry);
+            let reloaded = model.fingerprint() != self.core_data_model.fingerprint();
+            self.core_data_model = Arc::new(model);
+            reloaded
+        };
+        changed_files.sort();
+        changed_files.dedup();
+        (changed_files, model_reloaded)
+    }
+
+    fn is_source(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| self.options.extensions.iter().any(|wanted| wanted.trim_start_matches('.') == ext))
+    }
+
+    /// Relit une source ; renvoie `true` si elle a été ajoutée, modifiée ou supprimée
+    fn refresh(&mut self, path: &Path) -> bool {
+        match fs::read_to_string(path) {
+            Ok(content) if self.sources.get(path) == Some(&content) => false,
+            Ok(content) => {
+                self.sources.insert(path.to_path_buf(), content);
+                true
+            }
+            Err(_) => self.sources.remove(path).is_some(),
+        }
+    }
+
+    /// Analyse des sources en mémoire ; seules celles modifiées depuis l'analyse précédente sont réanalysées
+    fn analyze(&self) -> PerformanceReport {
+        let analyzer = Analyzer::new(self.config.clone())
+            .with_registry(self.options.registry.clone())
+            .with_core_data_model(self.core_data_model.clone())
+            .with_cache(self.cache.clone());
+        let mut report = self
+            .sources
+            .iter()
+            .fold(analyzer, |analyzer, (path, content)| analyzer.with_source(path.clone(), content.clone()))
+            .run();
+        report.project_root = Some(self.root.clone());
+        report
+    }
+}
+
+/// Modèle CoreData du projet, s'il sert aux règles actives
+fn load_model(root: &Path, registry: &RuleRegistry) -> CoreDataModel {
+    if registry.is_enabled(FetchRequestAnalyzer::RULE.id) {
+        CoreDataModel::load(root)
+    } else {
+        CoreDataModel::default()
+    }
+}
+
+/// Chemin d'un modèle CoreData (`.xcdatamodeld`, `.xcdatamodel` ou l'un de leurs fichiers)
+fn is_model_path(path: &Path) -> bool {
+    path.components().any(|component| {
+        Path::new(component.as_os_str())
+            .extension()
+            .is_some_and(|ext| ext == "xcdatamodel" || ext == "xcdatamodeld")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report_diff::Finding;
+    use crate::test_support::fixtures::{STRONG_DELEGATE, WEAK_DELEGATE};
+
+    #[test]
+    fn test_update_reanalyzes_changed_files_and_reports_diff() {
+        let root = std::env::temp_dir().join(format!("swift-analyzer-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("App")).unwrap();
+        fs::write(root.join("App/Editor.swift"), STRONG_DELEGATE).unwrap();
+        fs::write(root.join("App/List.swift"), "class List {}\n").unwrap();
+
+        let mut session = WatchSession::start(&root, AnalysisConfig::default(), WatchOptions::default());
+        let root = session.root().to_path_buf();
+        let editor = root.join("App/Editor.swift");
+        let grid = root.join("App/Grid.swift");
+        assert_eq!(session.report().project_stats.total_files, 2);
+        assert!(session.update(&[root.join("README.md"), editor.clone()]).is_none());
+
+        fs::write(&editor, WEAK_DELEGATE).unwrap();
+        fs::write(&grid, "class Grid {\n    var delegate: GridDelegate?\n}\n").unwrap();
+        let update = session.update(&[editor.clone(), grid.clone(), root.join("README.md")]).unwrap();
+        assert_eq!(update.changed_files, [editor.clone(), grid.clone()]);
+        assert!(!update.model_reloaded);
+        let findings = |findings: &[Finding]| {
+            findings.iter().map(|f| format!("{} {}", f.file, f.rule_id.as_deref().unwrap_or_default())).collect::<Vec<_>>()
+        };
+        assert_eq!(findings(&update.diff.added), ["App/Grid.swift memory.strong-delegate", "App/Grid.swift deadcode.unused-declaration"]);
+        assert_eq!(findings(&update.diff.removed), ["App/Editor.swift memory.strong-delegate"]);
+        let stats = session.report().cache.unwrap();
+        assert_eq!((stats.hits, stats.misses), (1, 2));
+        assert_eq!(session.report().project_stats.total_files, 3);
+
+        fs::remove_dir_all(root.join("App")).unwrap();
+        let update = session.update(&[root.join("App")]).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(update.changed_files.len(), 3);
+        assert_eq!(update.diff.removed.len(), 4);
+        assert_eq!(session.report().project_stats.total_files, 0);
+    }
+
+    #[test]
+    fn test_model_change_reanalyzes_fetch_requests() {
+        let root = std::env::temp_dir().join(format!("swift-analyzer-watch-model-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let model_dir = root.join("Model.xcdatamodeld/Model.xcdatamodel");
+        fs::create_dir_all(&model_dir).unwrap();
+        let model = |indexed: &str| {
+            format!("<entity name=\"CardEntity\">\n    <attribute name=\"due\" indexed=\"{}\" attributeType=\"Date\"/>\n</entity>\n", indexed)
+        };
+        fs::write(model_dir.join("contents"), model("NO")).unwrap();
+        fs::write(
+            root.join("DueView.swift"),
+            "struct DueView: View {\n    @FetchRequest(sortDescriptors: [SortDescriptor(\\.due)])\n    var cards: FetchedResults<CardEntity>\n}\n",
+        )
+        .unwrap();
+
+        let mut session = WatchSession::start(&root, AnalysisConfig::default(), WatchOptions::default());
+        let contents = session.root().join("Model.xcdatamodeld/Model.xcdatamodel/contents");
+        let missing_index = |report: &PerformanceReport| {
+            report.files_analyzed[0].issues.iter().any(|issue| issue.message.contains("'CardEntity.due' qui n'est pas indexé"))
+        };
+        assert!(missing_index(session.report()));
+
+        // Enregistrement sans modification des entités : rien à réanalyser
+        fs::write(&contents, model("NO")).unwrap();
+        assert!(session.update(std::slice::from_ref(&contents)).is_none());
+
+        fs::write(&contents, model("YES")).unwrap();
+        let update = session.update(&[contents]).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert!(update.model_reloaded);
+        assert!(update.changed_files.is_empty());
+        assert_eq!(update.diff.removed.len(), 1);
+        assert_eq!(update.diff.removed[0].rule_id.as_deref(), Some("coredata.fetch-request"));
+        assert!(!missing_index(session.report()));
+    }
+}
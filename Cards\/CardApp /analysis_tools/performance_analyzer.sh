@@ -74,7 +74,8 @@ run_rust_analysis() {
             (cd analysis_tools/rust_performance_analyzer && \
              cargo run --release -- \
                 --path "../.." \
-                --output "${REPORT_DIR}/rust_analysis.json" \
+                --output json \
+                --report-path "${REPORT_DIR}/rust_analysis.json" \
                 --complexity-threshold 10 \
                 --nesting-threshold 3)
             
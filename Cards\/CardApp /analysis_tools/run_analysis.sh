@@ -123,7 +123,7 @@ fi
 # Analyse de performance avec Rust
 section_header "ANALYSE DE PERFORMANCE (RUST)"
 if [ -d "$ANALYSIS_DIR/rust_performance_analyzer" ] && [ -f "$ANALYSIS_DIR/rust_performance_analyzer/Cargo.toml" ]; then
-    run_command "cd \"$ANALYSIS_DIR/rust_performance_analyzer\" && cargo run --release -- --source \"$PROJECT_ROOT\" --output \"$REPORTS_DIR/performance_analysis.json\"" "Exécution de l'analyseur de performance Rust" "$REPORTS_DIR/performance_analysis_log.txt"
+    run_command "cd \"$ANALYSIS_DIR/rust_performance_analyzer\" && cargo run --release -- --path \"$PROJECT_ROOT\" --output json --report-path \"$REPORTS_DIR/performance_analysis.json\"" "Exécution de l'analyseur de performance Rust" "$REPORTS_DIR/performance_analysis_log.txt"
 else
     echo -e "${YELLOW}⚠️ Analyse de performance Rust ignorée (projet Cargo non disponible)${NC}"
     # Créer un fichier de résultat vide pour qu'il puisse être référencé plus tard
@@ -1,281 +1,1159 @@
-use clap::Parser;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use colored::*;
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
-use rayon::prelude::*;
-use regex::Regex;
-use std::collections::HashMap;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
-use std::time::Instant;
-use walkdir::WalkDir;
-use structopt::StructOpt;
-
-mod analyzer;
-mod models;
-mod reports;
-mod rules;
-
-use analyzer::{analyze_file, AnalysisContext, AnalysisResult};
-use models::{FileIssue, IssueType, PerformanceReport, Severity, AnalysisConfig, ProjectStats, HotspotFile};
-use reports::generate_report;
-use analyzers::{AnalysisContext, analyze_file};
-use reporters::{json, html};
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use swift_analyzer_core::analyzer::Analyzer;
+use swift_analyzer_core::baseline::{Baseline, DEFAULT_BASELINE_FILE};
+use swift_analyzer_core::cache::{AnalysisCache, DEFAULT_CACHE_DIR};
+use swift_analyzer_core::call_graph::CallGraph;
+use swift_analyzer_core::churn::{self, ChurnHistory, ChurnOptions};
+use swift_analyzer_core::core_data_model::CoreDataModel;
+use swift_analyzer_core::discovery::{collect_source_files, relative_path};
+use swift_analyzer_core::fetch_request_analyzer::FetchRequestAnalyzer;
+use swift_analyzer_core::git::{self, ChangeScope, ChangeSet};
+use swift_analyzer_core::history::{self, format_timestamp, HistoryEntry, DEFAULT_HISTORY_FILE};
+use swift_analyzer_core::models::{self, AnalysisConfig, FileIssue, PerformanceReport, Severity};
+use swift_analyzer_core::ownership::{self, CodeOwners};
+use swift_analyzer_core::project_index::ProjectIndex;
+use swift_analyzer_core::quality_gate::QualityGate;
+use swift_analyzer_core::report_diff::{self, Change, Finding, ReportDiff};
+use swift_analyzer_core::reporters::{checkstyle, code_quality, html, interactive, json, junit, legacy, markdown, sarif, trend};
+use swift_analyzer_core::rules::{convention_link, RuleCategory, RuleMetadata, RuleRegistry, RuleSelector};
+use swift_analyzer_core::watch::{WatchOptions, WatchSession, WatchUpdate};
+
+/// Attente après une notification pour regrouper les événements d'une même modification (enregistrement, `git checkout`)
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Problèmes ajoutés ou supprimés listés après chaque réanalyse
+const WATCH_MAX_FINDINGS: usize = 20;
 
 /// Analyseur de performance multi-thread pour code Swift
-#[derive(Debug, StructOpt)]
-#[structopt(name = "swift-analyzer", about = "Analyseur multi-thread de performances Swift")]
+#[derive(Debug, Parser)]
+#[command(name = "swift-analyzer", about = "Analyseur multi-thread de performances Swift", args_conflicts_with_subcommands = true)]
 struct Opt {
+    #[command(flatten)]
+    analyze: AnalyzeArgs,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Options de l'analyse (commande par défaut)
+#[derive(Debug, Args)]
+struct AnalyzeArgs {
     /// Chemin du projet Swift à analyser
-    #[structopt(parse(from_os_str))]
-    path: PathBuf,
+    path: Option<PathBuf>,
+
+    /// Chemin du projet Swift (équivalent nommé, `--project-dir` de l'ancien analyseur)
+    #[arg(long = "path", visible_alias = "project-dir", value_name = "PATH", conflicts_with = "path")]
+    path_option: Option<PathBuf>,
 
     /// Types de fichiers à analyser (par défaut: .swift)
-    #[structopt(short, long, default_value = "swift")]
+    #[arg(short, long, default_value = "swift")]
     extensions: Vec<String>,
 
     /// Nombre maximum de threads à utiliser
-    #[structopt(short, long, default_value = "0")]
+    #[arg(short, long, default_value = "0")]
     threads: usize,
 
-    /// Format de sortie (json, html, console)
-    #[structopt(short, long, default_value = "console")]
-    output: String,
+    /// Format de sortie (console, json, html, html-interactive, sarif, junit, checkstyle, markdown, code-quality), éventuellement suivi de `=chemin` ; répétable
+    #[arg(short, long, value_name = "FORMAT[=CHEMIN]", default_value = "console")]
+    output: Vec<OutputSpec>,
 
-    /// Chemin du fichier de sortie (si json ou html est sélectionné)
-    #[structopt(short, long)]
+    /// Chemin du fichier de sortie des formats donnés sans `=chemin`
+    #[arg(short, long)]
     report_path: Option<PathBuf>,
 
+    /// Cas de test du rapport JUnit : un par fichier (file) ou par règle (rule)
+    #[arg(long, value_name = "GROUPING", default_value = "file")]
+    junit_by: junit::Grouping,
+
+    /// Taille visée du rapport HTML en Kio ; au-delà, les listes du rapport sont tronquées
+    #[arg(long, value_name = "KIO", default_value = "5120")]
+    html_size_budget: usize,
+
+    /// Nombre de fichiers détaillés dans le rapport Markdown (0 : tous)
+    #[arg(long, value_name = "N", default_value = "20")]
+    markdown_max_files: usize,
+
+    /// Nombre de problèmes listés par fichier dans le rapport Markdown (0 : tous)
+    #[arg(long, value_name = "N", default_value = "10")]
+    markdown_max_issues: usize,
+
+    /// Rapports supplémentaires au format des anciens outils (répétable)
+    #[arg(long, value_enum)]
+    legacy_format: Vec<LegacyFormat>,
+
+    /// Ligne de base (`baseline create`) : seuls les problèmes absents de ce fichier sont signalés, code de sortie 1 s'il y en a
+    #[arg(long, value_name = "FICHIER")]
+    baseline: Option<PathBuf>,
+
+    /// Ajouter le résumé de l'analyse (score, compteurs, points chauds, commit git) à cet historique JSONL, lu par `trend`
+    #[arg(long, value_name = "FICHIER")]
+    history: Option<PathBuf>,
+
+    /// N'analyser que les fichiers modifiés depuis cette référence git (index, copie de travail et fichiers non suivis)
+    #[arg(long, value_name = "REF", conflicts_with_all = ["staged", "history"])]
+    changed_since: Option<String>,
+
+    /// N'analyser que les modifications indexées, dans leur version indexée (crochet pre-commit)
+    #[arg(long, conflicts_with = "history")]
+    staged: bool,
+
+    /// Avec `--changed-since` ou `--staged` : signaler aussi les problèmes des lignes non modifiées des fichiers modifiés
+    #[arg(long)]
+    all_lines: bool,
+
+    /// Attribuer chaque problème à l'auteur de sa ligne (`git blame` local) et résumer les problèmes par auteur
+    #[arg(long)]
+    blame: bool,
+
+    /// Attribuer chaque problème aux propriétaires de son fichier selon ce fichier CODEOWNERS
+    /// (sans valeur : .github/CODEOWNERS, CODEOWNERS ou docs/CODEOWNERS du dépôt) et résumer par propriétaire
+    #[arg(long, value_name = "FICHIER", num_args = 0..=1)]
+    codeowners: Option<Option<PathBuf>>,
+
+    /// Classer aussi les fichiers par fréquence de modification × complexité, d'après l'historique git local
+    #[arg(long)]
+    churn: bool,
+
+    /// Avec `--churn` : période récente (en jours avant le dernier commit) des lignes modifiées
+    #[arg(long, value_name = "JOURS", default_value_t = churn::DEFAULT_RECENT_DAYS)]
+    churn_recent_days: u64,
+
+    /// Avec `--churn` : nombre maximal de commits parcourus, des plus récents aux plus anciens
+    #[arg(long, value_name = "N")]
+    churn_max_commits: Option<usize>,
+
+    #[command(flatten)]
+    gate: GateArgs,
+
+    /// Répertoire du cache d'analyse (résultats des fichiers inchangés réutilisés d'une exécution à l'autre)
+    #[arg(long, value_name = "RÉPERTOIRE", default_value = DEFAULT_CACHE_DIR)]
+    cache_dir: PathBuf,
+
+    /// Analyser tous les fichiers sans lire ni mettre à jour le cache
+    #[arg(long)]
+    no_cache: bool,
+
     /// Seuil de complexité cyclomatique pour signaler des problèmes
-    #[structopt(long, default_value = "10")]
+    #[arg(long, default_value = "10")]
     complexity_threshold: u32,
 
     /// Seuil de profondeur d'imbrication pour signaler des problèmes
-    #[structopt(long, default_value = "3")]
+    #[arg(long, default_value = "3")]
     nesting_threshold: u32,
 
     /// Sévérité minimale pour afficher (Critical, High, Medium, Low)
-    #[structopt(long, default_value = "Low")]
-    min_severity: String,
+    #[arg(long, default_value = "Low")]
+    min_severity: Severity,
 
-    /// Désactiver l'analyse de capture de closure
-    #[structopt(long)]
+    #[command(flatten)]
+    rules: RuleSelection,
+
+    /// Ancien équivalent de `--disable memory.closure-self-capture`
+    #[arg(long, hide = true)]
     no_closure_capture_analysis: bool,
 
-    /// Désactiver l'analyse CoreData
-    #[structopt(long)]
+    /// Ancien équivalent de `--disable category:coredata`
+    #[arg(long, hide = true)]
     no_coredata_analysis: bool,
 
-    /// Désactiver l'analyse de concurrence
-    #[structopt(long)]
+    /// Ancien équivalent de `--disable category:concurrency`
+    #[arg(long, hide = true)]
     no_concurrency_analysis: bool,
+
+    /// Ancien équivalent de `--disable category:resources`
+    #[arg(long, hide = true)]
+    no_resource_analysis: bool,
+
+    /// Ancien équivalent de `--disable category:swiftui`
+    #[arg(long, hide = true)]
+    no_swiftui_analysis: bool,
+
+    /// Ancien équivalent de `--disable performance.main-thread-cost`
+    #[arg(long, hide = true)]
+    no_main_thread_analysis: bool,
+
+    /// Coût estimé à partir duquel un point d'entrée du thread principal est signalé
+    #[arg(long, default_value = "20")]
+    main_thread_threshold: u32,
+
+    /// Ancien équivalent de `--disable category:deadcode`
+    #[arg(long, hide = true)]
+    no_dead_code_analysis: bool,
+
+    /// Ancien équivalent de `--disable category:duplication`
+    #[arg(long, hide = true)]
+    no_clone_analysis: bool,
+
+    /// Longueur minimale (en jetons) d'un fragment dupliqué
+    #[arg(long, default_value = "50")]
+    clone_min_tokens: usize,
+}
+
+impl AnalyzeArgs {
+    /// Modifications git à analyser, `None` pour le projet entier
+    fn change_scope(&self) -> Option<ChangeScope> {
+        match (&self.changed_since, self.staged) {
+            (Some(reference), _) => Some(ChangeScope::Since(reference.clone())),
+            (None, true) => Some(ChangeScope::Staged),
+            (None, false) => None,
+        }
+    }
+}
+
+/// Conditions de la porte qualité : code de sortie 1 si l'une n'est pas remplie (2 : erreur de l'outil)
+#[derive(Debug, Args)]
+struct GateArgs {
+    /// Nombre maximal de problèmes Critical
+    #[arg(long, value_name = "N")]
+    gate_max_critical: Option<usize>,
+
+    /// Nombre maximal de problèmes High
+    #[arg(long, value_name = "N")]
+    gate_max_high: Option<usize>,
+
+    /// Score de santé minimal
+    #[arg(long, value_name = "SCORE")]
+    gate_min_health_score: Option<f64>,
+
+    /// Nombre maximal de nouveaux problèmes par rapport à `--baseline` (0 par défaut avec une ligne de base)
+    #[arg(long, value_name = "N", requires = "baseline")]
+    gate_max_new: Option<usize>,
+
+    /// Complexité cyclomatique maximale d'une fonction
+    #[arg(long, value_name = "N")]
+    gate_max_complexity: Option<f64>,
+
+    /// Aucun problème plus grave que cette sévérité dans les fichiers modifiés (Critical, High, Medium, Low)
+    #[arg(long, value_name = "SÉVÉRITÉ")]
+    gate_changed_above: Option<Severity>,
+
+    /// Référence git des fichiers modifiés pour `--gate-changed-above` (sans effet avec `--changed-since` ou `--staged`)
+    #[arg(long, value_name = "REF", default_value = "HEAD")]
+    gate_changed_since: String,
+}
+
+impl GateArgs {
+    /// Porte qualité demandée ; avec une ligne de base, aucun nouveau problème n'est toléré par défaut
+    fn quality_gate(&self, project_path: &Path, has_baseline: bool, changes: Option<&ChangeSet>) -> Result<QualityGate, String> {
+        let mut gate = QualityGate {
+            max_critical: self.gate_max_critical,
+            max_high: self.gate_max_high,
+            min_health_score: self.gate_min_health_score,
+            max_new_issues: self.gate_max_new.or(has_baseline.then_some(0)),
+            max_complexity: self.gate_max_complexity,
+            changed_files_max_severity: self.gate_changed_above,
+            changed_files: Vec::new(),
+        };
+        if let (Some(_), Some(changes)) = (gate.changed_files_max_severity, changes) {
+            gate.changed_files = changes.files.keys().cloned().collect();
+        } else if gate.changed_files_max_severity.is_some() {
+            gate.changed_files = git::changed_files(project_path, &self.gate_changed_since).ok_or_else(|| {
+                format!("Impossible de lister les fichiers modifiés depuis {} (dépôt git requis)", self.gate_changed_since)
+            })?;
+        }
+        Ok(gate)
+    }
+}
+
+/// Activation des règles intégrées
+#[derive(Debug, Args)]
+struct RuleSelection {
+    /// Désactiver des règles : identifiant (`memory.strong-delegate`) ou catégorie (`category:coredata`), répétable
+    #[arg(long, value_name = "RULE")]
+    disable: Vec<RuleSelector>,
+
+    /// Réactiver des règles après les `--disable` (ex. une règle d'une catégorie désactivée), répétable
+    #[arg(long, value_name = "RULE")]
+    enable: Vec<RuleSelector>,
+}
+
+impl RuleSelection {
+    /// Registre des règles intégrées après application des `--disable` puis des `--enable`
+    fn registry(&self, implied_disabled: Vec<RuleSelector>) -> Result<RuleRegistry, String> {
+        let mut registry = RuleRegistry::builtin();
+        for selector in implied_disabled.iter().chain(&self.disable) {
+            registry.disable(selector)?;
+        }
+        for selector in &self.enable {
+            registry.enable(selector)?;
+        }
+        Ok(registry)
+    }
+}
+
+/// Formats de rapport de l'analyse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Console,
+    Json,
+    Html,
+    HtmlInteractive,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Markdown,
+    CodeQuality,
+}
+
+/// Sortie demandée avec `--output` : `format` ou `format=chemin`
+#[derive(Debug, Clone)]
+struct OutputSpec {
+    format: OutputFormat,
+    path: Option<PathBuf>,
+}
+
+impl FromStr for OutputSpec {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (format, path) = match value.split_once('=') {
+            Some((format, path)) => (format, Some(PathBuf::from(path))),
+            None => (value, None),
+        };
+        let format = <OutputFormat as ValueEnum>::from_str(format, true)
+            .map_err(|_| format!("Format de sortie inconnu: {} (console, json, html, html-interactive, sarif, junit, checkstyle, markdown, code-quality)", format))?;
+        Ok(OutputSpec { format, path })
+    }
+}
+
+/// Formats de sortie des anciens outils
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum LegacyFormat {
+    /// `rapports_optimisation/performance_analysis.json` et `swift_auto_fix.sh` (ancien `swift_performance_analyzer`)
+    SwiftAnalyzerJson,
+    /// `performance_analysis_report.md` (ancien `analyze_performance.rs`)
+    PerformanceMarkdown,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Analyser un projet (commande par défaut)
+    Analyze(AnalyzeArgs),
+
+    /// Consulter les règles d'analyse
+    Rules {
+        #[command(subcommand)]
+        command: RulesCommand,
+    },
+
+    /// Expliquer une règle : justification, exemples Swift et conventions CardApp
+    Explain {
+        /// Identifiant de la règle (voir `rules list`)
+        rule_id: String,
+    },
+
+    /// Réanalyser le projet à chaque modification des sources ou du modèle CoreData et afficher les problèmes
+    /// ajoutés et supprimés ; les sorties `--output` autres que console sont régénérées à chaque fois
+    Watch(AnalyzeArgs),
+
+    /// Gérer la ligne de base des problèmes existants
+    Baseline {
+        #[command(subcommand)]
+        command: BaselineCommand,
+    },
+
+    /// Gérer le cache d'analyse
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+
+    /// Comparer deux rapports JSON (`--output json`, ou `performance_analysis.json` de `--legacy-format`) : problèmes ajoutés, supprimés, modifiés et métriques par fichier
+    Diff {
+        /// Rapport de référence
+        old: PathBuf,
+
+        /// Rapport à comparer
+        new: PathBuf,
+
+        /// Format de sortie
+        #[arg(short, long, value_enum, default_value = "console")]
+        format: DiffFormat,
+
+        /// Fichier de sortie (sortie standard par défaut)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Évolution du projet d'après l'historique (`--history`) : score, problèmes, points chauds
+    Trend {
+        /// Historique des analyses
+        #[arg(long, value_name = "FICHIER", default_value = DEFAULT_HISTORY_FILE)]
+        history: PathBuf,
+
+        /// Ne garder que les N dernières analyses
+        #[arg(long, value_name = "N")]
+        last: Option<usize>,
+
+        /// Format de sortie (html : page d'évolution, avec --output)
+        #[arg(short, long, value_enum, default_value = "console")]
+        format: TrendFormat,
+
+        /// Fichier de sortie de la page HTML
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Exporter le graphe d'appels du projet (DOT ou JSON)
+    Callgraph {
+        /// Chemin du projet Swift
+        path: PathBuf,
+
+        /// Format d'export
+        #[arg(short, long, value_enum, default_value = "dot")]
+        format: CallgraphFormat,
+
+        /// Types de fichiers à analyser (par défaut: .swift)
+        #[arg(short, long, default_value = "swift")]
+        extensions: Vec<String>,
+
+        /// Fichier de sortie (sortie standard par défaut)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Ne conserver que le code atteignable depuis cette fonction (`Type.fonction` ou `fonction`)
+        #[arg(long)]
+        from: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum CacheCommand {
+    /// Supprimer les fichiers du cache d'analyse
+    Clean {
+        /// Répertoire du cache
+        #[arg(long, value_name = "RÉPERTOIRE", default_value = DEFAULT_CACHE_DIR)]
+        cache_dir: PathBuf,
+    },
+}
+
+/// Formats de sortie de `trend`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TrendFormat {
+    Console,
+    Html,
+}
+
+/// Formats d'export de `callgraph`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CallgraphFormat {
+    Dot,
+    Json,
+}
+
+/// Formats de sortie de `diff`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum DiffFormat {
+    Console,
+    Markdown,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum BaselineCommand {
+    /// Analyser le projet et enregistrer l'empreinte de chaque problème dans `--baseline` (swift-analyzer-baseline.json par défaut)
+    Create(AnalyzeArgs),
+}
+
+#[derive(Debug, Subcommand)]
+enum RulesCommand {
+    /// Lister les règles : identifiant, catégorie, sévérité et activation
+    List {
+        /// Ne garder que les règles de cette catégorie
+        #[arg(long)]
+        category: Option<RuleCategory>,
+
+        /// Ne garder que les règles de cette sévérité par défaut ou plus grave (Critical, High, Medium, Low)
+        #[arg(long)]
+        min_severity: Option<Severity>,
+
+        /// N'afficher que les règles actives
+        #[arg(long)]
+        enabled_only: bool,
+
+        #[command(flatten)]
+        rules: RuleSelection,
+    },
 }
 
 fn main() {
-    let opt = Opt::from_args();
+    let opt = Opt::parse();
+    
+    match &opt.command {
+        Some(Command::Analyze(args)) => run_analysis(args),
+        Some(command) => run_command(command),
+        None => run_analysis(&opt.analyze),
+    }
+}
+
+/// Analyse complète d'un projet
+fn run_analysis(args: &AnalyzeArgs) {
+    let outputs = match resolve_outputs(&args.output, args.report_path.as_deref()) {
+        Ok(outputs) => outputs,
+        Err(e) => {
+            eprintln!("{}", e.red());
+            std::process::exit(2);
+        }
+    };
+    let baseline = args.baseline.as_deref().map(|path| {
+        Baseline::load(path).unwrap_or_else(|e| {
+            eprintln!("{}", e.red());
+            std::process::exit(2);
+        })
+    });
+    
+    let now = Instant::now();
+    let (project_path, mut report, rules, changes) = analyze(args);
+    
+    // L'historique porte sur le projet entier, avant le filtrage par la ligne de base
+    if let Some(path) = &args.history {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+        let entry = HistoryEntry::from_report(&report, &project_path, timestamp, git::head_commit(&project_path));
+        match history::append(path, &entry) {
+            Ok(()) => println!("Historique mis à jour: {}", path.display()),
+            Err(e) => {
+                eprintln!("{}", e.red());
+                std::process::exit(2);
+            }
+        }
+    }
+    if let Some(baseline) = &baseline {
+        baseline.apply(&mut report, &project_path);
+        if changes.is_some() {
+            // Seuls les fichiers modifiés ont été analysés : les autres problèmes de la ligne de base ne sont pas corrigés
+            let analyzed: HashSet<String> =
+                report.files_analyzed.iter().map(|result| relative_path(&project_path, &result.file_path)).collect();
+            if let Some(summary) = &mut report.baseline {
+                summary.fixed.retain(|entry| analyzed.contains(&entry.file));
+            }
+        }
+    }
+    if let Some(changes) = changes.as_ref().filter(|_| !args.all_lines) {
+        changes.retain_changed_lines(&mut report);
+    }
+    
+    if args.blame || args.codeowners.is_some() {
+        report.ownership = Some(ownership::summarize(&report, args.codeowners.is_some(), args.blame));
+    }
+    
+    let gate = args.gate.quality_gate(&project_path, baseline.is_some(), changes.as_ref()).unwrap_or_else(|e| {
+        eprintln!("{}", e.red());
+        std::process::exit(2);
+    });
+    if !gate.is_empty() {
+        report.quality_gate = Some(gate.evaluate(&report));
+    }
+    
+    // Écrire chaque sortie demandée ; un rapport non écrit est une erreur de l'outil, signalée après les autres sorties
+    let mut write_failed = false;
+    let mut report_error = |e: String| {
+        eprintln!("{}", e.red());
+        write_failed = true;
+    };
+    for (format, path) in &outputs {
+        if let Err(e) = write_output(*format, path.as_deref(), &report, &project_path, &rules, args) {
+            report_error(e);
+        }
+    }
+    
+    // Rapports aux formats des anciens outils
+    for format in &args.legacy_format {
+        match format {
+            LegacyFormat::SwiftAnalyzerJson => {
+                let report_path = Path::new("rapports_optimisation/performance_analysis.json");
+                match legacy::generate_swift_analyzer_report(&report, &project_path.display().to_string(), report_path) {
+                    Ok(()) => println!("Rapport enregistré dans: {}", report_path.display()),
+                    Err(e) => report_error(e),
+                }
+                match legacy::generate_fix_script(&report, Path::new("swift_auto_fix.sh")) {
+                    Ok(()) => println!("Script de correction généré: swift_auto_fix.sh"),
+                    Err(e) => report_error(e),
+                }
+            }
+            LegacyFormat::PerformanceMarkdown => {
+                let report_path = Path::new("performance_analysis_report.md");
+                match legacy::generate_markdown_report(&report, now.elapsed(), report_path) {
+                    Ok(()) => println!("Rapport Markdown généré: {}", report_path.display()),
+                    Err(e) => report_error(e),
+                }
+            }
+        }
+    }
+    
+    if let Some(stats) = report.cache {
+        println!("Cache: {} fichiers réutilisés, {} analysés ; index du projet : {} réutilisés, {} indexés",
+                 stats.hits, stats.misses, stats.index_hits, stats.index_misses);
+    }
+    println!("Analyse complète en {:.2} secondes", now.elapsed().as_secs_f32());
     
+    if write_failed {
+        std::process::exit(2);
+    }
+    if report.quality_gate.as_ref().is_some_and(|gate| !gate.passed) {
+        eprintln!("{}", "❌ Porte qualité échouée".bold().red());
+        std::process::exit(1);
+    }
+}
+
+/// `baseline create` : analyse puis enregistrement des empreintes de tous les problèmes
+fn create_baseline(args: &AnalyzeArgs) {
+    let path = args.baseline.clone().unwrap_or_else(|| PathBuf::from(DEFAULT_BASELINE_FILE));
+    if args.change_scope().is_some() {
+        eprintln!("{}", "La ligne de base porte sur le projet entier : --changed-since et --staged ne s'appliquent pas".red());
+        std::process::exit(2);
+    }
+    let (project_path, report, _, _) = analyze(args);
+    let baseline = Baseline::from_report(&report, &project_path);
+    if let Err(e) = baseline.save(&path) {
+        eprintln!("{}", e.red());
+        std::process::exit(2);
+    }
+    println!("Ligne de base enregistrée: {} ({} problèmes)", path.display(), baseline.findings.len());
+}
+
+/// Configuration, règles et exécution de l'analyse ; renvoie le chemin du projet, le rapport, les règles actives
+/// et, avec `--changed-since` ou `--staged`, les modifications git analysées
+fn analyze(args: &AnalyzeArgs) -> (PathBuf, PerformanceReport, RuleRegistry, Option<ChangeSet>) {
     // Définir le nombre de threads si spécifié
-    if opt.threads > 0 {
+    if args.threads > 0 {
         rayon::ThreadPoolBuilder::new()
-            .num_threads(opt.threads)
+            .num_threads(args.threads)
             .build_global()
             .unwrap();
     }
     
-    let now = Instant::now();
-    println!("{}", "🔍 Analyse de performances Swift multi-thread".bold().green());
-    println!("Chemin du projet: {}", opt.path.display().to_string().cyan());
-    
-    // Créer la configuration d'analyse
-    let min_severity = match opt.min_severity.to_lowercase().as_str() {
-        "critical" => models::Severity::Critical,
-        "high" => models::Severity::High,
-        "medium" => models::Severity::Medium,
-        _ => models::Severity::Low,
+    let Some(project_path) = args.path.clone().or_else(|| args.path_option.clone()) else {
+        Opt::command().print_help().unwrap();
+        println!();
+        std::process::exit(2);
     };
     
-    let config = AnalysisConfig {
-        cyclomatic_complexity_threshold: opt.complexity_threshold,
-        nesting_depth_threshold: opt.nesting_threshold,
-        min_severity,
-        analyze_closure_captures: !opt.no_closure_capture_analysis,
-        analyze_core_data: !opt.no_coredata_analysis,
-        analyze_concurrency: !opt.no_concurrency_analysis,
+    println!("{}", "🔍 Analyse de performances Swift multi-thread".bold().green());
+    println!("Chemin du projet: {}", project_path.display().to_string().cyan());
+    
+    let config = analysis_config(args);
+    
+    let registry = match build_registry(args) {
+        Ok(registry) => registry,
+        Err(e) => {
+            eprintln!("{}", e.red());
+            std::process::exit(2);
+        }
     };
+    let rules = registry.clone();
     
     // Collecter tous les fichiers Swift récursivement
-    let mut swift_files = Vec::new();
-    let extensions: Vec<String> = opt.extensions.iter().map(|e| format!(".{}", e)).collect();
-    
-    for entry in WalkDir::new(&opt.path).into_iter().filter_map(|e| e.ok()) {
-        let path = entry.path();
-        if path.is_file() {
-            if let Some(ext) = path.extension() {
-                if let Some(ext_str) = ext.to_str() {
-                    if extensions.iter().any(|e| e.ends_with(&format!(".{}", ext_str))) {
-                        swift_files.push(path.to_path_buf());
-                    }
-                }
-            }
+    let mut swift_files = collect_source_files(&project_path, &args.extensions);
+    
+    println!("Fichiers trouvés pour l'analyse: {}", swift_files.len());
+    
+    // Fichiers modifiés seulement ; les autres servent de contexte aux analyses du projet
+    let changes = args.change_scope().map(|scope| {
+        ChangeSet::load(&project_path, &scope).unwrap_or_else(|e| {
+            eprintln!("{}", e.red());
+            std::process::exit(2);
+        })
+    });
+    let mut context_files = Vec::new();
+    let mut staged_sources = Vec::new();
+    if let Some(changes) = &changes {
+        let (changed, unchanged) = swift_files.into_iter().partition(|path| changes.get(path).is_some());
+        swift_files = changed;
+        context_files = unchanged;
+        if args.staged {
+            staged_sources = swift_files
+                .drain(..)
+                .filter_map(|path| Some((path.clone(), changes.get(&path)?.staged_content.clone()?)))
+                .collect();
         }
+        println!("Fichiers modifiés analysés: {}", swift_files.len() + staged_sources.len());
     }
     
-    println!("Fichiers trouvés pour l'analyse: {}", swift_files.len());
+    // Charger le modèle CoreData (.xcdatamodel) pour vérifier les index
+    let core_data_model = Arc::new(if registry.is_enabled(FetchRequestAnalyzer::RULE.id) {
+        CoreDataModel::load(&project_path)
+    } else {
+        CoreDataModel::default()
+    });
     
     // Créer une barre de progression
-    let pb = ProgressBar::new(swift_files.len() as u64);
+    let pb = ProgressBar::new((swift_files.len() + staged_sources.len()) as u64);
     pb.set_style(ProgressStyle::default_bar()
         .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
         .unwrap()
         .progress_chars("#>-"));
     
-    // Créer un rapport partagé
-    let report = Arc::new(Mutex::new(PerformanceReport {
-        files_analyzed: Vec::new(),
-        issue_count_by_type: std::collections::HashMap::new(),
-        issue_count_by_severity: std::collections::HashMap::new(),
-        hotspots: Vec::new(),
-        project_stats: ProjectStats {
-            total_files: swift_files.len(),
-            problematic_files: 0,
-            total_lines: 0,
-            health_score: 100.0,
-        },
-    }));
-    
-    // Analyser chaque fichier en parallèle
-    swift_files.par_iter().for_each(|file_path| {
-        let mut context = AnalysisContext::new(config.clone());
-        let result = analyze_file(file_path, &mut context);
-        
-        // Mettre à jour le rapport avec les résultats
-        let mut report = report.lock().unwrap();
-        
-        // Mettre à jour les compteurs de problèmes
-        for issue in &result.issues {
-            *report.issue_count_by_type.entry(issue.issue_type).or_insert(0) += 1;
-            *report.issue_count_by_severity.entry(issue.severity).or_insert(0) += 1;
-        }
-        
-        // Mettre à jour les statistiques du projet
-        report.project_stats.total_lines += result.line_count;
-        if !result.issues.is_empty() {
-            report.project_stats.problematic_files += 1;
-        }
-        
-        // Calculer le score de criticité du fichier
-        let criticality_score = calculate_criticality_score(&result);
-        
-        // Ajouter aux hotspots si nécessaire
-        if criticality_score > 0.0 {
-            report.hotspots.push(HotspotFile {
-                file_path: file_path.clone(),
-                issue_count: result.issues.len(),
-                criticality_score,
-            });
-        }
-        
-        // Ajouter le résultat au rapport
-        report.files_analyzed.push(result);
-        
-        // Avancer la barre de progression
-        pb.inc(1);
-    });
+    // Analyser chaque fichier en parallèle, puis le projet dans son ensemble
+    let progress = pb.clone();
+    let mut analyzer = Analyzer::new(config)
+        .with_registry(registry)
+        .with_core_data_model(core_data_model)
+        .with_files(swift_files)
+        .with_context_files(context_files)
+        .on_file_analyzed(move |_| progress.inc(1));
+    for (path, content) in staged_sources {
+        analyzer = analyzer.with_source(path, content);
+    }
+    if !args.no_cache {
+        analyzer = analyzer.with_cache(AnalysisCache::new(&args.cache_dir));
+    }
+    let mut report = analyzer.run();
     
     pb.finish_with_message("Analyse terminée!");
+    print_warnings(&report.warnings);
+    
+    // Attribution de tous les problèmes, avant le filtrage par la ligne de base ou par lignes modifiées
+    if let Err(e) = attribute_issues(args, &project_path, &mut report) {
+        eprintln!("{}", e.red());
+        std::process::exit(2);
+    }
     
-    // Finaliser et trier les hotspots
-    let mut report = report.lock().unwrap();
-    report.hotspots.sort_by(|a, b| b.criticality_score.partial_cmp(&a.criticality_score).unwrap());
-    report.hotspots.truncate(10); // Garder seulement les 10 fichiers les plus problématiques
+    if args.churn {
+        let options = ChurnOptions {
+            recent_days: args.churn_recent_days,
+            max_commits: args.churn_max_commits,
+        };
+        match ChurnHistory::load(&project_path, options) {
+            Ok(history) => {
+                println!("Historique git: {} commits parcourus", history.commits);
+                report.churn_hotspots = history.hotspots(&report);
+            }
+            Err(e) => {
+                eprintln!("{}", e.red());
+                std::process::exit(2);
+            }
+        }
+    }
+    report.project_root = Some(project_path.clone());
+    (project_path, report, rules, changes)
+}
+
+/// Configuration de l'analyse : seuils et sévérité minimale
+fn analysis_config(args: &AnalyzeArgs) -> AnalysisConfig {
+    AnalysisConfig {
+        cyclomatic_complexity_threshold: args.complexity_threshold,
+        nesting_depth_threshold: args.nesting_threshold,
+        min_severity: args.min_severity,
+        main_thread_cost_threshold: args.main_thread_threshold,
+        clone_min_tokens: args.clone_min_tokens,
+    }
+}
+
+/// `watch` : analyse initiale, puis réanalyse des fichiers modifiés à chaque notification du système de fichiers
+fn watch_project(args: &AnalyzeArgs) {
+    let exit = |message: String| -> ! {
+        eprintln!("{}", message.red());
+        std::process::exit(2);
+    };
+    if args.change_scope().is_some() || args.baseline.is_some() || args.history.is_some() {
+        exit("La surveillance porte sur le projet entier : --changed-since, --staged, --baseline et --history ne s'appliquent pas".to_string());
+    }
+    let outputs = resolve_outputs(&args.output, args.report_path.as_deref()).unwrap_or_else(|e| exit(e));
+    let registry = build_registry(args).unwrap_or_else(|e| exit(e));
+    let Some(project_path) = args.path.clone().or_else(|| args.path_option.clone()) else {
+        Opt::command().print_help().unwrap();
+        println!();
+        std::process::exit(2);
+    };
+    if args.threads > 0 {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(args.threads)
+            .build_global()
+            .unwrap();
+    }
+    
+    println!("{}", "👀 Analyse continue de performances Swift".bold().green());
+    println!("Chemin du projet: {}", project_path.display().to_string().cyan());
+    let now = Instant::now();
+    let options = WatchOptions {
+        extensions: args.extensions.clone(),
+        registry: registry.clone(),
+    };
+    let mut session = WatchSession::start(&project_path, analysis_config(args), options);
+    println!("Analyse initiale en {:.2} secondes", now.elapsed().as_secs_f32());
+    print_warnings(&session.report().warnings);
+    for (format, path) in &outputs {
+        write_output(*format, path.as_deref(), session.report(), session.root(), &registry, args).unwrap_or_else(|e| exit(e));
+    }
     
-    // Calculer le score de santé du projet
-    calculate_health_score(&mut report);
+    let (sender, receiver) = mpsc::channel();
+    // La surveillance dure tant que `_watcher` existe, c'est-à-dire jusqu'à l'arrêt du programme
+    let _watcher = notify::recommended_watcher(sender)
+        .and_then(|mut watcher| watcher.watch(session.root(), RecursiveMode::Recursive).map(|_| watcher))
+        .unwrap_or_else(|e| exit(format!("Impossible de surveiller {}: {}", project_path.display(), e)));
+    println!("{}", "Surveillance des modifications (Ctrl+C pour arrêter)…".dimmed());
     
-    // Afficher le rapport selon le format demandé
-    match opt.output.as_str() {
-        "json" => {
-            if let Some(path) = &opt.report_path {
-                json::generate_report(&report, path);
+    // Chemins modifiés d'un événement ; les simples lectures sont ignorées
+    let modified_paths = |event: notify::Result<notify::Event>| match event {
+        Ok(event) if !matches!(event.kind, EventKind::Access(_)) => event.paths,
+        Ok(_) => Vec::new(),
+        Err(e) => {
+            eprintln!("{}", format!("Erreur de surveillance: {}", e).red());
+            Vec::new()
+        }
+    };
+    while let Ok(event) = receiver.recv() {
+        let mut paths = modified_paths(event);
+        while let Ok(event) = receiver.recv_timeout(WATCH_DEBOUNCE) {
+            paths.extend(modified_paths(event));
+        }
+        paths.sort();
+        paths.dedup();
+        
+        let now = Instant::now();
+        let Some(update) = session.update(&paths) else {
+            continue;
+        };
+        print_watch_update(&update, session.report(), now.elapsed());
+        print_warnings(&session.report().warnings);
+        // Un rapport non écrit n'arrête pas la surveillance : il sera réécrit à la modification suivante
+        for (format, path) in outputs.iter().filter(|(format, _)| *format != OutputFormat::Console) {
+            if let Err(e) = write_output(*format, path.as_deref(), session.report(), session.root(), &registry, args) {
+                eprintln!("{}", e.red());
+            }
+        }
+    }
+}
+
+/// Auteurs (`--blame`) et propriétaires (`--codeowners`) des problèmes
+fn attribute_issues(args: &AnalyzeArgs, project_path: &Path, report: &mut PerformanceReport) -> Result<(), String> {
+    if let Some(path) = &args.codeowners {
+        let path = match path {
+            Some(path) => path.clone(),
+            None => CodeOwners::discover(project_path)
+                .ok_or_else(|| format!("Aucun fichier CODEOWNERS trouvé ({})", ownership::CODEOWNERS_LOCATIONS.join(", ")))?,
+        };
+        println!("Propriétaires: {}", path.display());
+        ownership::assign_owners(report, &CodeOwners::load(&path)?);
+    }
+    if args.blame {
+        ownership::blame_authors(report, project_path)?;
+    }
+    Ok(())
+}
+
+/// Chemin de chaque sortie (`=chemin`, sinon `--report-path`) ; deux sorties ne peuvent pas partager un fichier,
+/// et les rapports HTML ne s'écrivent pas sur la sortie standard
+fn resolve_outputs(outputs: &[OutputSpec], report_path: Option<&Path>) -> Result<Vec<(OutputFormat, Option<PathBuf>)>, String> {
+    let resolved: Vec<(OutputFormat, Option<PathBuf>)> = outputs
+        .iter()
+        .map(|output| match output.format {
+            OutputFormat::Console => (output.format, None),
+            _ => (output.format, output.path.clone().or_else(|| report_path.map(Path::to_path_buf))),
+        })
+        .collect();
+
+    for (i, (format, path)) in resolved.iter().enumerate() {
+        if path.is_none() && matches!(format, OutputFormat::Html | OutputFormat::HtmlInteractive) {
+            return Err(missing_html_path());
+        }
+        if let Some(path) = path {
+            if resolved[..i].iter().any(|(_, other)| other.as_ref() == Some(path)) {
+                return Err(format!(
+                    "Plusieurs sorties écriraient dans {} : précisez un chemin par format (--output format=chemin)",
+                    path.display()
+                ));
+            }
+        }
+    }
+    Ok(resolved)
+}
+
+fn missing_html_path() -> String {
+    "Chemin de rapport HTML non spécifié (--output html=chemin ou --report-path)".to_string()
+}
+
+/// Écrit le rapport dans un format ; sans chemin, les formats texte vont sur la sortie standard
+///
+/// Erreur si le fichier du rapport ne peut pas être écrit, ou pour un rapport HTML sans chemin.
+fn write_output(
+    format: OutputFormat,
+    path: Option<&Path>,
+    report: &PerformanceReport,
+    project_path: &Path,
+    registry: &RuleRegistry,
+    args: &AnalyzeArgs,
+) -> Result<(), String> {
+    match format {
+        OutputFormat::Console => print_console_report(report),
+        OutputFormat::Json => match path {
+            Some(path) => {
+                json::generate_report(report, path)?;
                 println!("Rapport JSON généré: {}", path.display());
-            } else {
-                println!("{}", serde_json::to_string_pretty(&report).unwrap());
             }
+            None => println!("{}", serde_json::to_string_pretty(report).unwrap()),
         },
-        "html" => {
-            if let Some(path) = &opt.report_path {
-                html::generate_report(&report, path);
+        OutputFormat::Html => match path {
+            Some(path) => {
+                let options = html::HtmlOptions {
+                    size_budget: args.html_size_budget * 1024,
+                };
+                html::generate_report(report, registry, options, path)?;
                 println!("Rapport HTML généré: {}", path.display());
-            } else {
-                println!("Chemin de rapport HTML non spécifié");
             }
+            None => return Err(missing_html_path()),
+        },
+        OutputFormat::HtmlInteractive => match path {
+            Some(path) => {
+                interactive::generate_report(report, project_path, path)?;
+                println!("Rapport HTML interactif généré: {}", path.display());
+            }
+            None => return Err(missing_html_path()),
+        },
+        OutputFormat::Sarif => match path {
+            Some(path) => {
+                sarif::generate_report(report, project_path, registry, path)?;
+                println!("Rapport SARIF généré: {}", path.display());
+            }
+            None => println!("{}", serde_json::to_string_pretty(&sarif::sarif_log(report, project_path, registry)).unwrap()),
+        },
+        OutputFormat::Junit => match path {
+            Some(path) => {
+                junit::generate_report(report, args.junit_by, registry, path)?;
+                println!("Rapport JUnit généré: {}", path.display());
+            }
+            None => println!("{}", junit::to_xml(report, args.junit_by, registry)),
         },
-        _ => {
-            print_console_report(&report);
+        OutputFormat::Checkstyle => match path {
+            Some(path) => {
+                checkstyle::generate_report(report, path)?;
+                println!("Rapport Checkstyle généré: {}", path.display());
+            }
+            None => println!("{}", checkstyle::to_xml(report)),
+        },
+        OutputFormat::Markdown => {
+            let options = markdown::MarkdownOptions {
+                max_files: (args.markdown_max_files > 0).then_some(args.markdown_max_files),
+                max_issues_per_file: (args.markdown_max_issues > 0).then_some(args.markdown_max_issues),
+            };
+            match path {
+                Some(path) => {
+                    markdown::generate_report(report, project_path, options, path)?;
+                    println!("Rapport Markdown généré: {}", path.display());
+                }
+                None => println!("{}", markdown::to_markdown(report, project_path, options)),
+            }
         }
+        OutputFormat::CodeQuality => match path {
+            Some(path) => {
+                code_quality::generate_report(report, project_path, registry, path)?;
+                println!("Rapport Code Quality généré: {}", path.display());
+            }
+            None => println!("{}", serde_json::to_string_pretty(&code_quality::code_quality_issues(report, project_path, registry)).unwrap()),
+        },
     }
-    
-    println!("Analyse complète en {:.2} secondes", now.elapsed().as_secs_f32());
+    Ok(())
 }
 
-/// Calcule le score de criticité d'un fichier basé sur ses problèmes
-fn calculate_criticality_score(result: &models::AnalysisResult) -> f64 {
-    let mut score = 0.0;
-    
-    for issue in &result.issues {
-        score += match issue.severity {
-            models::Severity::Critical => 10.0,
-            models::Severity::High => 5.0,
-            models::Severity::Medium => 2.0,
-            models::Severity::Low => 0.5,
-        };
-    }
-    
-    // Ajouter un facteur basé sur la complexité
-    score += result.metrics.max_cyclomatic_complexity * 0.5;
-    score += result.metrics.max_nesting_depth as f64 * 0.3;
-    
-    // Normaliser par rapport à la taille du fichier
-    if result.line_count > 0 {
-        score = score * (1.0 + (result.line_count as f64 / 500.0).min(1.0));
+/// Registre des règles de l'analyse, anciennes options `--no-*-analysis` comprises
+fn build_registry(args: &AnalyzeArgs) -> Result<RuleRegistry, String> {
+    let legacy_flags = [
+        (args.no_closure_capture_analysis, "memory.closure-self-capture"),
+        (args.no_coredata_analysis, "category:coredata"),
+        (args.no_concurrency_analysis, "category:concurrency"),
+        (args.no_resource_analysis, "category:resources"),
+        (args.no_swiftui_analysis, "category:swiftui"),
+        (args.no_main_thread_analysis, "performance.main-thread-cost"),
+        (args.no_dead_code_analysis, "category:deadcode"),
+        (args.no_clone_analysis, "category:duplication"),
+    ];
+    let disabled = legacy_flags
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, selector)| selector.parse())
+        .collect::<Result<_, _>>()?;
+    args.rules.registry(disabled)
+}
+
+fn severity_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "red",
+        Severity::High => "yellow",
+        Severity::Medium => "cyan",
+        Severity::Low => "green",
     }
-    
-    score
 }
 
-/// Calcule le score de santé global du projet
-fn calculate_health_score(report: &mut PerformanceReport) {
-    let total_files = report.project_stats.total_files;
-    if total_files == 0 {
-        report.project_stats.health_score = 100.0;
-        return;
+/// Exécute une sous-commande
+fn run_command(command: &Command) {
+    match command {
+        Command::Analyze(args) => run_analysis(args),
+        Command::Watch(args) => watch_project(args),
+        Command::Baseline { command: BaselineCommand::Create(args) } => create_baseline(args),
+        Command::Cache { command: CacheCommand::Clean { cache_dir } } => match AnalysisCache::new(cache_dir).clean() {
+            Ok(removed) => println!("Cache {} vidé ({} fichiers supprimés)", cache_dir.display(), removed),
+            Err(e) => {
+                eprintln!("{}", e.red());
+                std::process::exit(2);
+            }
+        },
+        Command::Rules { command: RulesCommand::List { category, min_severity, enabled_only, rules } } => {
+            let registry = rules.registry(Vec::new()).unwrap_or_else(|e| {
+                eprintln!("{}", e.red());
+                std::process::exit(2);
+            });
+            let min_severity = min_severity.unwrap_or(Severity::Low);
+            
+            println!("{:<34} {:<12} {:<9} {:<7} {}", "RÈGLE".bold(), "CATÉGORIE".bold(), "SÉVÉRITÉ".bold(), "ACTIVE".bold(), "DESCRIPTION".bold());
+            for metadata in registry.metadata() {
+                let enabled = registry.is_enabled(metadata.id);
+                if category.is_some_and(|c| c != metadata.category)
+                    || metadata.default_severity > min_severity
+                    || (*enabled_only && !enabled)
+                {
+                    continue;
+                }
+                println!("{:<34} {:<12} {:<9} {:<7} {}",
+                         metadata.id,
+                         metadata.category.as_str(),
+                         format!("{:?}", metadata.default_severity).color(severity_color(metadata.default_severity)),
+                         if enabled { "oui".green() } else { "non".red() },
+                         metadata.description);
+            }
+        }
+        Command::Explain { rule_id } => {
+            let registry = RuleRegistry::builtin();
+            let Some(metadata) = registry.get(rule_id) else {
+                eprintln!("{} règle inconnue: {} (voir `rules list`)", "Erreur:".red(), rule_id);
+                std::process::exit(2);
+            };
+            print_rule_explanation(metadata);
+        }
+        Command::Diff { old, new, format, output } => {
+            let load = |path: &Path| {
+                report_diff::load_report(path).unwrap_or_else(|e| {
+                    eprintln!("{}", e.red());
+                    std::process::exit(2);
+                })
+            };
+            let diff = ReportDiff::between(&load(old), &load(new));
+            
+            let content = match format {
+                DiffFormat::Console => {
+                    print_console_diff(&diff);
+                    return;
+                }
+                DiffFormat::Markdown => report_diff::to_markdown(&diff),
+                DiffFormat::Json => serde_json::to_string_pretty(&diff).unwrap(),
+            };
+            
+            match output {
+                Some(output) => {
+                    fs::write(output, content).unwrap_or_else(|e| {
+                        eprintln!("{} {}: {}", "Erreur:".red(), output.display(), e);
+                        std::process::exit(2);
+                    });
+                    println!("Comparaison générée: {}", output.display());
+                }
+                None => print!("{}", content),
+            }
+        }
+        Command::Trend { history: path, last, format, output } => {
+            let mut entries = history::load(path).unwrap_or_else(|e| {
+                eprintln!("{}", e.red());
+                std::process::exit(2);
+            });
+            if let Some(last) = last {
+                entries.drain(..entries.len().saturating_sub(*last));
+            }
+            
+            match (format, output) {
+                (TrendFormat::Console, _) => print_console_trend(&entries),
+                (TrendFormat::Html, Some(output)) => {
+                    trend::generate_report(&entries, output).unwrap_or_else(|e| {
+                        eprintln!("{}", e.red());
+                        std::process::exit(2);
+                    });
+                    println!("Page d'évolution générée: {}", output.display());
+                }
+                (TrendFormat::Html, None) => {
+                    eprintln!("{}", "Chemin de la page d'évolution non spécifié (--output)".red());
+                    std::process::exit(2);
+                }
+            }
+        }
+        Command::Callgraph { path, format, extensions, output, from } => {
+            let files = collect_source_files(path, extensions);
+            let index = ProjectIndex::build(&files);
+            print_warnings(index.read_errors());
+            let mut graph = CallGraph::build(&index);
+            
+            if let Some(name) = from {
+                let (owner, function) = match name.rsplit_once('.') {
+                    Some((owner, function)) => (Some(owner), function),
+                    None => (None, name.as_str()),
+                };
+                let Some(&start) = index
+                    .functions_named(function)
+                    .iter()
+                    .find(|&&id| owner.is_none() || index.function(id).owner.as_deref() == owner)
+                else {
+                    eprintln!("{} fonction introuvable: {}", "Erreur:".red(), name);
+                    std::process::exit(2);
+                };
+                graph = graph.subgraph_from(start);
+            }
+            
+            let content = match format {
+                CallgraphFormat::Dot => graph.to_dot(&index),
+                CallgraphFormat::Json => graph.to_json(&index).unwrap_or_else(|e| {
+                    eprintln!("{} sérialisation du graphe d'appels: {}", "Erreur:".red(), e);
+                    std::process::exit(2);
+                }),
+            };
+            
+            match output {
+                Some(output) => {
+                    fs::write(output, content).unwrap_or_else(|e| {
+                        eprintln!("{} {}: {}", "Erreur:".red(), output.display(), e);
+                        std::process::exit(2);
+                    });
+                    eprintln!("Graphe d'appels généré ({} appels): {}", graph.edges().len(), output.display());
+                }
+                None => print!("{}", content),
+            }
+        }
     }
+}
+
+/// Affiche l'explication détaillée d'une règle
+fn print_rule_explanation(metadata: &RuleMetadata) {
+    let languages: Vec<&str> = metadata.languages.iter().map(|l| l.as_str()).collect();
+    println!("{}", metadata.id.bold());
+    println!("Catégorie: {}  |  Sévérité: {}  |  Analyse: {}  |  Langages: {}",
+             metadata.category,
+             format!("{:?}", metadata.default_severity).color(severity_color(metadata.default_severity)),
+             metadata.level.as_str(),
+             languages.join(", "));
+    println!("\n{}", metadata.description);
     
-    let problematic_files = report.project_stats.problematic_files;
-    let problem_ratio = problematic_files as f64 / total_files as f64;
-    
-    // Calculer le score en fonction du nombre de problèmes de chaque sévérité
-    let mut severity_score = 0.0;
-    let critical_issues = *report.issue_count_by_severity.get(&models::Severity::Critical).unwrap_or(&0) as f64;
-    let high_issues = *report.issue_count_by_severity.get(&models::Severity::High).unwrap_or(&0) as f64;
-    let medium_issues = *report.issue_count_by_severity.get(&models::Severity::Medium).unwrap_or(&0) as f64;
-    let low_issues = *report.issue_count_by_severity.get(&models::Severity::Low).unwrap_or(&0) as f64;
-    
-    // Les problèmes critiques ont plus d'impact
-    severity_score += critical_issues * 5.0;
-    severity_score += high_issues * 2.0;
-    severity_score += medium_issues * 0.5;
-    severity_score += low_issues * 0.1;
-    
-    // Normaliser par le nombre total de lignes
-    if report.project_stats.total_lines > 0 {
-        severity_score = severity_score * 1000.0 / report.project_stats.total_lines as f64;
+    let Some(explanation) = &metadata.explanation else {
+        return;
+    };
+    println!("\n{}", "POURQUOI".bold().yellow());
+    println!("{}", explanation.rationale);
+    println!("\n{}", "❌ À ÉVITER".bold().red());
+    println!("{}", explanation.bad_example);
+    println!("\n{}", "✅ À PRÉFÉRER".bold().green());
+    println!("{}", explanation.good_example);
+    if !explanation.conventions.is_empty() {
+        println!("\n{}", "CONVENTIONS CARDAPP".bold().cyan());
+        for section in explanation.conventions {
+            println!("{} {} ({})", "•".cyan(), section, convention_link(section));
+        }
     }
-    
-    // Combiner les scores
-    let health_score = 100.0 - (problem_ratio * 50.0 + severity_score.min(50.0));
-    
-    report.project_stats.health_score = health_score.max(0.0).min(100.0);
 }
 
 /// Affiche un rapport dans la console
@@ -288,17 +1166,28 @@ fn print_console_report(report: &PerformanceReport) {
              report.project_stats.problematic_files,
              (report.project_stats.problematic_files as f64 / report.project_stats.total_files as f64) * 100.0);
     println!("Lignes de code analysées: {}", report.project_stats.total_lines);
+    println!("Code dupliqué: {:.1}%", report.project_stats.duplication_percentage);
+    if let Some(baseline) = &report.baseline {
+        println!("Ligne de base: {} nouveaux, {} inchangés, {} corrigés",
+                 baseline.new.to_string().bold(), baseline.unchanged, baseline.fixed.len());
+    }
+    
+    if let Some(gate) = &report.quality_gate {
+        let status = if gate.passed { "RÉUSSIE".bold().green() } else { "ÉCHOUÉE".bold().red() };
+        println!("\n{} {}", "PORTE QUALITÉ".bold(), status);
+        for condition in &gate.conditions {
+            let mark = if condition.passed { "✔".green() } else { "✘".red() };
+            println!("{} {}: {} (seuil {})", mark, condition.description, condition.actual, condition.threshold);
+            for detail in &condition.details {
+                println!("    {}", detail);
+            }
+        }
+    }
     
     // Résumé des problèmes par sévérité
     println!("\n{}", "PROBLÈMES PAR SÉVÉRITÉ".bold());
     for (severity, count) in &report.issue_count_by_severity {
-        let color = match severity {
-            models::Severity::Critical => "red",
-            models::Severity::High => "yellow",
-            models::Severity::Medium => "cyan",
-            models::Severity::Low => "green",
-        };
-        println!("{}: {}", format!("{:?}", severity).color(color), count);
+        println!("{}: {}", format!("{:?}", severity).color(severity_color(*severity)), count);
     }
     
     // Résumé des problèmes par type
@@ -307,6 +1196,22 @@ fn print_console_report(report: &PerformanceReport) {
         println!("{:?}: {}", issue_type, count);
     }
     
+    // Répartition par propriétaire (--codeowners) et par auteur (--blame)
+    if let Some(ownership) = &report.ownership {
+        for (title, summaries) in [("PROBLÈMES PAR PROPRIÉTAIRE", &ownership.owners), ("PROBLÈMES PAR AUTEUR", &ownership.authors)] {
+            if summaries.is_empty() {
+                continue;
+            }
+            println!("\n{}", title.bold());
+            for summary in summaries.iter().take(10) {
+                let severities: Vec<String> =
+                    summary.issues_by_severity.iter().map(|(severity, count)| format!("{:?}: {}", severity, count)).collect();
+                println!("{} - {} problèmes dans {} fichiers ({})",
+                         summary.name.cyan(), summary.issues, summary.files, severities.join(", "));
+            }
+        }
+    }
+    
     // Points chauds (fichiers les plus problématiques)
     println!("\n{}", "POINTS CHAUDS (TOP 10)".bold().red());
     println!("---------------------------------------------------");
@@ -319,27 +1224,99 @@ fn print_console_report(report: &PerformanceReport) {
                  hotspot.criticality_score);
     }
     
+    // Points chauds selon l'historique git (--churn)
+    if !report.churn_hotspots.is_empty() {
+        println!("\n{}", "POINTS CHAUDS : MODIFICATIONS × COMPLEXITÉ (TOP 10)".bold().red());
+        println!("---------------------------------------------------");
+        for (i, hotspot) in report.churn_hotspots.iter().take(10).enumerate() {
+            println!("{}. {} - {} commits, {} auteurs, {} lignes modifiées récemment, complexité {:.0} (score: {:.0})",
+                     i + 1,
+                     hotspot.file_path.display().to_string().yellow(),
+                     hotspot.commits,
+                     hotspot.authors,
+                     hotspot.recent_churn,
+                     hotspot.complexity,
+                     hotspot.score);
+        }
+    }
+    
+    // Points d'entrée les plus coûteux sur le thread principal
+    if !report.main_thread_estimates.is_empty() {
+        println!("\n{}", "TRAVAIL SUR LE THREAD PRINCIPAL (TOP 10)".bold().magenta());
+        println!("---------------------------------------------------");
+        for (i, estimate) in report.main_thread_estimates.iter().take(10).enumerate() {
+            println!("{}. {} [{:?}] - coût estimé {} ({}:{})",
+                     i+1,
+                     estimate.entry_point.yellow(),
+                     estimate.kind,
+                     estimate.estimated_cost,
+                     estimate.file_path.display(),
+                     estimate.line);
+            for operation in estimate.operations.iter().take(3) {
+                let via = if operation.call_chain.is_empty() {
+                    String::new()
+                } else {
+                    format!(" via {}", operation.call_chain.join(" → "))
+                };
+                println!("   {} {} (+{}){}", "•".magenta(), operation.operation, operation.cost, via);
+            }
+        }
+    }
+    
+    // Code dupliqué
+    if !report.clones.is_empty() {
+        println!("\n{}", "CODE DUPLIQUÉ".bold().magenta());
+        println!("---------------------------------------------------");
+        println!("Duplication: {:.1}% des lignes, {} groupes de clones",
+                 report.project_stats.duplication_percentage,
+                 report.clones.len());
+        for (i, group) in report.clones.iter().take(10).enumerate() {
+            println!("{}. {:?} - {} jetons, {} occurrences", i+1, group.clone_type, group.token_count, group.locations.len());
+            for location in &group.locations {
+                println!("   {} {}:{}-{}", "•".magenta(), location.file_path.display(), location.line_start, location.line_end);
+            }
+        }
+    }
+    
+    // Déclarations inutilisées
+    if !report.dead_code.is_empty() {
+        println!("\n{}", "CODE MORT".bold().magenta());
+        println!("---------------------------------------------------");
+        for confidence in [models::Confidence::High, models::Confidence::Medium, models::Confidence::Low] {
+            let count = report.dead_code.iter().filter(|f| f.confidence == confidence).count();
+            println!("Confiance {:?}: {}", confidence, count);
+        }
+        for finding in report.dead_code.iter().filter(|f| f.confidence != models::Confidence::Low).take(20) {
+            println!("   {} {:?} {} ({}:{})",
+                     "•".magenta(),
+                     finding.kind,
+                     finding.name.yellow(),
+                     finding.file_path.display(),
+                     finding.line);
+        }
+    }
+    
     // Afficher les 20 problèmes les plus critiques
     println!("\n{}", "PROBLÈMES CRITIQUES".bold().red());
     println!("---------------------------------------------------");
     
-    let mut all_issues: Vec<(&Path, &models::FileIssue)> = Vec::new();
+    let mut all_issues: Vec<(&Path, &FileIssue)> = Vec::new();
     for result in &report.files_analyzed {
         for issue in &result.issues {
-            if issue.severity == models::Severity::Critical || issue.severity == models::Severity::High {
+            if issue.severity == Severity::Critical || issue.severity == Severity::High {
                 all_issues.push((&result.file_path, issue));
             }
         }
     }
     
     // Trier par sévérité
-    all_issues.sort_by(|a, b| b.1.severity.cmp(&a.1.severity));
+    all_issues.sort_by_key(|(_, issue)| issue.severity);
     all_issues.truncate(20);
     
     for (file_path, issue) in all_issues {
         let severity_str = match issue.severity {
-            models::Severity::Critical => "CRITIQUE".red().bold(),
-            models::Severity::High => "ELEVÉ".yellow().bold(),
+            Severity::Critical => "CRITIQUE".red().bold(),
+            Severity::High => "ELEVÉ".yellow().bold(),
             _ => "".normal(),
         };
         
@@ -355,6 +1332,197 @@ fn print_console_report(report: &PerformanceReport) {
         if let Some(snippet) = &issue.code_snippet {
             println!("   {}", snippet.trim());
         }
+        if let Some(ownership) = &issue.ownership {
+            let attribution: Vec<&str> = ownership.author.as_deref().into_iter().chain(ownership.owners.iter().map(String::as_str)).collect();
+            if !attribution.is_empty() {
+                println!("   {} {}", "👤".dimmed(), attribution.join(", ").dimmed());
+            }
+        }
         println!();
     }
-} 
\ No newline at end of file
+} 
+
+/// Affiche la comparaison de deux rapports sur la console
+fn print_console_diff(diff: &ReportDiff) {
+    println!("\n{}", "📈 COMPARAISON DES ANALYSES".bold().yellow());
+    println!("---------------------------------------------------");
+    let score = format!("{:+.1}", diff.health_score.delta());
+    let score = if diff.health_score.delta() < 0.0 { score.red() } else { score.green() };
+    println!("Score de santé: {:.1}% → {:.1}% ({})", diff.health_score.old, diff.health_score.new, score);
+    print_change("Fichiers analysés", &diff.total_files);
+    print_change("Lignes de code", &diff.total_lines);
+    print_change("Problèmes", &diff.issues);
+    for (severity, change) in &diff.issues_by_severity {
+        print_change(&format!("{:?}", severity).color(severity_color(*severity)).to_string(), change);
+    }
+    println!("Problèmes: {} ajoutés, {} supprimés, {} modifiés, {} inchangés",
+             diff.added.len().to_string().red(), diff.removed.len().to_string().green(), diff.changed.len(), diff.unchanged);
+    
+    for (title, findings, sign) in [("PROBLÈMES AJOUTÉS", &diff.added, "+".red()), ("PROBLÈMES SUPPRIMÉS", &diff.removed, "-".green())] {
+        if findings.is_empty() {
+            continue;
+        }
+        println!("\n{}", title.bold());
+        for finding in findings {
+            println!("{} {}", sign, format_finding(finding));
+        }
+    }
+    
+    if !diff.changed.is_empty() {
+        println!("\n{}", "PROBLÈMES MODIFIÉS".bold());
+        for change in &diff.changed {
+            println!("~ {} (ligne {} → {}, {:?} → {:?})", format_finding(&change.new), change.old.line, change.new.line, change.old.severity, change.new.severity);
+        }
+    }
+    
+    if !diff.files.is_empty() {
+        println!("\n{}", "FICHIERS (lignes, complexité max, problèmes)".bold());
+        for delta in &diff.files {
+            let (old, new) = (delta.old.as_ref(), delta.new.as_ref());
+            println!("{}: {} lignes, complexité {}, {} problèmes",
+                     delta.file.yellow(),
+                     report_diff::evolution(old.map(|s| s.lines as f64), new.map(|s| s.lines as f64)),
+                     report_diff::evolution(old.map(|s| s.max_cyclomatic_complexity), new.map(|s| s.max_cyclomatic_complexity)),
+                     report_diff::evolution(old.map(|s| s.issues as f64), new.map(|s| s.issues as f64)));
+        }
+    }
+}
+
+/// Affiche une réanalyse de `watch` : fichiers relus, problèmes ajoutés et supprimés, score et compteurs
+/// Erreurs sans effet sur les résultats (fichiers illisibles, cache non enregistré)
+fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("{} {}", "Avertissement:".yellow(), warning);
+    }
+}
+
+fn print_watch_update(update: &WatchUpdate, report: &PerformanceReport, elapsed: Duration) {
+    let root = report.project_root.clone().unwrap_or_default();
+    let mut changes: Vec<String> = update.changed_files.iter().map(|path| relative_path(&root, path)).collect();
+    if update.model_reloaded {
+        changes.push("modèle CoreData".to_string());
+    }
+    let reanalyzed = report.cache.map_or(update.changed_files.len(), |stats| stats.misses);
+    println!("\n{} {} ({} fichiers réanalysés en {:.2} s)",
+             "↻".bold().cyan(),
+             changes.join(", ").bold(),
+             reanalyzed,
+             elapsed.as_secs_f32());
+    
+    let diff = &update.diff;
+    for (findings, sign) in [(&diff.added, "+".red()), (&diff.removed, "-".green())] {
+        for finding in findings.iter().take(WATCH_MAX_FINDINGS) {
+            println!("{} {} {}", sign, format!("{:?}", finding.severity).color(severity_color(finding.severity)), format_finding(finding));
+        }
+        if findings.len() > WATCH_MAX_FINDINGS {
+            println!("{} … et {} autres", sign, findings.len() - WATCH_MAX_FINDINGS);
+        }
+    }
+    for change in diff.changed.iter().filter(|change| change.old.severity != change.new.severity) {
+        println!("~ {:?} → {:?} {}", change.old.severity, change.new.severity, format_finding(&change.new));
+    }
+    
+    let score = format!("{:+.1}", diff.health_score.delta());
+    let score = if diff.health_score.delta() < 0.0 { score.red() } else { score.green() };
+    let counts: Vec<String> = diff
+        .issues_by_severity
+        .iter()
+        .map(|(severity, change)| {
+            let delta = if change.delta() == 0 { String::new() } else { format!(" ({:+})", change.delta()) };
+            format!("{} {}{}", format!("{:?}", severity).color(severity_color(*severity)), change.new, delta)
+        })
+        .collect();
+    println!("Score de santé: {:.1}% ({}) · {} ajoutés, {} supprimés · {}",
+             diff.health_score.new, score, diff.added.len(), diff.removed.len(), counts.join(" · "));
+    if let Some(hotspot) = report.hotspots.first() {
+        println!("Point chaud principal: {} (score {:.1})", relative_path(&root, &hotspot.file_path), hotspot.criticality_score);
+    }
+}
+
+fn print_change(label: &str, change: &Change<usize>) {
+    println!("{}: {} → {} ({:+})", label, change.old, change.new, change.delta());
+}
+
+fn format_finding(finding: &Finding) -> String {
+    format!("{}:{} [{}] {}",
+            finding.file,
+            finding.line,
+            finding.rule_id.as_deref().unwrap_or("unknown"),
+            finding.message)
+}
+
+/// Affiche l'évolution de l'historique sur la console
+fn print_console_trend(entries: &[HistoryEntry]) {
+    let (Some(first), Some(last)) = (entries.first(), entries.last()) else {
+        println!("Historique vide : lancer l'analyse avec --history pour l'alimenter");
+        return;
+    };
+    
+    println!("\n{}", "📈 ÉVOLUTION DU PROJET".bold().yellow());
+    println!("---------------------------------------------------");
+    let scores: Vec<f64> = entries.iter().map(|entry| entry.health_score).collect();
+    println!("Score de santé: {:.1}% → {:.1}% ({:+.1})  {}",
+             first.health_score, last.health_score, last.health_score - first.health_score, sparkline(&scores).cyan());
+    let issues: Vec<f64> = entries.iter().map(|entry| entry.issue_count() as f64).collect();
+    println!("Problèmes: {} → {}  {}", first.issue_count(), last.issue_count(), sparkline(&issues).red());
+    
+    println!("\n{}", "ANALYSES".bold());
+    println!("{:<17} {:<9} {:>6} {:>9} {:>5} {:>7} {:>5} {:>6}", "DATE (UTC)", "COMMIT", "SCORE", "CRITICAL", "HIGH", "MEDIUM", "LOW", "TOTAL");
+    for entry in entries {
+        let count = |severity| entry.issues_by_severity.get(&severity).copied().unwrap_or_default();
+        println!("{:<17} {:<9} {:>6.1} {:>9} {:>5} {:>7} {:>5} {:>6}",
+                 format_timestamp(entry.timestamp),
+                 entry.short_commit(),
+                 entry.health_score,
+                 count(Severity::Critical),
+                 count(Severity::High),
+                 count(Severity::Medium),
+                 count(Severity::Low),
+                 entry.issue_count());
+    }
+    
+    println!("\n{}", "PROBLÈMES PAR TYPE".bold());
+    let mut types: Vec<_> = first.issues_by_type.keys().chain(last.issues_by_type.keys()).collect();
+    types.sort();
+    types.dedup();
+    for issue_type in types {
+        let old = first.issues_by_type.get(issue_type).copied().unwrap_or_default();
+        let new = last.issues_by_type.get(issue_type).copied().unwrap_or_default();
+        println!("{:?}: {} → {} ({:+})", issue_type, old, new, new as i64 - old as i64);
+    }
+    
+    println!("\n{}", "POINTS CHAUDS (criticité)".bold().red());
+    for file in &last.hotspots {
+        let scores: Vec<f64> = entries
+            .iter()
+            .map(|entry| entry.files.get(file).map_or(0.0, |health| health.criticality_score))
+            .collect();
+        println!("{} {} {:.1}", sparkline(&scores).red(), file.yellow(), scores.last().copied().unwrap_or_default());
+    }
+    
+    let (improved, worsened) = history::file_trends(first, last);
+    for (title, trends) in [("FICHIERS AMÉLIORÉS", &improved), ("FICHIERS DÉGRADÉS", &worsened)] {
+        if trends.is_empty() {
+            continue;
+        }
+        println!("\n{}", title.bold());
+        for trend in trends.iter().take(10) {
+            let delta = format!("{:+.1}", trend.delta());
+            println!("{} ({})", trend.file, if trend.delta() < 0.0 { delta.green() } else { delta.red() });
+        }
+    }
+}
+
+/// Courbe en caractères blocs (`▁` à `█`) entre le minimum et le maximum de la série
+fn sparkline(values: &[f64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    values
+        .iter()
+        .map(|value| {
+            let level = if max > min { (value - min) / (max - min) * 7.0 } else { 0.0 };
+            BLOCKS[level.round() as usize]
+        })
+        .collect()
+}
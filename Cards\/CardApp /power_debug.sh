@@ -200,7 +200,7 @@ run_rust_analyzer() {
         
         # Exécuter l'analyseur Rust
         cd "${WORKSPACE_DIR}"
-        "${RUST_PERF}/target/release/swift_performance_analyzer" "${WORKSPACE_DIR}" \
+        "${TOOLS_DIR}/target/release/swift_performance_analyzer" "${WORKSPACE_DIR}" \
             --output json --report-path "${REPORTS_DIR}/rust_analysis_${TIMESTAMP}.json" >> "${LOG_FILE}" 2>&1 || true
         
         echo -e "${GREEN}Analyse Rust terminée.${NC}"
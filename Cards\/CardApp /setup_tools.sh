@@ -101,6 +101,12 @@ echo -e "${YELLOW}Vérification de Rust...${NC}"
 if command -v cargo &> /dev/null; then
     echo -e "${GREEN}✓ Rust est installé${NC}"
     
+    if [ -f analysis_tools/Cargo.toml ] && grep -q "^\[workspace\]" analysis_tools/Cargo.toml; then
+        # Le workspace de l'analyseur existe déjà : ne pas écraser ses sources
+        echo -e "${YELLOW}Compilation du workspace Rust...${NC}"
+        (cd analysis_tools && cargo check)
+        echo -e "${GREEN}✓ Configuration Rust terminée${NC}"
+    else
     # Création du Cargo.toml
     echo -e "${YELLOW}Création des dépendances Rust...${NC}"
     cat > analysis_tools/rust_performance_analyzer/Cargo.toml << EOF
@@ -132,6 +138,7 @@ EOF
     echo -e "${YELLOW}Compilation du projet Rust...${NC}"
     (cd analysis_tools/rust_performance_analyzer && cargo check)
     echo -e "${GREEN}✓ Configuration Rust terminée${NC}"
+    fi
 else
     echo -e "${RED}✗ Rust n'est pas installé${NC}"
     echo -e "${YELLOW}Les fonctionnalités d'analyse de performance avancée ne seront pas disponibles${NC}"